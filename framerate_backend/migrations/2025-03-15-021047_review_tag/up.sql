@@ -0,0 +1,16 @@
+-- Your SQL goes here
+CREATE TABLE "tags"(
+    "tag_id" uuid NOT NULL PRIMARY KEY,
+    "user_id" uuid NOT NULL,
+    "name" text NOT NULL,
+    UNIQUE ("user_id", "name"),
+    FOREIGN KEY ("user_id") REFERENCES "users"("user_id") ON DELETE CASCADE
+);
+
+CREATE TABLE "review_tag"(
+    "review_id" uuid NOT NULL,
+    "tag_id" uuid NOT NULL,
+    PRIMARY KEY ("review_id", "tag_id"),
+    FOREIGN KEY ("review_id") REFERENCES "reviews"("review_id") ON DELETE CASCADE,
+    FOREIGN KEY ("tag_id") REFERENCES "tags"("tag_id") ON DELETE CASCADE
+);
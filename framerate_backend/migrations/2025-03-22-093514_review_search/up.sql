@@ -0,0 +1,8 @@
+-- Your SQL goes here
+-- Searches also match the media title from the joined media table, so they can't use
+-- an index on this column and it is only stored to save computing it for each search
+ALTER TABLE "reviews"
+    ADD COLUMN "search_vector" tsvector NOT NULL GENERATED ALWAYS AS (
+        setweight(to_tsvector('english', coalesce("title", '')), 'A') ||
+        setweight(to_tsvector('english', coalesce("description", '')), 'B')
+    ) STORED;
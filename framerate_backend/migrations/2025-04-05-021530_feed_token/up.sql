@@ -0,0 +1,7 @@
+-- Your SQL goes here
+CREATE TABLE "feed_tokens"(
+    "user_id" uuid NOT NULL PRIMARY KEY,
+    "token" uuid NOT NULL UNIQUE,
+    "date_created" timestamp NOT NULL,
+    FOREIGN KEY ("user_id") REFERENCES "users"("user_id") ON DELETE CASCADE
+);
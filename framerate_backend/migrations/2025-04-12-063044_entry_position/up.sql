@@ -0,0 +1,21 @@
+-- Your SQL goes here
+ALTER TABLE "movie_entries"
+    ADD COLUMN "position" integer,
+    ADD COLUMN "added_at" timestamp;
+
+ALTER TABLE "show_entries"
+    ADD COLUMN "position" integer,
+    ADD COLUMN "added_at" timestamp;
+
+-- Existing entries were last changed when their details were refreshed, which is the
+-- closest record of when they were added
+UPDATE "movie_entries" SET "added_at" = "updated_at";
+UPDATE "show_entries" SET "added_at" = "updated_at";
+
+ALTER TABLE "movie_entries"
+    ALTER COLUMN "added_at" SET DEFAULT now(),
+    ALTER COLUMN "added_at" SET NOT NULL;
+
+ALTER TABLE "show_entries"
+    ALTER COLUMN "added_at" SET DEFAULT now(),
+    ALTER COLUMN "added_at" SET NOT NULL;
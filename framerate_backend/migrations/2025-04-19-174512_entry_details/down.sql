@@ -0,0 +1,8 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE "movie_entries"
+    DROP COLUMN "note",
+    DROP COLUMN "priority";
+
+ALTER TABLE "show_entries"
+    DROP COLUMN "note",
+    DROP COLUMN "priority";
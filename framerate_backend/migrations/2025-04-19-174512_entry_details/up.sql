@@ -0,0 +1,8 @@
+-- Your SQL goes here
+ALTER TABLE "movie_entries"
+    ADD COLUMN "note" text,
+    ADD COLUMN "priority" smallint;
+
+ALTER TABLE "show_entries"
+    ADD COLUMN "note" text,
+    ADD COLUMN "priority" smallint;
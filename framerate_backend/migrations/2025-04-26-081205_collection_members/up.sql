@@ -0,0 +1,25 @@
+-- Your SQL goes here
+CREATE TABLE "collection_members"(
+    "collection_id" uuid NOT NULL,
+    "user_id" uuid NOT NULL,
+    "role" text NOT NULL,
+    "accepted" boolean NOT NULL DEFAULT false,
+    "date_created" timestamp NOT NULL,
+    PRIMARY KEY ("collection_id", "user_id"),
+    FOREIGN KEY ("collection_id") REFERENCES "collections"("collection_id") ON DELETE CASCADE,
+    FOREIGN KEY ("user_id") REFERENCES "users"("user_id") ON DELETE CASCADE
+);
+
+CREATE TABLE "collection_changes"(
+    "change_id" uuid NOT NULL PRIMARY KEY,
+    "collection_id" uuid NOT NULL,
+    "user_id" uuid NOT NULL,
+    "media_type" text NOT NULL,
+    "media_id" integer NOT NULL,
+    "action" text NOT NULL,
+    "date_created" timestamp NOT NULL,
+    FOREIGN KEY ("collection_id") REFERENCES "collections"("collection_id") ON DELETE CASCADE,
+    FOREIGN KEY ("user_id") REFERENCES "users"("user_id") ON DELETE CASCADE
+);
+
+CREATE INDEX "collection_changes_collection_id_idx" ON "collection_changes"("collection_id", "date_created");
@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TABLE "collections" ADD COLUMN "rules" jsonb;
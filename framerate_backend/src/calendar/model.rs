@@ -0,0 +1,218 @@
+use crate::db::DbConnection;
+use crate::movie::MOVIE_MEDIA_TYPE;
+use crate::schema::{collection_members, movie_entries, show_entries};
+use crate::show::SHOW_MEDIA_TYPE;
+use crate::utils::AppError;
+use chrono::{NaiveDate, NaiveDateTime, TimeDelta};
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::ops::RangeInclusive;
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+/// iCalendar lines should be no longer than this many octets
+const ICS_LINE_LENGTH: usize = 75;
+
+pub const DEFAULT_UPCOMING_DAYS: u16 = 30;
+pub const UPCOMING_DAYS: RangeInclusive<u16> = 0..=365;
+
+/// An all day event for a movie release or the next episode of a show
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CalendarEvent {
+    /// One of `movie` or `show`
+    pub media_type: String,
+    pub media_id: i32,
+    pub title: String,
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub poster_path: Option<String>,
+    /// The release date of a movie, or the air date of a show's next episode
+    pub date: NaiveDate,
+}
+
+#[derive(Deserialize, IntoParams)]
+#[serde(rename_all = "camelCase")]
+pub struct UpcomingParameters {
+    /// How many days ahead to look, from 0 to 365. Defaults to 30
+    #[param(nullable = false)]
+    pub days: Option<u16>,
+}
+
+/// Escapes text for an iCalendar property value
+fn escape_text(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+/// Folds a content line longer than `ICS_LINE_LENGTH`, continuing it on lines that
+/// start with a space
+fn fold_line(line: &str, ics: &mut String) {
+    let mut length = 0;
+    for character in line.chars() {
+        if length + character.len_utf8() > ICS_LINE_LENGTH {
+            ics.push_str("\r\n ");
+            length = 1;
+        }
+        ics.push(character);
+        length += character.len_utf8();
+    }
+    ics.push_str("\r\n");
+}
+
+impl CalendarEvent {
+    /// Finds upcoming movie releases and next episode air dates from all of the user's
+    /// collections, including those shared with them, listing entries in more than one
+    /// collection only once
+    pub fn find_upcoming(
+        conn: &mut DbConnection,
+        user_id: Uuid,
+        from: NaiveDate,
+        until: Option<NaiveDate>,
+    ) -> Result<Vec<Self>, AppError> {
+        let shared_collection_ids = collection_members::table
+            .filter(collection_members::user_id.eq(user_id))
+            .filter(collection_members::accepted.eq(true))
+            .select(collection_members::collection_id);
+
+        let mut movie_query = movie_entries::table
+            .filter(
+                movie_entries::user_id
+                    .eq(user_id)
+                    .or(movie_entries::collection_id.eq_any(shared_collection_ids)),
+            )
+            .filter(movie_entries::release_date.ge(from))
+            .into_boxed();
+
+        if let Some(until) = until {
+            movie_query = movie_query.filter(movie_entries::release_date.le(until));
+        }
+
+        let movies = movie_query
+            .select((
+                movie_entries::movie_id,
+                movie_entries::title,
+                movie_entries::poster_path,
+                movie_entries::release_date.assume_not_null(),
+            ))
+            .load::<(i32, String, Option<String>, NaiveDate)>(conn)?;
+
+        let mut show_query = show_entries::table
+            .filter(
+                show_entries::user_id
+                    .eq(user_id)
+                    .or(show_entries::collection_id.eq_any(shared_collection_ids)),
+            )
+            .filter(show_entries::next_air_date.ge(from))
+            .into_boxed();
+
+        if let Some(until) = until {
+            show_query = show_query.filter(show_entries::next_air_date.le(until));
+        }
+
+        let shows = show_query
+            .select((
+                show_entries::show_id,
+                show_entries::name,
+                show_entries::poster_path,
+                show_entries::next_air_date.assume_not_null(),
+            ))
+            .load::<(i32, String, Option<String>, NaiveDate)>(conn)?;
+
+        let mut events: Vec<CalendarEvent> = movies
+            .into_iter()
+            .map(|(media_id, title, poster_path, date)| {
+                (MOVIE_MEDIA_TYPE, media_id, title, poster_path, date)
+            })
+            .chain(
+                shows
+                    .into_iter()
+                    .map(|(media_id, title, poster_path, date)| {
+                        (SHOW_MEDIA_TYPE, media_id, title, poster_path, date)
+                    }),
+            )
+            .map(
+                |(media_type, media_id, title, poster_path, date)| CalendarEvent {
+                    media_type: media_type.to_string(),
+                    media_id,
+                    title,
+                    poster_path,
+                    date,
+                },
+            )
+            .collect();
+
+        events.sort_by(|a, b| a.date.cmp(&b.date).then_with(|| a.title.cmp(&b.title)));
+
+        // Sorted first so the earliest date is kept when copies of an entry disagree
+        let mut seen = HashSet::new();
+        events.retain(|event| seen.insert((event.media_type.clone(), event.media_id)));
+
+        Ok(events)
+    }
+
+    fn summary(&self) -> String {
+        if self.media_type == SHOW_MEDIA_TYPE {
+            format!("{} (new episode)", self.title)
+        } else {
+            self.title.clone()
+        }
+    }
+
+    /// Movies keep the same id as their release date moves, while each air date of a
+    /// show is its own event
+    fn uid(&self) -> String {
+        if self.media_type == SHOW_MEDIA_TYPE {
+            format!(
+                "{}-{}-{}@framerate",
+                self.media_type,
+                self.media_id,
+                self.date.format("%Y%m%d")
+            )
+        } else {
+            format!("{}-{}@framerate", self.media_type, self.media_id)
+        }
+    }
+}
+
+/// Writes the events as an iCalendar file
+pub fn write_ics(events: &[CalendarEvent], generated_at: NaiveDateTime) -> String {
+    let mut ics = String::new();
+    let stamp = generated_at.format("%Y%m%dT%H%M%SZ").to_string();
+
+    fold_line("BEGIN:VCALENDAR", &mut ics);
+    fold_line("VERSION:2.0", &mut ics);
+    fold_line("PRODID:-//Framerate//Upcoming//EN", &mut ics);
+    fold_line("CALSCALE:GREGORIAN", &mut ics);
+    fold_line("X-WR-CALNAME:Framerate", &mut ics);
+
+    for event in events {
+        fold_line("BEGIN:VEVENT", &mut ics);
+        fold_line(&format!("UID:{}", event.uid()), &mut ics);
+        fold_line(&format!("DTSTAMP:{stamp}"), &mut ics);
+        fold_line(
+            &format!("DTSTART;VALUE=DATE:{}", event.date.format("%Y%m%d")),
+            &mut ics,
+        );
+        fold_line(
+            &format!(
+                "DTEND;VALUE=DATE:{}",
+                (event.date + TimeDelta::days(1)).format("%Y%m%d")
+            ),
+            &mut ics,
+        );
+        fold_line(
+            &format!("SUMMARY:{}", escape_text(&event.summary())),
+            &mut ics,
+        );
+        fold_line("TRANSP:TRANSPARENT", &mut ics);
+        fold_line("END:VEVENT", &mut ics);
+    }
+
+    fold_line("END:VCALENDAR", &mut ics);
+
+    ics
+}
@@ -0,0 +1,53 @@
+use super::{write_ics, CalendarEvent, UpcomingParameters, DEFAULT_UPCOMING_DAYS, UPCOMING_DAYS};
+use crate::db::DbPool;
+use crate::feed_token::FeedToken;
+use crate::utils::response_body::Success;
+use crate::utils::{jwt::Auth, AppError};
+use actix_web::{get, web, HttpResponse, Responder};
+use chrono::{TimeDelta, Utc};
+use uuid::Uuid;
+
+#[utoipa::path(tag = "Calendar", responses((status = OK, body = String, content_type = "text/calendar"),(status = NOT_FOUND)))]
+#[get("/calendar/{feed_token}.ics")]
+async fn find(
+    pool: web::Data<DbPool>,
+    feed_token: web::Path<Uuid>,
+) -> actix_web::Result<impl Responder> {
+    let now = Utc::now().naive_utc();
+
+    let events = web::block(move || {
+        let mut conn = pool.get()?;
+        let user_id = FeedToken::find_user_id(&mut conn, feed_token.into_inner())?;
+        CalendarEvent::find_upcoming(&mut conn, user_id, now.date(), None)
+    })
+    .await??;
+
+    Ok(HttpResponse::Ok()
+        .content_type("text/calendar; charset=utf-8")
+        .body(write_ics(&events, now)))
+}
+
+#[utoipa::path(tag = "Calendar", params(UpcomingParameters), responses((status = OK, body = Vec<CalendarEvent>),(status = BAD_REQUEST)))]
+#[get("/upcoming")]
+async fn find_upcoming(
+    pool: web::Data<DbPool>,
+    auth: Auth,
+    params: web::Query<UpcomingParameters>,
+) -> actix_web::Result<impl Responder> {
+    let days = params.days.unwrap_or(DEFAULT_UPCOMING_DAYS);
+
+    if !UPCOMING_DAYS.contains(&days) {
+        return Err(AppError::external(400, "Invalid days"))?;
+    }
+
+    let today = Utc::now().date_naive();
+    let until = today + TimeDelta::days(i64::from(days));
+
+    let events = web::block(move || {
+        let mut conn = pool.get()?;
+        CalendarEvent::find_upcoming(&mut conn, auth.user_id, today, Some(until))
+    })
+    .await??;
+
+    Ok(Success::new(events))
+}
@@ -1,3 +1,5 @@
 pub mod model;
+pub mod rules;
 
 pub use model::*;
+pub use rules::*;
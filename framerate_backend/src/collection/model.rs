@@ -1,21 +1,136 @@
+use crate::collection::CollectionRules;
 use crate::db::DbConnection;
-use crate::schema::collections;
+use crate::schema::{collection_members, collections};
 use crate::user;
 use crate::utils::AppError;
+use chrono::{NaiveDate, NaiveDateTime};
+use diesel::dsl::sql;
+use diesel::expression::{SqlLiteral, TypedExpressionType};
 use diesel::prelude::*;
 use serde::{Deserialize, Serialize};
-use utoipa::ToSchema;
+use std::cmp::Ordering;
+use std::ops::RangeInclusive;
+use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 
-#[derive(AsChangeset, Insertable, Associations, Selectable, Queryable)]
+/// Collections that can hold both movies and shows
+pub const MIXED_MEDIA_TYPE: &str = "mixed";
+pub const DEFAULT_WATCHLIST: &str = "watchlist";
+/// Where reviewed watchlist entries are moved to, when the user chooses to keep them
+pub const DEFAULT_WATCHED: &str = "watched";
+
+/// Priorities of collection entries, from lowest to highest
+pub const ENTRY_PRIORITIES: RangeInclusive<i16> = 1..=5;
+
+/// Order of the entries in a collection read
+#[derive(Debug, Default, Clone, Copy, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum EntrySort {
+    /// Manual order, followed by entries without a position in the order they were added
+    Position,
+    Title,
+    /// Latest release or first air date first
+    #[default]
+    ReleaseDate,
+    /// Most recently added first
+    DateAdded,
+    Status,
+}
+
+impl EntrySort {
+    /// Compares entries found in memory the way collection reads order them in the
+    /// database, where text is ordered with `code_point_order`
+    pub fn compare<E: SortableEntry>(self, a: &E, b: &E) -> Ordering {
+        match self {
+            EntrySort::Position => (
+                a.position().is_none(),
+                a.position(),
+                a.added_at(),
+                a.title(),
+            )
+                .cmp(&(
+                    b.position().is_none(),
+                    b.position(),
+                    b.added_at(),
+                    b.title(),
+                )),
+            EntrySort::Title => a.title().cmp(b.title()),
+            EntrySort::ReleaseDate => b.date().cmp(&a.date()),
+            EntrySort::DateAdded => b
+                .added_at()
+                .cmp(&a.added_at())
+                .then_with(|| a.title().cmp(b.title())),
+            EntrySort::Status => (a.status().is_none(), a.status(), a.title()).cmp(&(
+                b.status().is_none(),
+                b.status(),
+                b.title(),
+            )),
+        }
+    }
+}
+
+/// Orders a text column by code point, the way strings compare in Rust, instead of by
+/// the database's collation
+pub fn code_point_order<ST>(column: &str) -> SqlLiteral<ST>
+where
+    ST: TypedExpressionType,
+{
+    sql(&format!("{column} COLLATE \"C\""))
+}
+
+/// The fields of a collection entry that `EntrySort` orders by
+pub trait SortableEntry {
+    fn title(&self) -> &str;
+    /// The release date of a movie, or the first air date of a show
+    fn date(&self) -> Option<NaiveDate>;
+    fn added_at(&self) -> NaiveDateTime;
+    fn status(&self) -> Option<&str>;
+    fn position(&self) -> Option<i32>;
+}
+
+/// What happens to a watchlist entry once it is reviewed, set as `watchlistOnReview`
+/// in the user's configuration
+#[derive(Debug, Default, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum WatchlistOnReview {
+    #[default]
+    Keep,
+    Remove,
+    /// Moved to the default watched collection
+    Move,
+}
+
+impl WatchlistOnReview {
+    /// Reads the setting from the user's configuration, keeping entries when it is
+    /// missing or unknown
+    pub fn from_configuration(configuration: &serde_json::Value) -> Self {
+        configuration
+            .get("watchlistOnReview")
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .unwrap_or_default()
+    }
+}
+
+#[derive(Deserialize, IntoParams)]
+#[serde(rename_all = "camelCase")]
+pub struct CollectionFindParameters {
+    #[param(nullable = false)]
+    #[param(value_type=Option<String>)]
+    pub sort: Option<EntrySort>,
+}
+
+#[derive(Serialize, Deserialize, AsChangeset, Insertable, Associations, Selectable, Queryable)]
 #[diesel(belongs_to(user::User))]
 #[diesel(table_name = collections)]
+#[serde(rename_all = "camelCase")]
 pub struct Collection {
     pub collection_id: Uuid,
     pub user_id: Uuid,
     pub name: String,
     pub media_type: String,
     pub default_for: Option<String>,
+    /// Makes this a smart collection, with entries found by these rules when it is read
+    pub rules: Option<serde_json::Value>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -25,14 +140,27 @@ pub struct NewCollection {
     pub media_type: String,
 }
 
-#[derive(Debug, Deserialize, Serialize, AsChangeset, ToSchema)]
-#[diesel(table_name = collections)]
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct UpdatedCollection {
     pub name: String,
+    /// Replaces the rules of a smart collection. Static collections can't be given
+    /// rules
+    #[schema(nullable = false)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rules: Option<CollectionRules>,
 }
 
 impl Collection {
+    /// The rules of a smart collection, or `None` for a static collection
+    pub fn smart_rules(&self) -> Result<Option<CollectionRules>, AppError> {
+        self.rules
+            .clone()
+            .map(serde_json::from_value)
+            .transpose()
+            .map_err(|_| AppError::external(500, "Invalid collection rules"))
+    }
+
     pub fn find_default(
         conn: &mut DbConnection,
         user_id: Uuid,
@@ -52,6 +180,51 @@ impl Collection {
         Ok(collection)
     }
 
+    /// Finds a default collection, or `None` when the user doesn't have one yet
+    pub fn find_optional_default(
+        conn: &mut DbConnection,
+        user_id: Uuid,
+        media_type: &str,
+        default_for: &str,
+    ) -> Result<Option<Self>, AppError> {
+        let collection = collections::table
+            .select(Collection::as_select())
+            .filter(
+                collections::user_id
+                    .eq(user_id)
+                    .and(collections::media_type.eq(media_type))
+                    .and(collections::default_for.eq(default_for)),
+            )
+            .first(conn)
+            .optional()?;
+
+        Ok(collection)
+    }
+
+    /// Finds a default collection, creating it the first time it is needed
+    pub fn find_or_create_default(
+        conn: &mut DbConnection,
+        user_id: Uuid,
+        media_type: &str,
+        default_for: &str,
+        name: &str,
+    ) -> Result<Self, AppError> {
+        match Collection::find_optional_default(conn, user_id, media_type, default_for)? {
+            Some(collection) => Ok(collection),
+            None => Collection::create(
+                conn,
+                Collection {
+                    collection_id: Uuid::new_v4(),
+                    media_type: media_type.to_string(),
+                    user_id,
+                    name: name.to_string(),
+                    default_for: Some(default_for.to_string()),
+                    rules: None,
+                },
+            ),
+        }
+    }
+
     pub fn find(
         conn: &mut DbConnection,
         user_id: Uuid,
@@ -67,13 +240,56 @@ impl Collection {
         Ok(collections)
     }
 
+    /// Finds a collection the user owns, or has accepted an invite to with one of
+    /// `roles`. Without any roles only the owner can find it
+    pub fn find_shared(
+        conn: &mut DbConnection,
+        user_id: Uuid,
+        media_type: Option<&str>,
+        collection_id: &Uuid,
+        roles: &[&str],
+    ) -> Result<Self, AppError> {
+        let shared_collection_ids = collection_members::table
+            .filter(collection_members::user_id.eq(user_id))
+            .filter(collection_members::accepted.eq(true))
+            .filter(collection_members::role.eq_any(roles))
+            .select(collection_members::collection_id);
+
+        let mut query = collections::table
+            .filter(collections::collection_id.eq(collection_id))
+            .filter(
+                collections::user_id
+                    .eq(user_id)
+                    .or(collections::collection_id.eq_any(shared_collection_ids)),
+            )
+            .select(Collection::as_select())
+            .into_boxed();
+
+        if let Some(media_type) = media_type {
+            query = query.filter(collections::media_type.eq(media_type));
+        }
+
+        let collection = query.first(conn)?;
+        Ok(collection)
+    }
+
+    /// Finds the user's own collections and those shared with them
     pub fn find_by_media_type(
         conn: &mut DbConnection,
         user_id: Uuid,
         media_type: &str,
     ) -> Result<Vec<Self>, AppError> {
+        let shared_collection_ids = collection_members::table
+            .filter(collection_members::user_id.eq(user_id))
+            .filter(collection_members::accepted.eq(true))
+            .select(collection_members::collection_id);
+
         let collections = collections::table
-            .filter(collections::user_id.eq(user_id))
+            .filter(
+                collections::user_id
+                    .eq(user_id)
+                    .or(collections::collection_id.eq_any(shared_collection_ids)),
+            )
             .filter(collections::media_type.eq(media_type))
             .filter(collections::default_for.is_null())
             .order(collections::name.desc())
@@ -95,10 +311,31 @@ impl Collection {
         collection_id: &Uuid,
         collection: UpdatedCollection,
     ) -> Result<Self, AppError> {
+        if let Some(rules) = &collection.rules {
+            let existing = collections::table
+                .filter(collections::user_id.eq(user_id))
+                .filter(collections::collection_id.eq(collection_id))
+                .select(Collection::as_select())
+                .first(conn)?;
+
+            if existing.rules.is_none() {
+                return Err(AppError::external(
+                    400,
+                    "Rules can only be set on smart collections",
+                ));
+            }
+
+            rules.validate(conn, *user_id, &existing.media_type)?;
+        }
+
+        let rules = collection
+            .rules
+            .map(|rules| collections::rules.eq(serde_json::json!(rules)));
+
         let updated_collection = diesel::update(collections::table)
             .filter(collections::user_id.eq(user_id))
             .filter(collections::collection_id.eq(collection_id))
-            .set(collection)
+            .set((collections::name.eq(collection.name), rules))
             .get_result(conn)?;
         Ok(updated_collection)
     }
@@ -0,0 +1,129 @@
+use crate::db::DbConnection;
+use crate::schema::{collections, company, tags};
+use crate::utils::AppError;
+use diesel::dsl::exists;
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// What a smart collection's entries are found from
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum RuleSource {
+    /// Media the user reviewed, each listed once
+    Reviews,
+    /// Media in the user's static collections, each listed once
+    Entries,
+}
+
+/// The query a smart collection runs each time it is read. Conditions are combined,
+/// and each only applies to one of the sources
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct CollectionRules {
+    pub source: RuleSource,
+    /// Reviews rated at least this, out of 100
+    #[schema(nullable = false)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_rating: Option<i16>,
+    /// Reviews watched with this company
+    #[schema(nullable = false)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub company_id: Option<Uuid>,
+    /// Reviews with this tag
+    #[schema(nullable = false)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tag_id: Option<Uuid>,
+    /// Entries in this collection
+    #[schema(nullable = false)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub collection_id: Option<Uuid>,
+    /// Entries in the default watchlist
+    #[serde(default)]
+    pub watchlist: bool,
+    /// Entries with one of these TMDB statuses, like `Returning Series`
+    #[schema(nullable = false)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub statuses: Option<Vec<String>>,
+    /// Entries released, or with a next episode airing, between today and this many
+    /// days from now
+    #[schema(nullable = false)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub upcoming_days: Option<u16>,
+}
+
+impl CollectionRules {
+    /// Rejects conditions that don't apply to the source, which would otherwise be
+    /// silently ignored, and companies, tags or collections the user doesn't own. A
+    /// collection condition must name a static collection of the same media type
+    pub fn validate(
+        &self,
+        conn: &mut DbConnection,
+        user_id: Uuid,
+        media_type: &str,
+    ) -> Result<(), AppError> {
+        let applies = match self.source {
+            RuleSource::Reviews => {
+                self.collection_id.is_none()
+                    && !self.watchlist
+                    && self.statuses.is_none()
+                    && self.upcoming_days.is_none()
+            }
+            RuleSource::Entries => {
+                self.min_rating.is_none() && self.company_id.is_none() && self.tag_id.is_none()
+            }
+        };
+
+        let in_range = self
+            .min_rating
+            .is_none_or(|min_rating| (0..=100).contains(&min_rating));
+
+        if !applies || !in_range {
+            return Err(AppError::external(400, "Invalid collection rules"));
+        }
+
+        if let Some(company_id) = self.company_id {
+            let owned = diesel::select(exists(
+                company::table
+                    .filter(company::company_id.eq(company_id))
+                    .filter(company::created_by.eq(user_id)),
+            ))
+            .get_result::<bool>(conn)?;
+
+            if !owned {
+                return Err(AppError::external(400, "Company not found"));
+            }
+        }
+
+        if let Some(tag_id) = self.tag_id {
+            let owned = diesel::select(exists(
+                tags::table
+                    .filter(tags::tag_id.eq(tag_id))
+                    .filter(tags::user_id.eq(user_id)),
+            ))
+            .get_result::<bool>(conn)?;
+
+            if !owned {
+                return Err(AppError::external(400, "Tag not found"));
+            }
+        }
+
+        if let Some(collection_id) = self.collection_id {
+            let owned = diesel::select(exists(
+                collections::table
+                    .filter(collections::collection_id.eq(collection_id))
+                    .filter(collections::user_id.eq(user_id))
+                    .filter(collections::media_type.eq(media_type))
+                    .filter(collections::rules.is_null()),
+            ))
+            .get_result::<bool>(conn)?;
+
+            if !owned {
+                return Err(AppError::external(400, "Collection not found"));
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,5 @@
+pub mod model;
+pub mod routes;
+
+pub use model::*;
+pub use routes::*;
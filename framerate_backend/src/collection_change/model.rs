@@ -0,0 +1,67 @@
+use crate::db::DbConnection;
+use crate::schema::collection_changes;
+use crate::utils::AppError;
+use crate::{collection, user};
+use chrono::{NaiveDateTime, Utc};
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+pub const ENTRY_ADDED: &str = "added";
+pub const ENTRY_REMOVED: &str = "removed";
+
+/// An entry a member added to or removed from a collection
+#[derive(
+    Serialize, Deserialize, Insertable, Associations, Selectable, Queryable, Debug, ToSchema,
+)]
+#[diesel(belongs_to(collection::Collection))]
+#[diesel(belongs_to(user::User))]
+#[diesel(table_name = collection_changes)]
+#[serde(rename_all = "camelCase")]
+pub struct CollectionChange {
+    pub change_id: Uuid,
+    pub collection_id: Uuid,
+    /// The member who made the change
+    pub user_id: Uuid,
+    pub media_type: String,
+    pub media_id: i32,
+    /// One of `added` or `removed`
+    pub action: String,
+    pub date_created: NaiveDateTime,
+}
+
+impl CollectionChange {
+    /// Finds the changes to a collection, most recent first
+    pub fn find_all(conn: &mut DbConnection, collection_id: Uuid) -> Result<Vec<Self>, AppError> {
+        let changes = collection_changes::table
+            .filter(collection_changes::collection_id.eq(collection_id))
+            .order(collection_changes::date_created.desc())
+            .select(CollectionChange::as_select())
+            .load(conn)?;
+        Ok(changes)
+    }
+
+    pub fn create(
+        conn: &mut DbConnection,
+        collection_id: Uuid,
+        user_id: Uuid,
+        media_type: &str,
+        media_id: i32,
+        action: &str,
+    ) -> Result<Self, AppError> {
+        let change = diesel::insert_into(collection_changes::table)
+            .values(CollectionChange {
+                change_id: Uuid::new_v4(),
+                collection_id,
+                user_id,
+                media_type: media_type.to_string(),
+                media_id,
+                action: action.to_string(),
+                date_created: Utc::now().naive_utc(),
+            })
+            .returning(CollectionChange::as_returning())
+            .get_result(conn)?;
+        Ok(change)
+    }
+}
@@ -0,0 +1,31 @@
+use crate::collection::Collection;
+use crate::collection_change::CollectionChange;
+use crate::collection_member::COLLECTION_ROLES;
+use crate::db::DbPool;
+use crate::utils::jwt::Auth;
+use crate::utils::response_body::Success;
+use actix_web::{get, web, Responder};
+use uuid::Uuid;
+
+#[utoipa::path(tag = "Collection Change", responses((status = OK, body = Vec<CollectionChange>),(status = NOT_FOUND)))]
+#[get("/collections/{collection_id}/changes")]
+async fn find_all(
+    pool: web::Data<DbPool>,
+    auth: Auth,
+    collection_id: web::Path<Uuid>,
+) -> actix_web::Result<impl Responder> {
+    let changes = web::block(move || {
+        let mut conn = pool.get()?;
+        let collection = Collection::find_shared(
+            &mut conn,
+            auth.user_id,
+            None,
+            &collection_id,
+            &COLLECTION_ROLES,
+        )?;
+        CollectionChange::find_all(&mut conn, collection.collection_id)
+    })
+    .await??;
+
+    Ok(Success::new(changes))
+}
@@ -0,0 +1,210 @@
+use crate::db::DbConnection;
+use crate::schema::{collection_members, collections, users};
+use crate::utils::AppError;
+use crate::{collection, user};
+use chrono::{NaiveDateTime, Utc};
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Can read the collection
+pub const COLLECTION_VIEWER: &str = "viewer";
+/// Can also add, remove and edit the collection's entries
+pub const COLLECTION_EDITOR: &str = "editor";
+pub const COLLECTION_ROLES: [&str; 2] = [COLLECTION_VIEWER, COLLECTION_EDITOR];
+
+/// A user the owner of a collection has shared it with. The collection is only shared
+/// once the user accepts the invite
+#[derive(
+    Serialize, Deserialize, Insertable, Associations, Selectable, Queryable, Debug, ToSchema,
+)]
+#[diesel(belongs_to(collection::Collection))]
+#[diesel(belongs_to(user::User))]
+#[diesel(table_name = collection_members)]
+#[serde(rename_all = "camelCase")]
+pub struct CollectionMember {
+    pub collection_id: Uuid,
+    pub user_id: Uuid,
+    pub role: String,
+    pub accepted: bool,
+    pub date_created: NaiveDateTime,
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct NewCollectionMember {
+    pub email: String,
+    /// One of `viewer` or `editor`
+    pub role: String,
+}
+
+#[derive(Serialize, Deserialize, AsChangeset, ToSchema)]
+#[diesel(table_name = collection_members)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdatedCollectionMember {
+    /// One of `viewer` or `editor`
+    pub role: String,
+}
+
+#[derive(Serialize, Deserialize, Queryable, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CollectionMemberResponse {
+    pub user_id: Uuid,
+    pub first_name: String,
+    pub last_name: String,
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub avatar_uri: Option<String>,
+    pub role: String,
+    pub accepted: bool,
+}
+
+#[derive(Serialize, Deserialize, Queryable, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CollectionInvite {
+    pub collection_id: Uuid,
+    pub name: String,
+    pub media_type: String,
+    pub owner_id: Uuid,
+    pub role: String,
+}
+
+impl CollectionMember {
+    pub fn find_all(
+        conn: &mut DbConnection,
+        collection_id: Uuid,
+    ) -> Result<Vec<CollectionMemberResponse>, AppError> {
+        let members = collection_members::table
+            .inner_join(users::table)
+            .filter(collection_members::collection_id.eq(collection_id))
+            .order((users::first_name.asc(), users::last_name.asc()))
+            .select((
+                users::user_id,
+                users::first_name,
+                users::last_name,
+                users::avatar_uri,
+                collection_members::role,
+                collection_members::accepted,
+            ))
+            .load(conn)?;
+        Ok(members)
+    }
+
+    /// Finds the invites the user hasn't accepted yet
+    pub fn find_invites(
+        conn: &mut DbConnection,
+        user_id: Uuid,
+    ) -> Result<Vec<CollectionInvite>, AppError> {
+        let invites = collection_members::table
+            .inner_join(collections::table)
+            .filter(collection_members::user_id.eq(user_id))
+            .filter(collection_members::accepted.eq(false))
+            .order(collection_members::date_created.desc())
+            .select((
+                collections::collection_id,
+                collections::name,
+                collections::media_type,
+                collections::user_id,
+                collection_members::role,
+            ))
+            .load(conn)?;
+        Ok(invites)
+    }
+
+    /// Invites the user with the given email to a collection
+    pub fn create(
+        conn: &mut DbConnection,
+        collection: &collection::Collection,
+        member: NewCollectionMember,
+    ) -> Result<Self, AppError> {
+        if !COLLECTION_ROLES.contains(&member.role.as_str()) {
+            return Err(AppError::external(400, "Invalid role"));
+        }
+
+        if collection.default_for.is_some() {
+            return Err(AppError::external(
+                400,
+                "Default collections cannot be shared",
+            ));
+        }
+
+        let user_id = users::table
+            .filter(users::email.eq(&member.email))
+            .select(users::user_id)
+            .first::<Uuid>(conn)
+            .optional()?
+            .ok_or(AppError::external(404, "User not found"))?;
+
+        if user_id == collection.user_id {
+            return Err(AppError::external(
+                400,
+                "Collection owner cannot be invited",
+            ));
+        }
+
+        let new_member = diesel::insert_into(collection_members::table)
+            .values(CollectionMember {
+                collection_id: collection.collection_id,
+                user_id,
+                role: member.role,
+                accepted: false,
+                date_created: Utc::now().naive_utc(),
+            })
+            .on_conflict_do_nothing()
+            .returning(CollectionMember::as_returning())
+            .get_result(conn)
+            .optional()?
+            .ok_or(AppError::external(400, "User is already invited"))?;
+
+        Ok(new_member)
+    }
+
+    pub fn accept(
+        conn: &mut DbConnection,
+        collection_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Self, AppError> {
+        let member = diesel::update(collection_members::table)
+            .filter(collection_members::collection_id.eq(collection_id))
+            .filter(collection_members::user_id.eq(user_id))
+            .filter(collection_members::accepted.eq(false))
+            .set(collection_members::accepted.eq(true))
+            .returning(CollectionMember::as_returning())
+            .get_result(conn)?;
+        Ok(member)
+    }
+
+    pub fn update(
+        conn: &mut DbConnection,
+        collection_id: Uuid,
+        user_id: Uuid,
+        member: UpdatedCollectionMember,
+    ) -> Result<Self, AppError> {
+        if !COLLECTION_ROLES.contains(&member.role.as_str()) {
+            return Err(AppError::external(400, "Invalid role"));
+        }
+
+        let updated_member = diesel::update(collection_members::table)
+            .filter(collection_members::collection_id.eq(collection_id))
+            .filter(collection_members::user_id.eq(user_id))
+            .set(member)
+            .returning(CollectionMember::as_returning())
+            .get_result(conn)?;
+        Ok(updated_member)
+    }
+
+    pub fn delete(
+        conn: &mut DbConnection,
+        collection_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<usize, AppError> {
+        let res = diesel::delete(
+            collection_members::table
+                .filter(collection_members::collection_id.eq(collection_id))
+                .filter(collection_members::user_id.eq(user_id)),
+        )
+        .execute(conn)?;
+        Ok(res)
+    }
+}
@@ -0,0 +1,133 @@
+use crate::collection::Collection;
+use crate::collection_member::{
+    CollectionInvite, CollectionMember, CollectionMemberResponse, NewCollectionMember,
+    UpdatedCollectionMember, COLLECTION_ROLES,
+};
+use crate::db::DbPool;
+use crate::utils::response_body::{DeleteResponse, Success};
+use crate::utils::{jwt::Auth, AppError};
+use actix_web::{delete, get, post, put, web, Responder};
+use uuid::Uuid;
+
+#[utoipa::path(tag = "Collection Member", responses((status = OK, body = Vec<CollectionMemberResponse>),(status = NOT_FOUND)))]
+#[get("/collections/{collection_id}/members")]
+async fn find_all(
+    pool: web::Data<DbPool>,
+    auth: Auth,
+    collection_id: web::Path<Uuid>,
+) -> actix_web::Result<impl Responder> {
+    let members = web::block(move || {
+        let mut conn = pool.get()?;
+        let collection = Collection::find_shared(
+            &mut conn,
+            auth.user_id,
+            None,
+            &collection_id,
+            &COLLECTION_ROLES,
+        )?;
+        CollectionMember::find_all(&mut conn, collection.collection_id)
+    })
+    .await??;
+
+    Ok(Success::new(members))
+}
+
+#[utoipa::path(tag = "Collection Member", responses((status = OK, body = CollectionMember),(status = BAD_REQUEST),(status = NOT_FOUND)))]
+#[post("/collections/{collection_id}/members")]
+async fn create(
+    pool: web::Data<DbPool>,
+    auth: Auth,
+    collection_id: web::Path<Uuid>,
+    params: web::Json<NewCollectionMember>,
+) -> actix_web::Result<impl Responder> {
+    let member = web::block(move || {
+        let mut conn = pool.get()?;
+        let collection =
+            Collection::find_shared(&mut conn, auth.user_id, None, &collection_id, &[])?;
+        CollectionMember::create(&mut conn, &collection, params.into_inner())
+    })
+    .await??;
+
+    Ok(Success::new(member))
+}
+
+#[utoipa::path(tag = "Collection Member", responses((status = OK, body = CollectionMember),(status = BAD_REQUEST),(status = NOT_FOUND)))]
+#[put("/collections/{collection_id}/members/{user_id}")]
+async fn update(
+    pool: web::Data<DbPool>,
+    auth: Auth,
+    path: web::Path<(Uuid, Uuid)>,
+    params: web::Json<UpdatedCollectionMember>,
+) -> actix_web::Result<impl Responder> {
+    let (collection_id, user_id) = path.into_inner();
+
+    let member = web::block(move || {
+        let mut conn = pool.get()?;
+        let collection =
+            Collection::find_shared(&mut conn, auth.user_id, None, &collection_id, &[])?;
+        CollectionMember::update(
+            &mut conn,
+            collection.collection_id,
+            user_id,
+            params.into_inner(),
+        )
+    })
+    .await??;
+
+    Ok(Success::new(member))
+}
+
+/// Removes a member. The owner can remove anyone, and members can leave or decline
+/// an invite by removing themselves
+#[utoipa::path(tag = "Collection Member", responses((status = OK, body = DeleteResponse),(status = NOT_FOUND)))]
+#[delete("/collections/{collection_id}/members/{user_id}")]
+async fn delete(
+    pool: web::Data<DbPool>,
+    auth: Auth,
+    path: web::Path<(Uuid, Uuid)>,
+) -> actix_web::Result<impl Responder> {
+    let (collection_id, user_id) = path.into_inner();
+
+    let count = web::block(move || {
+        let mut conn = pool.get()?;
+        if user_id != auth.user_id {
+            Collection::find_shared(&mut conn, auth.user_id, None, &collection_id, &[])?;
+        }
+        CollectionMember::delete(&mut conn, collection_id, user_id)
+    })
+    .await??;
+
+    if count == 0 {
+        return Err(AppError::external(404, "Collection member not found"))?;
+    }
+
+    Ok(Success::new(DeleteResponse { count }))
+}
+
+#[utoipa::path(tag = "Collection Member", responses((status = OK, body = Vec<CollectionInvite>)))]
+#[get("/collections/invites")]
+async fn find_invites(pool: web::Data<DbPool>, auth: Auth) -> actix_web::Result<impl Responder> {
+    let invites = web::block(move || {
+        let mut conn = pool.get()?;
+        CollectionMember::find_invites(&mut conn, auth.user_id)
+    })
+    .await??;
+
+    Ok(Success::new(invites))
+}
+
+#[utoipa::path(tag = "Collection Member", responses((status = OK, body = CollectionMember),(status = NOT_FOUND)))]
+#[put("/collections/invites/{collection_id}")]
+async fn accept_invite(
+    pool: web::Data<DbPool>,
+    auth: Auth,
+    collection_id: web::Path<Uuid>,
+) -> actix_web::Result<impl Responder> {
+    let member = web::block(move || {
+        let mut conn = pool.get()?;
+        CollectionMember::accept(&mut conn, *collection_id, auth.user_id)
+    })
+    .await??;
+
+    Ok(Success::new(member))
+}
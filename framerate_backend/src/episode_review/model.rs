@@ -0,0 +1,251 @@
+use chrono::NaiveDate;
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{
+    db::DbConnection,
+    review::{self, Review, ReviewFindParameters, ReviewOrder, ReviewSort},
+    schema::{episode_reviews, reviews},
+    season::Episode,
+    user,
+    utils::{response_body::PaginatedResponse, AppError},
+};
+
+#[derive(Serialize, Deserialize, AsChangeset, Insertable, Associations, Selectable, Queryable)]
+#[diesel(belongs_to(user::User))]
+#[diesel(belongs_to(review::Review))]
+#[diesel(table_name = episode_reviews)]
+#[serde(rename_all = "camelCase")]
+pub struct EpisodeReview {
+    pub review_id: Uuid,
+    pub user_id: Uuid,
+    pub show_id: i32,
+    pub season_number: i32,
+    pub episode_number: i32,
+    pub name: Option<String>,
+    pub still_path: Option<String>,
+    #[diesel(treat_none_as_null = true)]
+    pub air_date: Option<NaiveDate>,
+}
+
+pub struct EpisodeReviewReadResponse {
+    pub review_id: Uuid,
+    pub user_id: Uuid,
+    pub date: Option<NaiveDate>,
+    pub rating: Option<i16>,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub venue: Option<String>,
+    pub show_id: i32,
+    pub season_number: i32,
+    pub episode: Episode,
+    pub snippet: Option<String>,
+}
+
+impl From<EpisodeReview> for Episode {
+    fn from(episode_review: EpisodeReview) -> Self {
+        Episode {
+            episode_number: episode_review.episode_number,
+            name: episode_review.name,
+            still_path: episode_review.still_path,
+            air_date: episode_review.air_date,
+            overview: None,
+        }
+    }
+}
+
+impl EpisodeReviewReadResponse {
+    fn new(episode_review: EpisodeReview, review: Review) -> Self {
+        EpisodeReviewReadResponse {
+            review_id: episode_review.review_id,
+            user_id: episode_review.user_id,
+            date: review.date,
+            rating: review.rating,
+            title: review.title,
+            description: review.description,
+            venue: review.venue,
+            show_id: episode_review.show_id,
+            season_number: episode_review.season_number,
+            episode: Episode::from(episode_review),
+            snippet: None,
+        }
+    }
+}
+
+impl EpisodeReview {
+    pub fn find_by_review_id(
+        conn: &mut DbConnection,
+        user_id: Uuid,
+        review_id: Uuid,
+    ) -> Result<EpisodeReviewReadResponse, AppError> {
+        let (episode_review, review_details) = episode_reviews::table
+            .filter(episode_reviews::review_id.eq(review_id))
+            .filter(episode_reviews::user_id.eq(user_id))
+            .inner_join(reviews::table)
+            .select((EpisodeReview::as_select(), Review::as_select()))
+            .first::<(EpisodeReview, Review)>(conn)?;
+
+        Ok(EpisodeReviewReadResponse::new(
+            episode_review,
+            review_details,
+        ))
+    }
+
+    pub fn find_all_reviews(
+        conn: &mut DbConnection,
+        user_id: Uuid,
+        params: ReviewFindParameters,
+    ) -> Result<PaginatedResponse<EpisodeReviewReadResponse>, AppError> {
+        let search = params.search();
+        let cursor = params.decode_cursor()?;
+        let order_column = params.order_column("episode_reviews.name", "episode_reviews.air_date");
+
+        let filtered_query = || {
+            let mut query = episode_reviews::table
+                .filter(episode_reviews::user_id.eq(user_id))
+                .inner_join(reviews::table)
+                .into_boxed();
+
+            if let Some(search) = &search {
+                query = query.filter(review::search_matches(
+                    "episode_reviews.name",
+                    search.clone(),
+                ));
+            }
+
+            for filter in params.filters(episode_reviews::air_date) {
+                query = query.filter(filter);
+            }
+
+            query
+        };
+
+        let mut query = filtered_query();
+
+        let order_by = params.order_by.as_ref().unwrap_or(&ReviewOrder::Date);
+        let sort = params.sort.as_ref().unwrap_or(&ReviewSort::Desc);
+        query = match sort {
+            ReviewSort::Asc => match order_by {
+                ReviewOrder::Date => query.order(reviews::date.asc().nulls_first()),
+                ReviewOrder::MediaReleaseDate => {
+                    query.order(episode_reviews::air_date.asc().nulls_first())
+                }
+                ReviewOrder::Rating => query.order(reviews::rating.asc()),
+                ReviewOrder::MediaTitle => query.order(episode_reviews::name.asc().nulls_first()),
+            },
+            ReviewSort::Desc => match order_by {
+                ReviewOrder::Date => query.order(reviews::date.desc().nulls_last()),
+                ReviewOrder::MediaReleaseDate => {
+                    query.order(episode_reviews::air_date.desc().nulls_last())
+                }
+                ReviewOrder::Rating => query.order(reviews::rating.desc()),
+                ReviewOrder::MediaTitle => query.order(episode_reviews::name.desc().nulls_last()),
+            },
+        };
+
+        if let (None, Some(search)) = (&params.order_by, &search) {
+            query = query.order(review::search_rank("episode_reviews.name", search.clone()).desc());
+        }
+
+        query = query.then_order_by(reviews::review_id.asc());
+
+        if let Some(cursor) = &cursor {
+            query = query
+                .filter(order_column.after(cursor))
+                .limit(params.page_size() + 1);
+        } else if let Some(page) = params.page {
+            let page_size = params.page_size();
+            query = query.limit(page_size + 1).offset((page - 1) * page_size);
+        }
+
+        let reviews = query
+            .select((
+                EpisodeReview::as_select(),
+                Review::as_select(),
+                review::search_snippet(search.clone()),
+                order_column.cursor_value(),
+            ))
+            .load::<(EpisodeReview, Review, Option<String>, Option<String>)>(conn)?;
+
+        let total = if params.is_paginated() {
+            filtered_query().count().get_result::<i64>(conn)?
+        } else {
+            reviews.len() as i64
+        };
+
+        let episode_reviews: Vec<(EpisodeReviewReadResponse, Option<String>)> = reviews
+            .into_iter()
+            .map(|(episode, review, snippet, cursor_value)| {
+                (
+                    EpisodeReviewReadResponse {
+                        snippet,
+                        ..EpisodeReviewReadResponse::new(episode, review)
+                    },
+                    cursor_value,
+                )
+            })
+            .collect();
+
+        Ok(params.paginate(episode_reviews, total, |review| review.review_id))
+    }
+
+    pub fn find_by_show_season_episode(
+        conn: &mut DbConnection,
+        user_id: Uuid,
+        show_id: i32,
+        season_number: i32,
+        episode_number: i32,
+    ) -> Result<Vec<EpisodeReviewReadResponse>, AppError> {
+        let reviews = episode_reviews::table
+            .filter(episode_reviews::show_id.eq(show_id))
+            .filter(episode_reviews::season_number.eq(season_number))
+            .filter(episode_reviews::episode_number.eq(episode_number))
+            .filter(episode_reviews::user_id.eq(user_id))
+            .inner_join(reviews::table)
+            .order(reviews::date.desc().nulls_last())
+            .select((EpisodeReview::as_select(), Review::as_select()))
+            .load::<(EpisodeReview, Review)>(conn)?;
+
+        let episode_reviews: Vec<EpisodeReviewReadResponse> = reviews
+            .into_iter()
+            .map(|(episode, review)| EpisodeReviewReadResponse::new(episode, review))
+            .collect();
+        Ok(episode_reviews)
+    }
+
+    pub fn create(conn: &mut DbConnection, review: EpisodeReview) -> Result<Self, AppError> {
+        let new_review = diesel::insert_into(episode_reviews::table)
+            .values(review)
+            .get_result(conn)?;
+        Ok(new_review)
+    }
+
+    pub fn update(conn: &mut DbConnection, review: EpisodeReview) -> Result<Self, AppError> {
+        let updated_review = diesel::update(episode_reviews::table)
+            .filter(episode_reviews::review_id.eq(review.review_id))
+            .set(review)
+            .get_result(conn)?;
+        Ok(updated_review)
+    }
+
+    pub fn delete(
+        conn: &mut DbConnection,
+        user_id: Uuid,
+        show_id: i32,
+        season_number: i32,
+        episode_number: i32,
+        review_id: Uuid,
+    ) -> Result<usize, AppError> {
+        let res = diesel::delete(
+            episode_reviews::table
+                .filter(episode_reviews::review_id.eq(review_id))
+                .filter(episode_reviews::user_id.eq(user_id))
+                .filter(episode_reviews::show_id.eq(show_id))
+                .filter(episode_reviews::season_number.eq(season_number))
+                .filter(episode_reviews::episode_number.eq(episode_number)),
+        )
+        .execute(conn)?;
+        Ok(res)
+    }
+}
@@ -0,0 +1,395 @@
+use super::EpisodeReviewReadResponse;
+
+use crate::db::DbPool;
+use crate::episode_review::EpisodeReview;
+use crate::review::{Review, ReviewFindParameters};
+use crate::review_company::{ReviewCompany, ReviewCompanyDetails, ReviewCompanySummary};
+use crate::review_tag::{ReviewTag, ReviewTagDetails, ReviewTagSummary};
+use crate::season::Episode;
+use crate::tmdb::TmdbClient;
+use crate::utils::response_body::{DeleteResponse, ListResponse, Success};
+use crate::utils::{jwt::Auth, AppError};
+use actix_web::{delete, get, post, web};
+use actix_web::{put, Responder};
+use chrono::NaiveDate;
+use diesel::Connection;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SaveEpisodeReviewRequest {
+    #[schema(nullable = false)]
+    pub date: Option<NaiveDate>,
+    #[schema(nullable = false)]
+    pub rating: Option<i16>,
+    #[schema(nullable = false)]
+    pub title: Option<String>,
+    #[schema(nullable = false)]
+    pub description: Option<String>,
+    #[schema(nullable = false)]
+    pub venue: Option<String>,
+    #[schema(nullable = false)]
+    pub company: Option<Vec<ReviewCompanySummary>>,
+    #[schema(nullable = false)]
+    pub tags: Option<Vec<ReviewTagSummary>>,
+}
+
+impl SaveEpisodeReviewRequest {
+    pub fn company(mut self, company: Vec<ReviewCompanySummary>) -> Self {
+        self.company = Some(company);
+        self
+    }
+
+    pub fn tags(mut self, tags: Vec<ReviewTagSummary>) -> Self {
+        self.tags = Some(tags);
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct EpisodeReviewResponse {
+    pub review_id: Uuid,
+    pub user_id: Uuid,
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date: Option<NaiveDate>,
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rating: Option<i16>,
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub venue: Option<String>,
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub company: Option<Vec<ReviewCompanyDetails>>,
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<ReviewTagDetails>>,
+    pub show_id: i32,
+    pub season_number: i32,
+    pub episode: Episode,
+    /// Matching review text with search terms highlighted, when searching
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snippet: Option<String>,
+}
+
+impl From<EpisodeReviewReadResponse> for EpisodeReviewResponse {
+    fn from(review: EpisodeReviewReadResponse) -> Self {
+        EpisodeReviewResponse {
+            review_id: review.review_id,
+            user_id: review.user_id,
+            date: review.date,
+            rating: review.rating,
+            title: review.title,
+            description: review.description,
+            venue: review.venue,
+            company: None,
+            tags: None,
+            show_id: review.show_id,
+            season_number: review.season_number,
+            episode: review.episode,
+            snippet: review.snippet,
+        }
+    }
+}
+
+impl EpisodeReviewResponse {
+    pub fn company(mut self, company: Vec<ReviewCompanyDetails>) -> Self {
+        self.company = Some(company);
+        self
+    }
+
+    pub fn tags(mut self, tags: Vec<ReviewTagDetails>) -> Self {
+        self.tags = Some(tags);
+        self
+    }
+}
+
+#[utoipa::path(tag = "Episode Review", params(ReviewFindParameters), responses((status = OK, body = ListResponse<EpisodeReviewResponse>),(status = BAD_REQUEST)))]
+#[get("/shows/seasons/episodes/reviews")]
+async fn find_all(
+    pool: web::Data<DbPool>,
+    auth: Auth,
+    params: web::Query<ReviewFindParameters>,
+) -> actix_web::Result<impl Responder> {
+    let paginated = params.is_paginated();
+    let reviews = web::block(move || {
+        let mut conn = pool.get()?;
+        EpisodeReview::find_all_reviews(&mut conn, auth.user_id, params.into_inner())
+    })
+    .await??;
+
+    Ok(Success::new(ListResponse::new(
+        reviews.map(EpisodeReviewResponse::from),
+        paginated,
+    )))
+}
+
+#[utoipa::path(tag = "Episode Review", responses((status = OK, body = Vec<EpisodeReviewResponse>)))]
+#[get("/shows/{show_id}/seasons/{season_number}/episodes/{episode_number}/reviews")]
+async fn find_by_show_season_episode(
+    pool: web::Data<DbPool>,
+    auth: Auth,
+    path: web::Path<(i32, i32, i32)>,
+) -> actix_web::Result<impl Responder> {
+    let (show_id, season_number, episode_number) = path.into_inner();
+
+    let reviews = web::block(move || {
+        let mut conn = pool.get()?;
+        EpisodeReview::find_by_show_season_episode(
+            &mut conn,
+            auth.user_id,
+            show_id,
+            season_number,
+            episode_number,
+        )
+    })
+    .await??;
+
+    Ok(Success::new(
+        reviews
+            .into_iter()
+            .map(EpisodeReviewResponse::from)
+            .collect::<Vec<EpisodeReviewResponse>>(),
+    ))
+}
+
+#[utoipa::path(tag = "Episode Review", responses((status = OK, body = EpisodeReviewResponse)))]
+#[get("/shows/seasons/episodes/reviews/{review_id}")]
+async fn find_by_review_id(
+    pool: web::Data<DbPool>,
+    auth: Auth,
+    review_id: web::Path<Uuid>,
+) -> actix_web::Result<impl Responder> {
+    let review = web::block(move || {
+        let mut conn = pool.get()?;
+        let review =
+            EpisodeReview::find_by_review_id(&mut conn, auth.user_id, review_id.into_inner())?;
+        let company = ReviewCompany::find_by_review(&mut conn, review.review_id)?;
+        let tags = ReviewTag::find_by_review(&mut conn, review.review_id)?;
+
+        Ok::<EpisodeReviewResponse, AppError>(
+            EpisodeReviewResponse::from(review)
+                .company(company)
+                .tags(tags),
+        )
+    })
+    .await??;
+
+    Ok(Success::new(review))
+}
+
+#[utoipa::path(tag = "Episode Review", responses((status = OK, body = EpisodeReviewResponse)))]
+#[post("/shows/{show_id}/seasons/{season_number}/episodes/{episode_number}/reviews")]
+async fn create(
+    pool: web::Data<DbPool>,
+    client: web::Data<TmdbClient>,
+    auth: Auth,
+    review: web::Json<SaveEpisodeReviewRequest>,
+    path: web::Path<(i32, i32, i32)>,
+) -> actix_web::Result<impl Responder> {
+    let (show_id, season_number, episode_number) = path.into_inner();
+    let review = review.into_inner();
+
+    let episode = Episode::find(&client, &show_id, &season_number, &episode_number).await?;
+
+    let review_id = Uuid::new_v4();
+
+    let review_to_save = Review {
+        review_id,
+        user_id: auth.user_id,
+        date: review.date,
+        rating: review.rating,
+        title: review.title,
+        description: review.description,
+        venue: review.venue,
+    };
+
+    let episode_review_to_save = EpisodeReview {
+        review_id,
+        user_id: auth.user_id,
+        show_id,
+        season_number,
+        episode_number,
+        name: episode.name,
+        still_path: episode.still_path,
+        air_date: episode.air_date,
+    };
+
+    let review = web::block(move || {
+        let mut conn = pool.get()?;
+
+        conn.transaction::<EpisodeReviewResponse, AppError, _>(|conn| {
+            let created_review = Review::create(conn, review_to_save)?;
+            let created_episode_review = EpisodeReview::create(conn, episode_review_to_save)?;
+
+            let company =
+                ReviewCompany::replace(conn, created_review.review_id, review.company.as_ref())?;
+            let tags = ReviewTag::replace(
+                conn,
+                auth.user_id,
+                created_review.review_id,
+                review.tags.as_ref(),
+            )?;
+
+            let review_response = EpisodeReviewResponse {
+                review_id: created_review.review_id,
+                user_id: created_review.user_id,
+                date: created_review.date,
+                rating: created_review.rating,
+                title: created_review.title,
+                description: created_review.description,
+                venue: created_review.venue,
+                show_id: created_episode_review.show_id,
+                season_number: created_episode_review.season_number,
+                episode: Episode::from(created_episode_review),
+                company: Some(company),
+                tags: Some(tags),
+                snippet: None,
+            };
+
+            Ok(review_response)
+        })
+    })
+    .await??;
+
+    Ok(Success::new(review))
+}
+
+#[utoipa::path(tag = "Episode Review", responses((status = OK, body = EpisodeReviewResponse)))]
+#[put("/shows/{show_id}/seasons/{season_number}/episodes/{episode_number}/reviews/{review_id}")]
+async fn update(
+    pool: web::Data<DbPool>,
+    client: web::Data<TmdbClient>,
+    auth: Auth,
+    review: web::Json<SaveEpisodeReviewRequest>,
+    path: web::Path<(i32, i32, i32, Uuid)>,
+) -> actix_web::Result<impl Responder> {
+    let (show_id, season_number, episode_number, review_id) = path.into_inner();
+    let review = review.into_inner();
+
+    let episode = Episode::find(&client, &show_id, &season_number, &episode_number).await?;
+
+    let review = web::block(move || {
+        let mut conn = pool.get()?;
+
+        let existing_review = EpisodeReview::find_by_review_id(&mut conn, auth.user_id, review_id)?;
+
+        if show_id != existing_review.show_id
+            || season_number != existing_review.season_number
+            || episode_number != existing_review.episode.episode_number
+        {
+            return Err(AppError::external(
+                400,
+                "Review show, season and episode cannot be changed",
+            ));
+        }
+
+        let review_to_save = Review {
+            review_id: existing_review.review_id,
+            user_id: existing_review.user_id,
+            date: review.date,
+            rating: review.rating,
+            title: review.title,
+            description: review.description,
+            venue: review.venue,
+        };
+
+        let episode_review_to_save = EpisodeReview {
+            review_id: existing_review.review_id,
+            user_id: auth.user_id,
+            show_id: existing_review.show_id,
+            season_number: existing_review.season_number,
+            episode_number: existing_review.episode.episode_number,
+            name: episode.name,
+            still_path: episode.still_path,
+            air_date: episode.air_date,
+        };
+
+        conn.transaction(|conn| {
+            let updated_review = Review::update(conn, review_to_save)?;
+
+            let updated_episode_review = EpisodeReview::update(conn, episode_review_to_save)?;
+
+            let company =
+                ReviewCompany::replace(conn, updated_review.review_id, review.company.as_ref())?;
+            let tags = ReviewTag::replace(
+                conn,
+                auth.user_id,
+                updated_review.review_id,
+                review.tags.as_ref(),
+            )?;
+
+            let review_response = EpisodeReviewResponse {
+                review_id: updated_review.review_id,
+                user_id: updated_review.user_id,
+                date: updated_review.date,
+                rating: updated_review.rating,
+                title: updated_review.title,
+                description: updated_review.description,
+                venue: updated_review.venue,
+                show_id: updated_episode_review.show_id,
+                season_number: updated_episode_review.season_number,
+                episode: Episode::from(updated_episode_review),
+                company: Some(company),
+                tags: Some(tags),
+                snippet: None,
+            };
+
+            Ok(review_response)
+        })
+    })
+    .await??;
+
+    Ok(Success::new(review))
+}
+
+#[utoipa::path(tag = "Episode Review", responses((status = OK, body = DeleteResponse)))]
+#[delete("/shows/{show_id}/seasons/{season_number}/episodes/{episode_number}/reviews/{review_id}")]
+async fn delete(
+    pool: web::Data<DbPool>,
+    auth: Auth,
+    path: web::Path<(i32, i32, i32, Uuid)>,
+) -> actix_web::Result<impl Responder> {
+    let (show_id, season_number, episode_number, review_id) = path.into_inner();
+
+    let count = web::block(move || {
+        let mut conn = pool.get()?;
+
+        conn.transaction::<usize, AppError, _>(|conn| {
+            let count = EpisodeReview::delete(
+                conn,
+                auth.user_id,
+                show_id,
+                season_number,
+                episode_number,
+                review_id,
+            )?;
+
+            if count == 0 {
+                return Ok(count);
+            }
+
+            ReviewCompany::delete_by_review(conn, review_id)?;
+            ReviewTag::delete_by_review(conn, review_id)?;
+            Review::delete(conn, auth.user_id, review_id)
+        })
+    })
+    .await??;
+
+    if count == 0 {
+        return Err(AppError::external(404, "Review not found"))?;
+    }
+
+    Ok(Success::new(DeleteResponse { count }))
+}
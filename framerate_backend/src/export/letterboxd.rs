@@ -0,0 +1,88 @@
+use crate::db::DbConnection;
+use crate::schema::{movie_reviews, reviews};
+use crate::utils::AppError;
+use chrono::{Datelike, NaiveDate};
+use diesel::prelude::*;
+use serde::Serialize;
+use std::collections::HashSet;
+use uuid::Uuid;
+
+/// A row of Letterboxd's import format
+#[derive(Serialize)]
+struct LetterboxdRow {
+    #[serde(rename = "Title")]
+    title: String,
+    #[serde(rename = "Year")]
+    year: Option<i32>,
+    #[serde(rename = "imdbID")]
+    imdb_id: Option<String>,
+    #[serde(rename = "tmdbID")]
+    tmdb_id: i32,
+    #[serde(rename = "WatchedDate")]
+    watched_date: Option<NaiveDate>,
+    #[serde(rename = "Rating10")]
+    rating: Option<i16>,
+    #[serde(rename = "Rewatch")]
+    rewatch: bool,
+    #[serde(rename = "Review")]
+    review: Option<String>,
+}
+
+/// Converts a rating out of 100 to Letterboxd's rating out of 10, which has no zero
+fn rating_out_of_ten(rating: i16) -> Option<i16> {
+    match (rating as f32 / 10.0).round() as i16 {
+        0 => None,
+        rating => Some(rating.min(10)),
+    }
+}
+
+/// Writes the user's movie reviews as a CSV file Letterboxd can import. Reviews are
+/// written oldest first, and any later review of the same movie is marked a rewatch
+pub fn write_csv(conn: &mut DbConnection, user_id: Uuid) -> Result<String, AppError> {
+    let diary = movie_reviews::table
+        .inner_join(reviews::table)
+        .filter(movie_reviews::user_id.eq(user_id))
+        .order((reviews::date.asc().nulls_first(), reviews::review_id.asc()))
+        .select((
+            movie_reviews::movie_id,
+            movie_reviews::title,
+            movie_reviews::imdb_id,
+            movie_reviews::release_date,
+            reviews::date,
+            reviews::rating,
+            reviews::description,
+        ))
+        .load::<(
+            i32,
+            String,
+            Option<String>,
+            Option<NaiveDate>,
+            Option<NaiveDate>,
+            Option<i16>,
+            Option<String>,
+        )>(conn)?;
+
+    let mut watched = HashSet::new();
+    let mut writer = csv::Writer::from_writer(vec![]);
+
+    for (movie_id, title, imdb_id, release_date, date, rating, description) in diary {
+        writer
+            .serialize(LetterboxdRow {
+                title,
+                year: release_date.map(|date| date.year()),
+                imdb_id,
+                tmdb_id: movie_id,
+                watched_date: date,
+                rating: rating.and_then(rating_out_of_ten),
+                rewatch: !watched.insert(movie_id),
+                review: description,
+            })
+            .map_err(|_| AppError::external(500, "Unable to create export"))?;
+    }
+
+    let file = writer
+        .into_inner()
+        .map_err(|_| AppError::external(500, "Unable to create export"))?;
+
+    String::from_utf8(file).map_err(|_| AppError::external(500, "Unable to create export"))
+}
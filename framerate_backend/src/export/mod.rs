@@ -0,0 +1,7 @@
+pub mod letterboxd;
+pub mod model;
+pub mod routes;
+pub mod stream;
+
+pub use model::*;
+pub use routes::*;
@@ -0,0 +1,559 @@
+use crate::collection::{Collection, CollectionRules, EntrySort};
+use crate::company::Company;
+use crate::db::DbConnection;
+use crate::episode_review::EpisodeReview;
+use crate::movie_entry::MovieEntry;
+use crate::movie_review::MovieReview;
+use crate::review::Review;
+use crate::review_company::ReviewCompany;
+use crate::review_tag::ReviewTag;
+use crate::schema::{
+    collections, company, episode_reviews, movie_entries, movie_reviews, review_company,
+    review_tag, reviews, season_reviews, show_entries, show_reviews, tags, users,
+};
+use crate::season_review::SeasonReview;
+use crate::show_entry::ShowEntry;
+use crate::show_review::ShowReview;
+use crate::tag::Tag;
+use crate::user::User;
+use crate::utils::{archive, AppError};
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io::{Cursor, Read, Seek, Write};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use zip::write::SimpleFileOptions;
+use zip::{ZipArchive, ZipWriter};
+
+pub const EXPORT_FORMAT: &str = "framerate-export";
+pub const EXPORT_VERSION: i32 = 1;
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportManifest {
+    pub format: String,
+    pub version: i32,
+    pub exported_at: NaiveDateTime,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportedProfile {
+    pub email: String,
+    pub first_name: String,
+    pub last_name: String,
+    pub avatar_uri: Option<String>,
+    pub public: bool,
+    pub date_created: NaiveDateTime,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportedCollection {
+    #[serde(flatten)]
+    pub collection: Collection,
+    pub movie_entries: Vec<MovieEntry>,
+    pub show_entries: Vec<ShowEntry>,
+}
+
+/// The ids restored data was given, by its id in the export
+#[derive(Default)]
+struct RestoredIds {
+    companies: HashMap<Uuid, Uuid>,
+    tags: HashMap<Uuid, Uuid>,
+    collections: HashMap<Uuid, Uuid>,
+}
+
+impl RestoredIds {
+    /// Points the rules of a smart collection at the restored companies, tags and
+    /// collections. A reference to something missing from the export becomes the nil
+    /// id, so it still matches nothing
+    fn remap_rules(&self, rules: serde_json::Value) -> Result<serde_json::Value, AppError> {
+        let mut rules: CollectionRules = serde_json::from_value(rules)
+            .map_err(|_| AppError::external(400, "Invalid collections.json"))?;
+
+        let remap = |ids: &HashMap<Uuid, Uuid>, id: Uuid| ids.get(&id).copied().unwrap_or_default();
+        rules.company_id = rules.company_id.map(|id| remap(&self.companies, id));
+        rules.tag_id = rules.tag_id.map(|id| remap(&self.tags, id));
+        rules.collection_id = rules.collection_id.map(|id| remap(&self.collections, id));
+
+        Ok(serde_json::json!(rules))
+    }
+}
+
+/// Everything a user has saved. Each field is written to its own JSON file in the
+/// export archive
+pub struct UserExport {
+    pub manifest: ExportManifest,
+    pub profile: ExportedProfile,
+    pub configuration: serde_json::Value,
+    pub reviews: Vec<Review>,
+    pub movie_reviews: Vec<MovieReview>,
+    pub show_reviews: Vec<ShowReview>,
+    pub season_reviews: Vec<SeasonReview>,
+    pub episode_reviews: Vec<EpisodeReview>,
+    pub companies: Vec<Company>,
+    pub review_companies: Vec<ReviewCompany>,
+    pub tags: Vec<Tag>,
+    pub review_tags: Vec<ReviewTag>,
+    pub collections: Vec<ExportedCollection>,
+    /// Default collections, which are merged into the existing defaults on import
+    pub watchlists: Vec<ExportedCollection>,
+}
+
+#[derive(Default, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UserImportReport {
+    pub reviews: i64,
+    pub companies: i64,
+    pub tags: i64,
+    pub collections: i64,
+    pub entries: i64,
+}
+
+fn write_json<W: Write + Seek, T: Serialize>(
+    zip: &mut ZipWriter<W>,
+    name: &str,
+    value: &T,
+) -> Result<(), AppError> {
+    zip.start_file(name, SimpleFileOptions::default())
+        .map_err(|_| AppError::external(500, "Unable to create export"))?;
+    serde_json::to_writer_pretty(&mut *zip, value)
+        .map_err(|_| AppError::external(500, "Unable to create export"))?;
+    zip.write_all(b"\n")
+        .map_err(|_| AppError::external(500, "Unable to create export"))?;
+    Ok(())
+}
+
+fn read_json<T: DeserializeOwned>(
+    archive: &mut ZipArchive<Cursor<&[u8]>>,
+    name: &str,
+) -> Result<T, AppError> {
+    let file = archive
+        .by_name(name)
+        .map_err(|_| AppError::external(400, &format!("Export is missing {name}")))?;
+    archive::read_json(file, name)
+}
+
+impl ExportedCollection {
+    fn find_all(
+        conn: &mut DbConnection,
+        user_id: Uuid,
+        defaults: bool,
+    ) -> Result<Vec<Self>, AppError> {
+        let collections = collections::table
+            .filter(collections::user_id.eq(user_id))
+            .filter(collections::default_for.is_not_null().eq(defaults))
+            .order(collections::name.asc())
+            .select(Collection::as_select())
+            .load(conn)?;
+
+        collections
+            .into_iter()
+            .map(|collection| {
+                let movie_entries = MovieEntry::find_all(
+                    conn,
+                    user_id,
+                    collection.collection_id,
+                    EntrySort::Position,
+                )?;
+                let show_entries = ShowEntry::find_all(
+                    conn,
+                    user_id,
+                    collection.collection_id,
+                    EntrySort::Position,
+                )?;
+                Ok(ExportedCollection {
+                    collection,
+                    movie_entries,
+                    show_entries,
+                })
+            })
+            .collect()
+    }
+
+    /// The id the collection is restored with, which for a default is the id of the
+    /// user's existing default of the same kind
+    fn restored_id(&self, conn: &mut DbConnection, user_id: Uuid) -> Uuid {
+        let existing = match &self.collection.default_for {
+            Some(default_for) => {
+                Collection::find_default(conn, user_id, &self.collection.media_type, default_for)
+                    .ok()
+            }
+            None => None,
+        };
+
+        existing.map_or_else(Uuid::new_v4, |collection| collection.collection_id)
+    }
+
+    /// Saves the collection and its entries for the user. Defaults are merged into the
+    /// user's existing default of the same kind
+    fn restore(
+        self,
+        conn: &mut DbConnection,
+        user_id: Uuid,
+        ids: &RestoredIds,
+    ) -> Result<i64, AppError> {
+        let collection_id = ids.collections[&self.collection.collection_id];
+
+        if Collection::find(conn, user_id, &self.collection.media_type, &collection_id).is_err() {
+            let rules = self
+                .collection
+                .rules
+                .map(|rules| ids.remap_rules(rules))
+                .transpose()?;
+
+            Collection::create(
+                conn,
+                Collection {
+                    collection_id,
+                    user_id,
+                    rules,
+                    ..self.collection
+                },
+            )?;
+        }
+
+        let movie_entries: Vec<MovieEntry> = self
+            .movie_entries
+            .into_iter()
+            .map(|entry| MovieEntry {
+                collection_id,
+                user_id,
+                ..entry
+            })
+            .collect();
+        let show_entries: Vec<ShowEntry> = self
+            .show_entries
+            .into_iter()
+            .map(|entry| ShowEntry {
+                collection_id,
+                user_id,
+                ..entry
+            })
+            .collect();
+
+        let count = diesel::insert_into(movie_entries::table)
+            .values(movie_entries)
+            .on_conflict_do_nothing()
+            .execute(conn)?
+            + diesel::insert_into(show_entries::table)
+                .values(show_entries)
+                .on_conflict_do_nothing()
+                .execute(conn)?;
+
+        Ok(count as i64)
+    }
+}
+
+impl UserExport {
+    pub fn find(conn: &mut DbConnection, user_id: Uuid) -> Result<Self, AppError> {
+        let user = users::table
+            .filter(users::user_id.eq(user_id))
+            .select(User::as_select())
+            .first(conn)?;
+
+        let reviews = reviews::table
+            .filter(reviews::user_id.eq(user_id))
+            .order(reviews::date.asc().nulls_first())
+            .select(Review::as_select())
+            .load(conn)?;
+        let review_ids: Vec<Uuid> = reviews.iter().map(|review| review.review_id).collect();
+
+        let movie_reviews = movie_reviews::table
+            .filter(movie_reviews::user_id.eq(user_id))
+            .select(MovieReview::as_select())
+            .load(conn)?;
+        let show_reviews = show_reviews::table
+            .filter(show_reviews::user_id.eq(user_id))
+            .select(ShowReview::as_select())
+            .load(conn)?;
+        let season_reviews = season_reviews::table
+            .filter(season_reviews::user_id.eq(user_id))
+            .select(SeasonReview::as_select())
+            .load(conn)?;
+        let episode_reviews = episode_reviews::table
+            .filter(episode_reviews::user_id.eq(user_id))
+            .select(EpisodeReview::as_select())
+            .load(conn)?;
+
+        let review_companies = review_company::table
+            .filter(review_company::review_id.eq_any(&review_ids))
+            .select(ReviewCompany::as_select())
+            .load(conn)?;
+        let review_tags = review_tag::table
+            .filter(review_tag::review_id.eq_any(&review_ids))
+            .select(ReviewTag::as_select())
+            .load(conn)?;
+
+        Ok(UserExport {
+            manifest: ExportManifest {
+                format: EXPORT_FORMAT.to_string(),
+                version: EXPORT_VERSION,
+                exported_at: chrono::Local::now().naive_local(),
+            },
+            profile: ExportedProfile {
+                email: user.email,
+                first_name: user.first_name,
+                last_name: user.last_name,
+                avatar_uri: user.avatar_uri,
+                public: user.public,
+                date_created: user.date_created,
+            },
+            configuration: user.configuration,
+            reviews,
+            movie_reviews,
+            show_reviews,
+            season_reviews,
+            episode_reviews,
+            companies: Company::find_all(conn, &user_id)?,
+            review_companies,
+            tags: Tag::find_all(conn, &user_id)?,
+            review_tags,
+            collections: ExportedCollection::find_all(conn, user_id, false)?,
+            watchlists: ExportedCollection::find_all(conn, user_id, true)?,
+        })
+    }
+
+    /// Writes the export as a ZIP archive of JSON files
+    pub fn to_zip(&self) -> Result<Vec<u8>, AppError> {
+        Ok(self.write_zip(Cursor::new(Vec::new()))?.into_inner())
+    }
+
+    /// Writes the export as a ZIP archive of JSON files to `writer`, which is flushed
+    /// after each file
+    pub fn write_zip<W: Read + Write + Seek>(&self, writer: W) -> Result<W, AppError> {
+        let mut zip = ZipWriter::new(writer);
+        zip.set_flush_on_finish_file(true);
+
+        write_json(&mut zip, "manifest.json", &self.manifest)?;
+        write_json(&mut zip, "profile.json", &self.profile)?;
+        write_json(&mut zip, "configuration.json", &self.configuration)?;
+        write_json(&mut zip, "reviews.json", &self.reviews)?;
+        write_json(&mut zip, "movie_reviews.json", &self.movie_reviews)?;
+        write_json(&mut zip, "show_reviews.json", &self.show_reviews)?;
+        write_json(&mut zip, "season_reviews.json", &self.season_reviews)?;
+        write_json(&mut zip, "episode_reviews.json", &self.episode_reviews)?;
+        write_json(&mut zip, "companies.json", &self.companies)?;
+        write_json(&mut zip, "review_companies.json", &self.review_companies)?;
+        write_json(&mut zip, "tags.json", &self.tags)?;
+        write_json(&mut zip, "review_tags.json", &self.review_tags)?;
+        write_json(&mut zip, "collections.json", &self.collections)?;
+        write_json(&mut zip, "watchlists.json", &self.watchlists)?;
+
+        let mut writer = zip
+            .finish()
+            .map_err(|_| AppError::external(500, "Unable to create export"))?;
+        writer
+            .flush()
+            .map_err(|_| AppError::external(500, "Unable to create export"))?;
+
+        Ok(writer)
+    }
+
+    /// Reads an archive written by `to_zip`, from this or another server
+    pub fn from_zip(archive: &[u8]) -> Result<Self, AppError> {
+        let mut archive = ZipArchive::new(Cursor::new(archive))
+            .map_err(|_| AppError::external(400, "Expected a Framerate export"))?;
+
+        let manifest: ExportManifest = read_json(&mut archive, "manifest.json")?;
+        if manifest.format != EXPORT_FORMAT {
+            return Err(AppError::external(400, "Expected a Framerate export"));
+        }
+        if manifest.version > EXPORT_VERSION {
+            return Err(AppError::external(400, "Unsupported export version"));
+        }
+
+        Ok(UserExport {
+            manifest,
+            profile: read_json(&mut archive, "profile.json")?,
+            configuration: read_json(&mut archive, "configuration.json")?,
+            reviews: read_json(&mut archive, "reviews.json")?,
+            movie_reviews: read_json(&mut archive, "movie_reviews.json")?,
+            show_reviews: read_json(&mut archive, "show_reviews.json")?,
+            season_reviews: read_json(&mut archive, "season_reviews.json")?,
+            episode_reviews: read_json(&mut archive, "episode_reviews.json")?,
+            companies: read_json(&mut archive, "companies.json")?,
+            review_companies: read_json(&mut archive, "review_companies.json")?,
+            tags: read_json(&mut archive, "tags.json")?,
+            review_tags: read_json(&mut archive, "review_tags.json")?,
+            collections: read_json(&mut archive, "collections.json")?,
+            watchlists: read_json(&mut archive, "watchlists.json")?,
+        })
+    }
+
+    /// Saves the export for the user. Everything is given new ids so an export can be
+    /// imported alongside existing data, tags are matched by name, and companies lose
+    /// their link to accounts on the original server
+    pub fn restore(
+        self,
+        conn: &mut DbConnection,
+        user_id: Uuid,
+    ) -> Result<UserImportReport, AppError> {
+        conn.transaction::<UserImportReport, AppError, _>(|conn| {
+            let mut report = UserImportReport::default();
+
+            diesel::update(users::table.filter(users::user_id.eq(user_id)))
+                .set((
+                    users::first_name.eq(self.profile.first_name),
+                    users::last_name.eq(self.profile.last_name),
+                    users::avatar_uri.eq(self.profile.avatar_uri),
+                    users::public.eq(self.profile.public),
+                    users::configuration.eq(self.configuration),
+                ))
+                .execute(conn)?;
+
+            let mut ids = RestoredIds::default();
+
+            for exported in self.companies {
+                let company_id = Uuid::new_v4();
+                ids.companies.insert(exported.company_id, company_id);
+                diesel::insert_into(company::table)
+                    .values(Company {
+                        company_id,
+                        created_by: user_id,
+                        user_id: None,
+                        ..exported
+                    })
+                    .execute(conn)?;
+                report.companies += 1;
+            }
+
+            let existing_tags: HashMap<String, Uuid> = Tag::find_all(conn, &user_id)?
+                .into_iter()
+                .map(|tag| (tag.name, tag.tag_id))
+                .collect();
+            for exported in self.tags {
+                let tag_id = match existing_tags.get(&exported.name) {
+                    Some(tag_id) => *tag_id,
+                    None => {
+                        let tag_id = Uuid::new_v4();
+                        diesel::insert_into(tags::table)
+                            .values(Tag {
+                                tag_id,
+                                user_id,
+                                ..exported
+                            })
+                            .execute(conn)?;
+                        report.tags += 1;
+                        tag_id
+                    }
+                };
+                ids.tags.insert(exported.tag_id, tag_id);
+            }
+
+            let mut review_ids = HashMap::new();
+            for exported in self.reviews {
+                let review_id = Uuid::new_v4();
+                review_ids.insert(exported.review_id, review_id);
+                Review::create(
+                    conn,
+                    Review {
+                        review_id,
+                        user_id,
+                        ..exported
+                    },
+                )?;
+                report.reviews += 1;
+            }
+
+            for exported in self.movie_reviews {
+                if let Some(review_id) = review_ids.get(&exported.review_id) {
+                    MovieReview::create(
+                        conn,
+                        MovieReview {
+                            review_id: *review_id,
+                            user_id,
+                            ..exported
+                        },
+                    )?;
+                }
+            }
+            for exported in self.show_reviews {
+                if let Some(review_id) = review_ids.get(&exported.review_id) {
+                    ShowReview::create(
+                        conn,
+                        ShowReview {
+                            review_id: *review_id,
+                            user_id,
+                            ..exported
+                        },
+                    )?;
+                }
+            }
+            for exported in self.season_reviews {
+                if let Some(review_id) = review_ids.get(&exported.review_id) {
+                    SeasonReview::create(
+                        conn,
+                        SeasonReview {
+                            review_id: *review_id,
+                            user_id,
+                            ..exported
+                        },
+                    )?;
+                }
+            }
+            for exported in self.episode_reviews {
+                if let Some(review_id) = review_ids.get(&exported.review_id) {
+                    EpisodeReview::create(
+                        conn,
+                        EpisodeReview {
+                            review_id: *review_id,
+                            user_id,
+                            ..exported
+                        },
+                    )?;
+                }
+            }
+
+            let review_companies: Vec<ReviewCompany> = self
+                .review_companies
+                .into_iter()
+                .filter_map(|exported| {
+                    Some(ReviewCompany {
+                        review_id: *review_ids.get(&exported.review_id)?,
+                        company_id: *ids.companies.get(&exported.company_id)?,
+                    })
+                })
+                .collect();
+            diesel::insert_into(review_company::table)
+                .values(review_companies)
+                .execute(conn)?;
+
+            let review_tags: Vec<ReviewTag> = self
+                .review_tags
+                .into_iter()
+                .filter_map(|exported| {
+                    Some(ReviewTag {
+                        review_id: *review_ids.get(&exported.review_id)?,
+                        tag_id: *ids.tags.get(&exported.tag_id)?,
+                    })
+                })
+                .collect();
+            diesel::insert_into(review_tag::table)
+                .values(review_tags)
+                .execute(conn)?;
+
+            // Smart collections can refer to collections restored after them
+            for collection in self.collections.iter().chain(&self.watchlists) {
+                ids.collections.insert(
+                    collection.collection.collection_id,
+                    collection.restored_id(conn, user_id),
+                );
+            }
+
+            for collection in self.collections {
+                report.entries += collection.restore(conn, user_id, &ids)?;
+                report.collections += 1;
+            }
+            for watchlist in self.watchlists {
+                report.entries += watchlist.restore(conn, user_id, &ids)?;
+            }
+
+            Ok(report)
+        })
+    }
+}
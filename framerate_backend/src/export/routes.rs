@@ -0,0 +1,100 @@
+use super::{letterboxd, stream::ZipStream, UserExport, UserImportReport};
+use crate::db::DbPool;
+use crate::utils::{jwt::Auth, response_body::Success, AppError};
+use actix_web::http::header::{ContentDisposition, DispositionParam, DispositionType};
+use actix_web::{get, post, rt, web, HttpResponse, Responder};
+use uuid::Uuid;
+
+#[utoipa::path(tag = "User", responses((status = OK, body = Vec<u8>, content_type = "application/zip"),(status = NOT_FOUND)))]
+#[get("/users/{user_id}/export")]
+async fn export_data(
+    pool: web::Data<DbPool>,
+    auth: Auth,
+    user_id: web::Path<Uuid>,
+) -> actix_web::Result<impl Responder> {
+    if auth.user_id != *user_id {
+        return Err(AppError::external(404, "User not found"))?;
+    }
+
+    let export = web::block(move || {
+        let mut conn = pool.get()?;
+        UserExport::find(&mut conn, user_id.into_inner())
+    })
+    .await??;
+
+    let file_name = format!(
+        "framerate-export-{}.zip",
+        export.manifest.exported_at.format("%Y-%m-%d")
+    );
+
+    // The archive is sent as each of its files is written, so a failure part way
+    // through can only end the download
+    let (mut writer, body) = ZipStream::new();
+    rt::task::spawn_blocking(move || {
+        if let Err(error) = export.write_zip(&mut writer) {
+            writer.fail(error);
+        }
+    });
+
+    Ok(HttpResponse::Ok()
+        .content_type("application/zip")
+        .insert_header(ContentDisposition {
+            disposition: DispositionType::Attachment,
+            parameters: vec![DispositionParam::Filename(file_name)],
+        })
+        .streaming(body))
+}
+
+#[utoipa::path(tag = "User", responses((status = OK, body = String, content_type = "text/csv"),(status = NOT_FOUND)))]
+#[get("/users/{user_id}/export/letterboxd")]
+async fn export_letterboxd(
+    pool: web::Data<DbPool>,
+    auth: Auth,
+    user_id: web::Path<Uuid>,
+) -> actix_web::Result<impl Responder> {
+    if auth.user_id != *user_id {
+        return Err(AppError::external(404, "User not found"))?;
+    }
+
+    let file = web::block(move || {
+        let mut conn = pool.get()?;
+        letterboxd::write_csv(&mut conn, user_id.into_inner())
+    })
+    .await??;
+
+    let file_name = format!(
+        "framerate-letterboxd-{}.csv",
+        chrono::Local::now().format("%Y-%m-%d")
+    );
+
+    Ok(HttpResponse::Ok()
+        .content_type("text/csv")
+        .insert_header(ContentDisposition {
+            disposition: DispositionType::Attachment,
+            parameters: vec![DispositionParam::Filename(file_name)],
+        })
+        .body(file))
+}
+
+#[utoipa::path(tag = "User", request_body(content = Vec<u8>, content_type = "application/zip"), responses((status = OK, body = UserImportReport),(status = BAD_REQUEST),(status = NOT_FOUND)))]
+#[post("/users/{user_id}/import")]
+async fn import_data(
+    pool: web::Data<DbPool>,
+    auth: Auth,
+    user_id: web::Path<Uuid>,
+    archive: web::Bytes,
+) -> actix_web::Result<impl Responder> {
+    if auth.user_id != *user_id {
+        return Err(AppError::external(404, "User not found"))?;
+    }
+
+    let export = UserExport::from_zip(&archive)?;
+
+    let report = web::block(move || {
+        let mut conn = pool.get()?;
+        export.restore(&mut conn, user_id.into_inner())
+    })
+    .await??;
+
+    Ok(Success::new(report))
+}
@@ -0,0 +1,111 @@
+use crate::utils::AppError;
+use actix_web::web::Bytes;
+use futures_util::stream::{self, Stream};
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use tokio::sync::mpsc;
+
+/// Chunks waiting to be sent before the archive writer has to wait for the client
+const PENDING_CHUNKS: usize = 4;
+
+/// Passes a ZIP archive on to a response body while it is written. The archive writer
+/// only seeks back within the file it is writing, and flushes once each file is
+/// finished, so only that file is held in memory
+pub struct ZipStream {
+    sender: mpsc::Sender<Result<Bytes, AppError>>,
+    buffer: Vec<u8>,
+    /// Position in the archive of the start of `buffer`
+    offset: u64,
+    position: u64,
+}
+
+impl ZipStream {
+    /// Creates the writer along with the body its chunks are sent to
+    pub fn new() -> (Self, impl Stream<Item = Result<Bytes, actix_web::Error>>) {
+        let (sender, receiver) = mpsc::channel::<Result<Bytes, AppError>>(PENDING_CHUNKS);
+
+        let body = stream::unfold(receiver, |mut receiver| async move {
+            let chunk = receiver.recv().await?;
+            Some((chunk.map_err(actix_web::Error::from), receiver))
+        });
+
+        let writer = ZipStream {
+            sender,
+            buffer: Vec::new(),
+            offset: 0,
+            position: 0,
+        };
+
+        (writer, body)
+    }
+
+    /// Ends the body with an error, as the response has already started
+    pub fn fail(&self, error: AppError) {
+        let _ = self.sender.blocking_send(Err(error));
+    }
+}
+
+/// Reads back the part of the archive not yet sent, which the archive writer needs to
+/// be able to do
+impl Read for ZipStream {
+    fn read(&mut self, data: &mut [u8]) -> io::Result<usize> {
+        let start = ((self.position - self.offset) as usize).min(self.buffer.len());
+        let available = &self.buffer[start..];
+        let count = available.len().min(data.len());
+        data[..count].copy_from_slice(&available[..count]);
+        self.position += count as u64;
+        Ok(count)
+    }
+}
+
+impl Write for ZipStream {
+    fn write(&mut self, data: &[u8]) -> io::Result<usize> {
+        let start = (self.position - self.offset) as usize;
+        let end = start + data.len();
+        if end > self.buffer.len() {
+            self.buffer.resize(end, 0);
+        }
+        self.buffer[start..end].copy_from_slice(data);
+        self.position += data.len() as u64;
+        Ok(data.len())
+    }
+
+    /// Sends everything before the current position, which the archive writer won't
+    /// return to
+    fn flush(&mut self) -> io::Result<()> {
+        let rest = self
+            .buffer
+            .split_off((self.position - self.offset) as usize);
+        let chunk = std::mem::replace(&mut self.buffer, rest);
+        self.offset = self.position;
+
+        if chunk.is_empty() {
+            return Ok(());
+        }
+
+        self.sender
+            .blocking_send(Ok(Bytes::from(chunk)))
+            .map_err(|_| io::Error::new(io::ErrorKind::BrokenPipe, "Download was cancelled"))
+    }
+}
+
+impl Seek for ZipStream {
+    fn seek(&mut self, position: SeekFrom) -> io::Result<u64> {
+        let end = self.offset + self.buffer.len() as u64;
+        let position = match position {
+            SeekFrom::Start(position) => Some(position),
+            SeekFrom::End(delta) => end.checked_add_signed(delta),
+            SeekFrom::Current(delta) => self.position.checked_add_signed(delta),
+        };
+
+        match position {
+            Some(position) if position >= self.offset => {
+                self.position = position;
+                Ok(position)
+            }
+            _ => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "Cannot seek into data already sent",
+            )),
+        }
+    }
+}
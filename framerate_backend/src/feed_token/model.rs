@@ -0,0 +1,64 @@
+use crate::{db::DbConnection, schema::feed_tokens, user, utils::AppError};
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Authenticates feed URLs for clients, like calendar apps, that can't send a bearer
+/// token. Each user has at most one, and generating a new one revokes the old one
+#[derive(
+    Serialize, Deserialize, Selectable, Queryable, Associations, Insertable, AsChangeset, ToSchema,
+)]
+#[diesel(belongs_to(user::User))]
+#[diesel(table_name = feed_tokens)]
+#[serde(rename_all = "camelCase")]
+pub struct FeedToken {
+    pub user_id: Uuid,
+    pub token: Uuid,
+    pub date_created: NaiveDateTime,
+}
+
+impl FeedToken {
+    pub fn find(conn: &mut DbConnection, user_id: Uuid) -> Result<Self, AppError> {
+        let feed_token = feed_tokens::table
+            .filter(feed_tokens::user_id.eq(user_id))
+            .select(FeedToken::as_select())
+            .first(conn)?;
+        Ok(feed_token)
+    }
+
+    /// Finds the user a feed token belongs to
+    pub fn find_user_id(conn: &mut DbConnection, token: Uuid) -> Result<Uuid, AppError> {
+        let user_id = feed_tokens::table
+            .filter(feed_tokens::token.eq(token))
+            .select(feed_tokens::user_id)
+            .first(conn)
+            .optional()?;
+
+        user_id.ok_or(AppError::external(404, "Feed not found"))
+    }
+
+    /// Generates a new feed token for the user, replacing any existing one
+    pub fn create(conn: &mut DbConnection, user_id: Uuid) -> Result<Self, AppError> {
+        let feed_token = FeedToken {
+            user_id,
+            token: Uuid::new_v4(),
+            date_created: chrono::Local::now().naive_local(),
+        };
+
+        let feed_token = diesel::insert_into(feed_tokens::table)
+            .values(&feed_token)
+            .on_conflict(feed_tokens::user_id)
+            .do_update()
+            .set(&feed_token)
+            .get_result(conn)?;
+        Ok(feed_token)
+    }
+
+    pub fn delete(conn: &mut DbConnection, user_id: Uuid) -> Result<usize, AppError> {
+        let res = diesel::delete(feed_tokens::table.filter(feed_tokens::user_id.eq(user_id)))
+            .execute(conn)?;
+        Ok(res)
+    }
+}
@@ -0,0 +1,45 @@
+use crate::{
+    db::DbPool,
+    feed_token::FeedToken,
+    utils::{
+        jwt::Auth,
+        response_body::{DeleteResponse, Success},
+    },
+};
+use actix_web::{delete, get, post, web, Responder};
+
+#[utoipa::path(tag = "Feed Token", responses((status = OK, body = FeedToken),(status = NOT_FOUND)))]
+#[get("/feed-token")]
+async fn find(pool: web::Data<DbPool>, auth: Auth) -> actix_web::Result<impl Responder> {
+    let feed_token = web::block(move || {
+        let mut conn = pool.get()?;
+        FeedToken::find(&mut conn, auth.user_id)
+    })
+    .await??;
+
+    Ok(Success::new(feed_token))
+}
+
+#[utoipa::path(tag = "Feed Token", responses((status = OK, body = FeedToken)))]
+#[post("/feed-token")]
+async fn create(pool: web::Data<DbPool>, auth: Auth) -> actix_web::Result<impl Responder> {
+    let feed_token = web::block(move || {
+        let mut conn = pool.get()?;
+        FeedToken::create(&mut conn, auth.user_id)
+    })
+    .await??;
+
+    Ok(Success::new(feed_token))
+}
+
+#[utoipa::path(tag = "Feed Token", responses((status = OK, body = DeleteResponse)))]
+#[delete("/feed-token")]
+async fn delete(pool: web::Data<DbPool>, auth: Auth) -> actix_web::Result<impl Responder> {
+    let count = web::block(move || {
+        let mut conn = pool.get()?;
+        FeedToken::delete(&mut conn, auth.user_id)
+    })
+    .await??;
+
+    Ok(Success::new(DeleteResponse { count }))
+}
@@ -0,0 +1,63 @@
+use super::{read_csv, ImportRowReport, ImportRows, ImportedReview};
+use crate::utils::AppError;
+use chrono::NaiveDate;
+use serde::Deserialize;
+
+/// Columns of the `ratings.csv` file exported from IMDb
+#[derive(Deserialize)]
+struct ImdbRow {
+    #[serde(rename = "Const")]
+    imdb_id: String,
+    #[serde(rename = "Your Rating")]
+    rating: Option<i16>,
+    #[serde(rename = "Date Rated")]
+    date_rated: Option<NaiveDate>,
+    #[serde(rename = "Title", default)]
+    title: Option<String>,
+    #[serde(rename = "Year", default)]
+    year: Option<i32>,
+}
+
+/// Reads each row of an IMDb ratings export along with its IMDb id, or why it could
+/// not be read
+pub fn parse(file: &str) -> Result<ImportRows<(String, ImportedReview)>, AppError> {
+    let rows = read_csv::<ImdbRow>(file, "Const", "an IMDb ratings export")?;
+
+    let reviews = rows
+        .into_iter()
+        .map(|(line, row)| {
+            let row = row.map_err(|error| ImportRowReport::invalid(line, None, error))?;
+            let title = row.title.unwrap_or_else(|| row.imdb_id.clone());
+
+            // IMDb ratings are out of 10
+            let rating = match row.rating {
+                Some(rating @ 1..=10) => Some(rating * 10),
+                Some(rating) => {
+                    return Err(ImportRowReport {
+                        year: row.year,
+                        ..ImportRowReport::invalid(
+                            line,
+                            Some(title),
+                            format!("Invalid rating {rating}"),
+                        )
+                    })
+                }
+                None => None,
+            };
+
+            Ok((
+                row.imdb_id,
+                ImportedReview {
+                    line,
+                    title,
+                    year: row.year,
+                    date: row.date_rated,
+                    rating,
+                    description: None,
+                },
+            ))
+        })
+        .collect();
+
+    Ok(reviews)
+}
@@ -0,0 +1,62 @@
+use super::{rating_from_stars, read_csv, ImportRowReport, ImportRows, ImportedReview};
+use crate::utils::AppError;
+use chrono::NaiveDate;
+use serde::Deserialize;
+
+/// Shared columns of the `diary.csv`, `ratings.csv` and `reviews.csv` files in a
+/// Letterboxd export
+#[derive(Deserialize)]
+struct LetterboxdRow {
+    #[serde(rename = "Date")]
+    date: Option<NaiveDate>,
+    #[serde(rename = "Name")]
+    name: String,
+    #[serde(rename = "Year")]
+    year: Option<i32>,
+    #[serde(rename = "Rating", default)]
+    rating: Option<f32>,
+    #[serde(rename = "Review", default)]
+    review: Option<String>,
+    /// Only in diary entries, where `Date` is when the entry was logged
+    #[serde(rename = "Watched Date", default)]
+    watched_date: Option<NaiveDate>,
+}
+
+/// Reads each row of a Letterboxd export, or why it could not be read
+pub fn parse(file: &str) -> Result<ImportRows<ImportedReview>, AppError> {
+    let rows = read_csv::<LetterboxdRow>(
+        file,
+        "Name",
+        "a Letterboxd diary, ratings or reviews export",
+    )?;
+
+    let reviews = rows
+        .into_iter()
+        .map(|(line, row)| {
+            let row = row.map_err(|error| ImportRowReport::invalid(line, None, error))?;
+
+            let rating = match row.rating {
+                Some(stars) => Some(rating_from_stars(stars).ok_or(ImportRowReport {
+                    year: row.year,
+                    ..ImportRowReport::invalid(
+                        line,
+                        Some(row.name.clone()),
+                        format!("Invalid rating {stars}"),
+                    )
+                })?),
+                None => None,
+            };
+
+            Ok(ImportedReview {
+                line,
+                title: row.name,
+                year: row.year,
+                date: row.watched_date.or(row.date),
+                rating,
+                description: row.review.filter(|review| !review.trim().is_empty()),
+            })
+        })
+        .collect();
+
+    Ok(reviews)
+}
@@ -0,0 +1,8 @@
+pub mod imdb;
+pub mod letterboxd;
+pub mod model;
+pub mod routes;
+pub mod trakt;
+
+pub use model::*;
+pub use routes::*;
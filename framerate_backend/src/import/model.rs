@@ -0,0 +1,595 @@
+use crate::collection::{Collection, DEFAULT_WATCHLIST};
+use crate::db::DbConnection;
+use crate::episode_review::EpisodeReview;
+use crate::movie::{Movie, MOVIE_MEDIA_TYPE};
+use crate::movie_entry::MovieEntry;
+use crate::movie_review::MovieReview;
+use crate::review::Review;
+use crate::schema::{episode_reviews, movie_reviews, reviews, season_reviews, show_reviews};
+use crate::season::{Episode, Season};
+use crate::season_review::SeasonReview;
+use crate::show::{Show, SHOW_MEDIA_TYPE};
+use crate::show_entry::ShowEntry;
+use crate::show_review::ShowReview;
+use crate::tmdb::{generate_endpoint, TmdbClient};
+use crate::utils::AppError;
+use chrono::{Datelike, NaiveDate, Utc};
+use diesel::dsl::exists;
+use diesel::prelude::*;
+use futures_util::stream::{self, StreamExt};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::future::Future;
+use std::hash::Hash;
+use tmdb_api::find::FindResults;
+use tracing::warn;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Most TMDB requests an import makes at once
+pub const IMPORT_CONCURRENCY: usize = 4;
+
+/// A review read from an export of another service
+pub struct ImportedReview {
+    /// Line of the file the review was read from, counting the header as line 1, or
+    /// the position of the item in a JSON export
+    pub line: u64,
+    pub title: String,
+    pub year: Option<i32>,
+    pub date: Option<NaiveDate>,
+    pub rating: Option<i16>,
+    pub description: Option<String>,
+}
+
+/// Rows read from an import file, or why each could not be read
+pub type ImportRows<T> = Vec<Result<T, ImportRowReport>>;
+
+/// Rows of a CSV file with the line each starts on
+pub type CsvRows<T> = Vec<(u64, Result<T, String>)>;
+
+/// The media an imported review was matched to
+pub enum ImportedMedia {
+    Movie(Movie),
+    Show(Show),
+    Season(Season),
+    Episode {
+        show_id: i32,
+        season_number: i32,
+        episode: Episode,
+    },
+}
+
+#[derive(Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportCandidate {
+    pub movie_id: i32,
+    pub title: String,
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub release_date: Option<NaiveDate>,
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportRowReport {
+    /// Line of the file, counting the header as line 1, or the position of the item
+    /// in a JSON export
+    pub line: u64,
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub year: Option<i32>,
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// Movies the row could refer to, when it is ambiguous
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub candidates: Option<Vec<ImportCandidate>>,
+}
+
+#[derive(Default, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportReport {
+    pub imported: i64,
+    /// Items added to a watchlist
+    pub watchlisted: i64,
+    /// Rows with no matching movie
+    pub unmatched: Vec<ImportRowReport>,
+    /// Rows matching more than one movie, which were not imported
+    pub ambiguous: Vec<ImportRowReport>,
+    /// Rows that could not be read
+    pub invalid: Vec<ImportRowReport>,
+    /// Rows that could not be matched because a TMDB request failed, which can be
+    /// imported again later
+    pub failed: Vec<ImportRowReport>,
+    /// Rows already imported by an earlier import, or already on a watchlist, which
+    /// were not imported again
+    pub skipped: i64,
+}
+
+#[derive(Clone)]
+pub enum MovieMatch {
+    Found(i32),
+    NotFound,
+    Ambiguous(Vec<ImportCandidate>),
+}
+
+impl From<&ImportedReview> for ImportRowReport {
+    fn from(review: &ImportedReview) -> Self {
+        ImportRowReport {
+            line: review.line,
+            title: Some(review.title.clone()),
+            year: review.year,
+            message: None,
+            candidates: None,
+        }
+    }
+}
+
+impl ImportRowReport {
+    pub fn invalid(line: u64, title: Option<String>, message: String) -> Self {
+        ImportRowReport {
+            line,
+            title,
+            year: None,
+            message: Some(message),
+            candidates: None,
+        }
+    }
+}
+
+impl From<Movie> for ImportCandidate {
+    fn from(movie: Movie) -> Self {
+        ImportCandidate {
+            movie_id: movie.id,
+            title: movie.title,
+            release_date: movie.release_date,
+        }
+    }
+}
+
+impl MovieMatch {
+    /// Resolves a title and release year to a TMDB movie. An exact title match is
+    /// preferred over other search results from the same year
+    pub async fn find(
+        client: &TmdbClient,
+        title: &str,
+        year: Option<i32>,
+    ) -> Result<MovieMatch, AppError> {
+        let results = Movie::search(client, title).await?;
+
+        let same_year: Vec<Movie> = results
+            .into_iter()
+            .filter(|movie| match year {
+                Some(year) => movie.release_date.map(|date| date.year()) == Some(year),
+                None => true,
+            })
+            .collect();
+
+        let (exact, others): (Vec<Movie>, Vec<Movie>) = same_year
+            .into_iter()
+            .partition(|movie| movie.title.to_lowercase() == title.to_lowercase());
+
+        let mut candidates = if exact.is_empty() { others } else { exact };
+
+        Ok(match candidates.len() {
+            0 => MovieMatch::NotFound,
+            1 => MovieMatch::Found(candidates.remove(0).id),
+            _ => MovieMatch::Ambiguous(candidates.into_iter().map(ImportCandidate::from).collect()),
+        })
+    }
+}
+
+impl ImportReport {
+    pub fn record_match(&mut self, review: &ImportedReview, movie_match: MovieMatch) {
+        match movie_match {
+            MovieMatch::Found(_) => {}
+            MovieMatch::NotFound => self.unmatched.push(ImportRowReport::from(review)),
+            MovieMatch::Ambiguous(candidates) => self.ambiguous.push(ImportRowReport {
+                candidates: Some(candidates),
+                ..ImportRowReport::from(review)
+            }),
+        }
+    }
+
+    /// Records a row whose TMDB request failed. Media TMDB doesn't know about is
+    /// unmatched rather than failed
+    pub fn record_failure(&mut self, review: &ImportedReview, error: &AppError) {
+        if let AppError::TmdbError(error) = error {
+            if error.status_code == 404 {
+                self.unmatched.push(ImportRowReport::from(review));
+                return;
+            }
+        }
+
+        warn!("Unable to import line {}: {error}", review.line);
+        self.failed.push(ImportRowReport {
+            message: Some("TMDB request failed".to_string()),
+            ..ImportRowReport::from(review)
+        });
+    }
+}
+
+/// Runs `find` once for each key, with at most `IMPORT_CONCURRENCY` requests in
+/// flight, keeping the result for every key so one failure doesn't stop the others
+pub async fn find_each<K, T, F, Fut>(
+    keys: impl IntoIterator<Item = K>,
+    find: F,
+) -> HashMap<K, Result<T, AppError>>
+where
+    K: Eq + Hash + Clone,
+    F: Fn(K) -> Fut,
+    Fut: Future<Output = Result<T, AppError>>,
+{
+    stream::iter(keys)
+        .map(|key| {
+            let result = find(key.clone());
+            async move { (key, result.await) }
+        })
+        .buffer_unordered(IMPORT_CONCURRENCY)
+        .collect()
+        .await
+}
+
+/// Converts a rating out of five stars, in half star steps, to a rating out of 100
+pub fn rating_from_stars(stars: f32) -> Option<i16> {
+    let half_stars = stars * 2.0;
+    if half_stars.fract() != 0.0 || !(1.0..=10.0).contains(&half_stars) {
+        return None;
+    }
+    Some(half_stars as i16 * 10)
+}
+
+/// Reads the rows of a CSV file along with the line each starts on. Fails when the
+/// header is missing `required_column`, as the file is likely from another service
+pub fn read_csv<T: DeserializeOwned>(
+    file: &str,
+    required_column: &str,
+    expected_file: &str,
+) -> Result<CsvRows<T>, AppError> {
+    let mut reader = csv::Reader::from_reader(file.as_bytes());
+
+    let headers = reader
+        .headers()
+        .map_err(|_| AppError::external(400, "Invalid CSV file"))?
+        .clone();
+    if !headers.iter().any(|header| header == required_column) {
+        return Err(AppError::external(
+            400,
+            &format!("Expected {expected_file}"),
+        ));
+    }
+
+    let rows = reader
+        .records()
+        .enumerate()
+        .map(|(index, record)| {
+            let line = record
+                .as_ref()
+                .ok()
+                .and_then(|record| record.position())
+                .map_or(index as u64 + 2, |position| position.line());
+            let row = record
+                .and_then(|record| record.deserialize::<T>(Some(&headers)))
+                .map_err(|error| error.to_string());
+            (line, row)
+        })
+        .collect();
+
+    Ok(rows)
+}
+
+impl ImportedMedia {
+    /// Finds the movie or show with an IMDb id through TMDB
+    pub async fn find_by_imdb_id(
+        client: &TmdbClient,
+        imdb_id: &str,
+    ) -> Result<Option<ImportedMedia>, AppError> {
+        let request_url = generate_endpoint(
+            format!("find/{imdb_id}"),
+            Some(HashMap::from([("external_source", "imdb_id")])),
+        );
+
+        let response = client.get(&request_url).send().await?;
+
+        if !response.status().is_success() {
+            return Err(AppError::tmdb_error(
+                response.status().as_u16(),
+                response.text().await?.as_str(),
+            ));
+        }
+
+        let results = response.json::<FindResults>().await?;
+
+        if let Some(movie) = results.movie_results.first() {
+            return Ok(Some(ImportedMedia::Movie(
+                Movie::find(client, &movie.id).await?,
+            )));
+        }
+
+        if let Some(show) = results.tv_results.first() {
+            return Ok(Some(ImportedMedia::Show(
+                Show::find(client, &show.id).await?,
+            )));
+        }
+
+        Ok(None)
+    }
+
+    /// Whether the user already has a review of this media on the date, or with no
+    /// date when there is none
+    fn is_reviewed(
+        &self,
+        conn: &mut DbConnection,
+        user_id: Uuid,
+        date: Option<NaiveDate>,
+    ) -> Result<bool, AppError> {
+        let reviewed = match self {
+            ImportedMedia::Movie(movie) => diesel::select(exists(
+                movie_reviews::table
+                    .inner_join(reviews::table)
+                    .filter(movie_reviews::user_id.eq(user_id))
+                    .filter(movie_reviews::movie_id.eq(movie.id))
+                    .filter(reviews::date.is_not_distinct_from(date)),
+            ))
+            .get_result::<bool>(conn)?,
+            ImportedMedia::Show(show) => diesel::select(exists(
+                show_reviews::table
+                    .inner_join(reviews::table)
+                    .filter(show_reviews::user_id.eq(user_id))
+                    .filter(show_reviews::show_id.eq(show.id))
+                    .filter(reviews::date.is_not_distinct_from(date)),
+            ))
+            .get_result::<bool>(conn)?,
+            ImportedMedia::Season(season) => diesel::select(exists(
+                season_reviews::table
+                    .inner_join(reviews::table)
+                    .filter(season_reviews::user_id.eq(user_id))
+                    .filter(season_reviews::show_id.eq(season.show_id))
+                    .filter(season_reviews::season_number.eq(season.season_number))
+                    .filter(reviews::date.is_not_distinct_from(date)),
+            ))
+            .get_result::<bool>(conn)?,
+            ImportedMedia::Episode {
+                show_id,
+                season_number,
+                episode,
+            } => diesel::select(exists(
+                episode_reviews::table
+                    .inner_join(reviews::table)
+                    .filter(episode_reviews::user_id.eq(user_id))
+                    .filter(episode_reviews::show_id.eq(show_id))
+                    .filter(episode_reviews::season_number.eq(season_number))
+                    .filter(episode_reviews::episode_number.eq(episode.episode_number))
+                    .filter(reviews::date.is_not_distinct_from(date)),
+            ))
+            .get_result::<bool>(conn)?,
+        };
+
+        Ok(reviewed)
+    }
+
+    fn create_review(
+        &self,
+        conn: &mut DbConnection,
+        user_id: Uuid,
+        review_id: Uuid,
+    ) -> Result<(), AppError> {
+        match self {
+            ImportedMedia::Movie(movie) => {
+                MovieReview::create(
+                    conn,
+                    MovieReview {
+                        review_id,
+                        user_id,
+                        movie_id: movie.id,
+                        title: movie.title.clone(),
+                        imdb_id: movie.imdb_id.clone(),
+                        poster_path: movie.poster_path.clone(),
+                        release_date: movie.release_date,
+                    },
+                )?;
+            }
+            ImportedMedia::Show(show) => {
+                ShowReview::create(
+                    conn,
+                    ShowReview {
+                        review_id,
+                        user_id,
+                        show_id: show.id,
+                        name: show.name.clone(),
+                        imdb_id: show
+                            .external_ids
+                            .as_ref()
+                            .and_then(|external_ids| external_ids.imdb_id.clone()),
+                        poster_path: show.poster_path.clone(),
+                        first_air_date: show.first_air_date,
+                    },
+                )?;
+            }
+            ImportedMedia::Season(season) => {
+                SeasonReview::create(
+                    conn,
+                    SeasonReview {
+                        review_id,
+                        user_id,
+                        show_id: season.show_id,
+                        season_number: season.season_number,
+                        name: season.name.clone(),
+                        poster_path: season.poster_path.clone(),
+                        air_date: season.air_date,
+                    },
+                )?;
+            }
+            ImportedMedia::Episode {
+                show_id,
+                season_number,
+                episode,
+            } => {
+                EpisodeReview::create(
+                    conn,
+                    EpisodeReview {
+                        review_id,
+                        user_id,
+                        show_id: *show_id,
+                        season_number: *season_number,
+                        episode_number: episode.episode_number,
+                        name: episode.name.clone(),
+                        still_path: episode.still_path.clone(),
+                        air_date: episode.air_date,
+                    },
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Adds the movie or show to the user's default watchlist, returning `false` when
+    /// it is already there
+    fn add_to_watchlist(&self, conn: &mut DbConnection, user_id: Uuid) -> Result<bool, AppError> {
+        match self {
+            ImportedMedia::Movie(movie) => {
+                let watchlist = Collection::find_or_create_default(
+                    conn,
+                    user_id,
+                    MOVIE_MEDIA_TYPE,
+                    DEFAULT_WATCHLIST,
+                    "Movie Watchlist",
+                )?;
+
+                if MovieEntry::find(conn, user_id, watchlist.collection_id, movie.id).is_ok() {
+                    return Ok(false);
+                }
+
+                MovieEntry::create(
+                    conn,
+                    MovieEntry {
+                        collection_id: watchlist.collection_id,
+                        user_id,
+                        movie_id: movie.id,
+                        imdb_id: movie.imdb_id.clone(),
+                        title: movie.title.clone(),
+                        poster_path: movie.poster_path.clone(),
+                        release_date: movie.release_date,
+                        status: movie.status.clone(),
+                        updated_at: Utc::now().naive_utc().date(),
+                        position: None,
+                        added_at: Utc::now().naive_utc(),
+                        note: None,
+                        priority: None,
+                    },
+                )?;
+            }
+            ImportedMedia::Show(show) => {
+                let watchlist = Collection::find_or_create_default(
+                    conn,
+                    user_id,
+                    SHOW_MEDIA_TYPE,
+                    DEFAULT_WATCHLIST,
+                    "Show Watchlist",
+                )?;
+
+                if ShowEntry::find(conn, user_id, watchlist.collection_id, show.id).is_ok() {
+                    return Ok(false);
+                }
+
+                ShowEntry::create(
+                    conn,
+                    ShowEntry {
+                        collection_id: watchlist.collection_id,
+                        user_id,
+                        show_id: show.id,
+                        imdb_id: show
+                            .external_ids
+                            .as_ref()
+                            .and_then(|external_ids| external_ids.imdb_id.clone()),
+                        name: show.name.clone(),
+                        poster_path: show.poster_path.clone(),
+                        first_air_date: show.first_air_date,
+                        last_air_date: show.last_air_date,
+                        next_air_date: show.next_air_date,
+                        status: show.status.clone(),
+                        updated_at: Utc::now().naive_utc().date(),
+                        position: None,
+                        added_at: Utc::now().naive_utc(),
+                        note: None,
+                        priority: None,
+                    },
+                )?;
+            }
+            ImportedMedia::Season(_) | ImportedMedia::Episode { .. } => {
+                return Err(AppError::external(
+                    400,
+                    "Only movies and shows can be added to a watchlist",
+                ));
+            }
+        }
+
+        Ok(true)
+    }
+}
+
+/// Saves imported reviews against the media they were matched to. A review is skipped
+/// when the user already reviewed the same media on the same date, so importing a
+/// file again doesn't duplicate its reviews
+pub fn create_reviews(
+    conn: &mut DbConnection,
+    user_id: Uuid,
+    reviews: Vec<(&ImportedReview, &ImportedMedia)>,
+    report: &mut ImportReport,
+) -> Result<(), AppError> {
+    conn.transaction::<(), AppError, _>(|conn| {
+        for (review, media) in reviews {
+            if media.is_reviewed(conn, user_id, review.date)? {
+                report.skipped += 1;
+                continue;
+            }
+
+            let review_id = Uuid::new_v4();
+
+            Review::create(
+                conn,
+                Review {
+                    review_id,
+                    user_id,
+                    date: review.date,
+                    rating: review.rating,
+                    title: None,
+                    description: review.description.clone(),
+                    venue: None,
+                },
+            )?;
+
+            media.create_review(conn, user_id, review_id)?;
+            report.imported += 1;
+        }
+
+        Ok(())
+    })
+}
+
+/// Adds imported watchlist items to the user's default watchlists, skipping media
+/// already on them
+pub fn create_watchlist_entries(
+    conn: &mut DbConnection,
+    user_id: Uuid,
+    media: Vec<&ImportedMedia>,
+    report: &mut ImportReport,
+) -> Result<(), AppError> {
+    conn.transaction::<(), AppError, _>(|conn| {
+        for media in media {
+            if media.add_to_watchlist(conn, user_id)? {
+                report.watchlisted += 1;
+            } else {
+                report.skipped += 1;
+            }
+        }
+
+        Ok(())
+    })
+}
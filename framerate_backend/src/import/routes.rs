@@ -0,0 +1,201 @@
+use super::trakt;
+use super::{
+    create_reviews, create_watchlist_entries, find_each, imdb, letterboxd, ImportReport,
+    ImportRowReport, ImportedMedia, MovieMatch,
+};
+use crate::db::DbPool;
+use crate::movie::Movie;
+use crate::tmdb::TmdbClient;
+use crate::utils::{jwt::Auth, response_body::Success, AppError};
+use actix_web::{post, web, Responder};
+use std::collections::{HashMap, HashSet};
+
+#[utoipa::path(tag = "Import", request_body(content = String, content_type = "text/csv"), responses((status = OK, body = ImportReport),(status = BAD_REQUEST)))]
+#[post("/reviews/import/letterboxd")]
+async fn import_letterboxd(
+    pool: web::Data<DbPool>,
+    client: web::Data<TmdbClient>,
+    auth: Auth,
+    file: String,
+) -> actix_web::Result<impl Responder> {
+    let rows = letterboxd::parse(&file)?;
+
+    let mut report = ImportReport::default();
+    let mut reviews = vec![];
+
+    for row in rows {
+        match row {
+            Ok(review) => reviews.push(review),
+            Err(invalid) => report.invalid.push(invalid),
+        }
+    }
+
+    let client = &client;
+    let searches: HashSet<(String, Option<i32>)> = reviews
+        .iter()
+        .map(|review| (review.title.clone(), review.year))
+        .collect();
+    let matches = find_each(searches, |(title, year)| async move {
+        MovieMatch::find(client, &title, year).await
+    })
+    .await;
+
+    let movie_ids: HashSet<i32> = matches
+        .values()
+        .filter_map(|movie_match| match movie_match {
+            Ok(MovieMatch::Found(movie_id)) => Some(*movie_id),
+            _ => None,
+        })
+        .collect();
+    let movies = find_each(movie_ids, |movie_id| async move {
+        Movie::find(client, &movie_id)
+            .await
+            .map(ImportedMedia::Movie)
+    })
+    .await;
+
+    let mut matched = vec![];
+
+    for review in reviews {
+        match &matches[&(review.title.clone(), review.year)] {
+            Ok(MovieMatch::Found(movie_id)) => match &movies[movie_id] {
+                Ok(_) => matched.push((review, *movie_id)),
+                Err(error) => report.record_failure(&review, error),
+            },
+            Ok(movie_match) => report.record_match(&review, movie_match.clone()),
+            Err(error) => report.record_failure(&review, error),
+        }
+    }
+
+    let movies: HashMap<i32, ImportedMedia> = movies
+        .into_iter()
+        .filter_map(|(movie_id, movie)| Some((movie_id, movie.ok()?)))
+        .collect();
+
+    let report = web::block(move || {
+        let mut conn = pool.get()?;
+        let reviews = matched
+            .iter()
+            .map(|(review, movie_id)| (review, &movies[movie_id]))
+            .collect();
+        create_reviews(&mut conn, auth.user_id, reviews, &mut report)?;
+        Ok::<ImportReport, AppError>(report)
+    })
+    .await??;
+
+    Ok(Success::new(report))
+}
+
+#[utoipa::path(tag = "Import", request_body(content = String, content_type = "text/csv"), responses((status = OK, body = ImportReport),(status = BAD_REQUEST)))]
+#[post("/reviews/import/imdb")]
+async fn import_imdb(
+    pool: web::Data<DbPool>,
+    client: web::Data<TmdbClient>,
+    auth: Auth,
+    file: String,
+) -> actix_web::Result<impl Responder> {
+    let rows = imdb::parse(&file)?;
+
+    let mut report = ImportReport::default();
+    let mut reviews = vec![];
+
+    for row in rows {
+        match row {
+            Ok(row) => reviews.push(row),
+            Err(invalid) => report.invalid.push(invalid),
+        }
+    }
+
+    let client = &client;
+    let imdb_ids: HashSet<String> = reviews.iter().map(|(imdb_id, _)| imdb_id.clone()).collect();
+    let media = find_each(imdb_ids, |imdb_id| async move {
+        ImportedMedia::find_by_imdb_id(client, &imdb_id).await
+    })
+    .await;
+
+    let mut matched = vec![];
+
+    for (imdb_id, review) in reviews {
+        match &media[&imdb_id] {
+            Ok(Some(_)) => matched.push((review, imdb_id)),
+            Ok(None) => report.unmatched.push(ImportRowReport::from(&review)),
+            Err(error) => report.record_failure(&review, error),
+        }
+    }
+
+    let media: HashMap<String, ImportedMedia> = media
+        .into_iter()
+        .filter_map(|(imdb_id, media)| Some((imdb_id, media.ok()??)))
+        .collect();
+
+    let report = web::block(move || {
+        let mut conn = pool.get()?;
+        let reviews = matched
+            .iter()
+            .map(|(review, imdb_id)| (review, &media[imdb_id]))
+            .collect();
+        create_reviews(&mut conn, auth.user_id, reviews, &mut report)?;
+        Ok::<ImportReport, AppError>(report)
+    })
+    .await??;
+
+    Ok(Success::new(report))
+}
+
+#[utoipa::path(tag = "Import", request_body(content = Vec<u8>, content_type = "application/zip"), responses((status = OK, body = ImportReport),(status = BAD_REQUEST)))]
+#[post("/reviews/import/trakt")]
+async fn import_trakt(
+    pool: web::Data<DbPool>,
+    client: web::Data<TmdbClient>,
+    auth: Auth,
+    archive: web::Bytes,
+) -> actix_web::Result<impl Responder> {
+    let import = trakt::parse(&archive)?;
+
+    let mut report = ImportReport {
+        unmatched: import.unmatched,
+        invalid: import.invalid,
+        ..ImportReport::default()
+    };
+
+    let keys: HashSet<_> = import
+        .reviews
+        .iter()
+        .chain(import.watchlist.iter())
+        .map(|(key, _)| *key)
+        .collect();
+    let media = trakt::find_media(&client, keys).await;
+
+    let mut reviews = vec![];
+    let mut watchlist = vec![];
+
+    for (list, items) in [
+        (&mut reviews, import.reviews),
+        (&mut watchlist, import.watchlist),
+    ] {
+        for (key, review) in items {
+            match media.get(key) {
+                Some(Ok(_)) => list.push((key, review)),
+                Some(Err(error)) => report.record_failure(&review, error),
+                None => report.unmatched.push(ImportRowReport::from(&review)),
+            }
+        }
+    }
+
+    let media = media.found;
+
+    let report = web::block(move || {
+        let mut conn = pool.get()?;
+        let reviews = reviews
+            .iter()
+            .map(|(key, review)| (review, &media[key]))
+            .collect();
+        create_reviews(&mut conn, auth.user_id, reviews, &mut report)?;
+        let watchlist = watchlist.iter().map(|(key, _)| &media[key]).collect();
+        create_watchlist_entries(&mut conn, auth.user_id, watchlist, &mut report)?;
+        Ok::<ImportReport, AppError>(report)
+    })
+    .await??;
+
+    Ok(Success::new(report))
+}
@@ -0,0 +1,376 @@
+use super::{find_each, ImportRowReport, ImportedMedia, ImportedReview};
+use crate::movie::Movie;
+use crate::season::Season;
+use crate::show::Show;
+use crate::tmdb::TmdbClient;
+use crate::utils::{archive, AppError};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use std::collections::{HashMap, HashSet};
+use std::io::Cursor;
+use zip::ZipArchive;
+
+/// The `watched-history.json`, `ratings-*.json` and `watchlist-*.json` files of a
+/// Trakt export. Items are numbered from 1 across the files in that order
+#[derive(Default)]
+pub struct TraktExport {
+    pub history: Vec<TraktItem>,
+    pub ratings: Vec<TraktItem>,
+    pub watchlist: Vec<TraktItem>,
+}
+
+#[derive(Deserialize)]
+pub struct TraktItem {
+    /// One of `movie`, `show`, `season` or `episode`
+    #[serde(rename = "type")]
+    pub media_type: String,
+    pub watched_at: Option<DateTime<Utc>>,
+    pub rated_at: Option<DateTime<Utc>>,
+    pub listed_at: Option<DateTime<Utc>>,
+    /// Rating out of 10
+    pub rating: Option<i16>,
+    pub movie: Option<TraktMedia>,
+    pub show: Option<TraktMedia>,
+    pub season: Option<TraktSeason>,
+    pub episode: Option<TraktEpisode>,
+}
+
+#[derive(Deserialize)]
+pub struct TraktMedia {
+    pub title: Option<String>,
+    pub year: Option<i32>,
+    pub ids: TraktIds,
+}
+
+#[derive(Deserialize)]
+pub struct TraktIds {
+    pub tmdb: Option<i32>,
+    pub imdb: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct TraktSeason {
+    pub number: i32,
+}
+
+#[derive(Deserialize)]
+pub struct TraktEpisode {
+    pub season: i32,
+    pub number: i32,
+    pub title: Option<String>,
+}
+
+/// The TMDB media a Trakt item refers to
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TraktMediaKey {
+    Movie(i32),
+    Show(i32),
+    Season(i32, i32),
+    Episode(i32, i32, i32),
+}
+
+impl TraktMediaKey {
+    /// The media requested from TMDB to find this one, as episodes are found through
+    /// their season
+    fn request(self) -> Self {
+        match self {
+            TraktMediaKey::Episode(show_id, season_number, _) => {
+                TraktMediaKey::Season(show_id, season_number)
+            }
+            key => key,
+        }
+    }
+}
+
+/// The media of a Trakt export found through TMDB, and the requests that failed
+#[derive(Default)]
+pub struct TraktMediaResults {
+    pub found: HashMap<TraktMediaKey, ImportedMedia>,
+    failed: HashMap<TraktMediaKey, AppError>,
+}
+
+impl TraktMediaResults {
+    /// The media, or the error of the request that should have found it. `None` when
+    /// the episode isn't part of its season on TMDB
+    pub fn get(&self, key: TraktMediaKey) -> Option<Result<&ImportedMedia, &AppError>> {
+        match self.found.get(&key) {
+            Some(media) => Some(Ok(media)),
+            None => self.failed.get(&key.request()).map(Err),
+        }
+    }
+}
+
+/// The items of a Trakt export, with ratings merged into the watches they belong to
+#[derive(Default)]
+pub struct TraktImport {
+    pub reviews: Vec<(TraktMediaKey, ImportedReview)>,
+    pub watchlist: Vec<(TraktMediaKey, ImportedReview)>,
+    /// Items without a TMDB id
+    pub unmatched: Vec<ImportRowReport>,
+    pub invalid: Vec<ImportRowReport>,
+}
+
+impl TraktExport {
+    /// Reads the ZIP archive Trakt exports. Files are matched by name wherever they
+    /// are in the archive, and files of other lists are ignored
+    pub fn from_zip(archive: &[u8]) -> Result<Self, AppError> {
+        let mut archive = ZipArchive::new(Cursor::new(archive))
+            .map_err(|_| AppError::external(400, "Expected a Trakt export"))?;
+
+        let mut names: Vec<String> = archive
+            .file_names()
+            .filter(|name| name.ends_with(".json"))
+            .map(String::from)
+            .collect();
+        names.sort();
+
+        let mut export = TraktExport::default();
+        let mut found = false;
+
+        for name in names {
+            let file_name = name.rsplit('/').next().unwrap_or_default();
+            let list = if file_name == "watched-history.json" {
+                &mut export.history
+            } else if file_name.starts_with("ratings-") {
+                &mut export.ratings
+            } else if file_name.starts_with("watchlist-") {
+                &mut export.watchlist
+            } else {
+                continue;
+            };
+
+            let file = archive
+                .by_name(&name)
+                .map_err(|_| AppError::external(400, &format!("Invalid {file_name}")))?;
+            let items: Vec<TraktItem> = archive::read_json(file, file_name)?;
+            list.extend(items);
+            found = true;
+        }
+
+        if !found {
+            return Err(AppError::external(400, "Expected a Trakt export"));
+        }
+
+        Ok(export)
+    }
+}
+
+impl TraktItem {
+    fn title(&self) -> Option<String> {
+        match (&self.movie, &self.show, &self.episode) {
+            (Some(movie), _, _) => movie.title.clone(),
+            (_, Some(show), Some(episode)) => Some(format!(
+                "{} S{:02}E{:02}",
+                show.title.as_deref().unwrap_or_default(),
+                episode.season,
+                episode.number
+            )),
+            (_, Some(show), None) => match &self.season {
+                Some(season) => Some(format!(
+                    "{} S{:02}",
+                    show.title.as_deref().unwrap_or_default(),
+                    season.number
+                )),
+                None => show.title.clone(),
+            },
+            _ => None,
+        }
+    }
+
+    fn year(&self) -> Option<i32> {
+        self.movie.as_ref().or(self.show.as_ref())?.year
+    }
+
+    /// The media the item refers to, or `None` when Trakt has no TMDB id for it
+    fn key(&self) -> Result<Option<TraktMediaKey>, String> {
+        let missing = || format!("Missing {} details", self.media_type);
+
+        let key = match self.media_type.as_str() {
+            "movie" => {
+                let movie = self.movie.as_ref().ok_or_else(missing)?;
+                movie.ids.tmdb.map(TraktMediaKey::Movie)
+            }
+            "show" => {
+                let show = self.show.as_ref().ok_or_else(missing)?;
+                show.ids.tmdb.map(TraktMediaKey::Show)
+            }
+            "season" => {
+                let show = self.show.as_ref().ok_or_else(missing)?;
+                let season = self.season.as_ref().ok_or_else(missing)?;
+                show.ids
+                    .tmdb
+                    .map(|show_id| TraktMediaKey::Season(show_id, season.number))
+            }
+            "episode" => {
+                let show = self.show.as_ref().ok_or_else(missing)?;
+                let episode = self.episode.as_ref().ok_or_else(missing)?;
+                show.ids
+                    .tmdb
+                    .map(|show_id| TraktMediaKey::Episode(show_id, episode.season, episode.number))
+            }
+            media_type => return Err(format!("Unknown type {media_type}")),
+        };
+
+        Ok(key)
+    }
+
+    fn review(&self, line: u64, date: Option<DateTime<Utc>>) -> ImportedReview {
+        ImportedReview {
+            line,
+            title: self.title().unwrap_or_default(),
+            year: self.year(),
+            date: date.map(|date| date.date_naive()),
+            rating: None,
+            description: None,
+        }
+    }
+}
+
+impl TraktImport {
+    /// Reads the item at `line`, recording it as unmatched or invalid when it has no
+    /// media to import
+    fn read(&mut self, line: u64, item: &TraktItem) -> Option<TraktMediaKey> {
+        match item.key() {
+            Ok(Some(key)) => Some(key),
+            Ok(None) => {
+                self.unmatched.push(ImportRowReport {
+                    message: Some("No TMDB id".to_string()),
+                    ..ImportRowReport::from(&item.review(line, None))
+                });
+                None
+            }
+            Err(message) => {
+                self.invalid
+                    .push(ImportRowReport::invalid(line, item.title(), message));
+                None
+            }
+        }
+    }
+}
+
+/// Reads a Trakt export. Watched movies and episodes become reviews dated when they
+/// were watched, and each rating is added to the latest watch of the rated media, or
+/// becomes a review of its own when the media was never watched
+pub fn parse(archive: &[u8]) -> Result<TraktImport, AppError> {
+    let export = TraktExport::from_zip(archive)?;
+
+    let mut import = TraktImport::default();
+    let mut line = 0;
+
+    for item in &export.history {
+        line += 1;
+        if let Some(key) = import.read(line, item) {
+            import
+                .reviews
+                .push((key, item.review(line, item.watched_at)));
+        }
+    }
+
+    for item in &export.ratings {
+        line += 1;
+        let Some(key) = import.read(line, item) else {
+            continue;
+        };
+
+        let rating = match item.rating {
+            Some(rating @ 1..=10) => rating * 10,
+            rating => {
+                import.invalid.push(ImportRowReport::invalid(
+                    line,
+                    item.title(),
+                    format!("Invalid rating {}", rating.unwrap_or_default()),
+                ));
+                continue;
+            }
+        };
+
+        let latest_watch = import
+            .reviews
+            .iter_mut()
+            .filter(|(watched, _)| *watched == key)
+            .max_by_key(|(_, review)| review.date);
+
+        match latest_watch {
+            Some((_, review)) => review.rating = Some(rating),
+            None => import.reviews.push((
+                key,
+                ImportedReview {
+                    rating: Some(rating),
+                    ..item.review(line, item.rated_at)
+                },
+            )),
+        }
+    }
+
+    for item in &export.watchlist {
+        line += 1;
+        let Some(key) = import.read(line, item) else {
+            continue;
+        };
+
+        match key {
+            TraktMediaKey::Movie(_) | TraktMediaKey::Show(_) => import
+                .watchlist
+                .push((key, item.review(line, item.listed_at))),
+            _ => import.invalid.push(ImportRowReport::invalid(
+                line,
+                item.title(),
+                "Only movies and shows can be added to a watchlist".to_string(),
+            )),
+        }
+    }
+
+    Ok(import)
+}
+
+/// Finds each of the media through TMDB. Seasons are requested once and provide the
+/// details of all their episodes
+pub async fn find_media(client: &TmdbClient, keys: HashSet<TraktMediaKey>) -> TraktMediaResults {
+    let requests: HashSet<TraktMediaKey> = keys.into_iter().map(TraktMediaKey::request).collect();
+    let results = find_each(requests, |key| async move {
+        match key {
+            TraktMediaKey::Movie(movie_id) => Movie::find(client, &movie_id)
+                .await
+                .map(ImportedMedia::Movie),
+            TraktMediaKey::Show(show_id) => {
+                Show::find(client, &show_id).await.map(ImportedMedia::Show)
+            }
+            TraktMediaKey::Season(show_id, season_number)
+            | TraktMediaKey::Episode(show_id, season_number, _) => {
+                Season::find(client, &show_id, &season_number)
+                    .await
+                    .map(ImportedMedia::Season)
+            }
+        }
+    })
+    .await;
+
+    let mut media = TraktMediaResults::default();
+
+    for (key, result) in results {
+        match result {
+            Ok(ImportedMedia::Season(mut season)) => {
+                let (show_id, season_number) = (season.show_id, season.season_number);
+                for episode in season.episodes.take().unwrap_or_default() {
+                    media.found.insert(
+                        TraktMediaKey::Episode(show_id, season_number, episode.episode_number),
+                        ImportedMedia::Episode {
+                            show_id,
+                            season_number,
+                            episode,
+                        },
+                    );
+                }
+                media.found.insert(key, ImportedMedia::Season(season));
+            }
+            Ok(found) => {
+                media.found.insert(key, found);
+            }
+            Err(error) => {
+                media.failed.insert(key, error);
+            }
+        }
+    }
+
+    media
+}
@@ -1,9 +1,17 @@
 pub mod administration;
 pub mod authentication;
+pub mod calendar;
 pub mod collection;
+pub mod collection_change;
+pub mod collection_member;
 pub mod company;
 pub mod db;
+pub mod episode_review;
+pub mod export;
+pub mod feed_token;
+pub mod import;
 pub mod meta;
+pub mod mixed_collection;
 pub mod movie;
 pub mod movie_collection;
 pub mod movie_entry;
@@ -11,6 +19,8 @@ pub mod movie_review;
 pub mod movie_watchlist;
 pub mod review;
 pub mod review_company;
+pub mod review_feed;
+pub mod review_tag;
 pub mod routes;
 pub mod schema;
 pub mod season;
@@ -20,6 +30,7 @@ pub mod show_collection;
 pub mod show_entry;
 pub mod show_review;
 pub mod show_watchlist;
+pub mod tag;
 pub mod tmdb;
 pub mod user;
 pub mod utils;
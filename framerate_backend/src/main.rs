@@ -2,7 +2,11 @@ extern crate diesel;
 extern crate diesel_migrations;
 
 use actix_cors::Cors;
-use actix_web::{middleware::Logger, web::Data, App, HttpServer};
+use actix_web::{
+    middleware::Logger,
+    web::{Data, PayloadConfig},
+    App, HttpServer,
+};
 use framerate::{db, movie_entry, routes, show_entry, tmdb, utils};
 use std::env;
 use tracing::info;
@@ -60,6 +64,8 @@ async fn main() -> std::io::Result<()> {
             .openapi(ApiDoc::openapi())
             .app_data(Data::new(pool.clone()))
             .app_data(Data::new(client.clone()))
+            // Allow imports of exports from other services, which are sent as plain text
+            .app_data(PayloadConfig::new(16 * 1024 * 1024))
             .map(|app| app.wrap(Cors::default()))
             .map(|app| app.wrap(Logger::default()))
             .map(|app| app.wrap(actix_cors::Cors::permissive()))
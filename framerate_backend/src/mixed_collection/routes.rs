@@ -0,0 +1,674 @@
+use crate::collection::{
+    Collection, CollectionFindParameters, EntrySort, SortableEntry, UpdatedCollection,
+    ENTRY_PRIORITIES, MIXED_MEDIA_TYPE,
+};
+use crate::collection_change::{CollectionChange, ENTRY_ADDED, ENTRY_REMOVED};
+use crate::collection_member::{COLLECTION_EDITOR, COLLECTION_ROLES};
+use crate::db::{DbConnection, DbPool};
+use crate::movie::{Movie, MOVIE_MEDIA_TYPE};
+use crate::movie_entry::{MovieEntry, UpdatedMovieEntry};
+use crate::show::{Show, SHOW_MEDIA_TYPE};
+use crate::show_entry::{ShowEntry, UpdatedShowEntry};
+use crate::tmdb::TmdbClient;
+use crate::utils::response_body::{DeleteResponse, Success};
+use crate::utils::{jwt::Auth, AppError};
+use actix_web::{delete, Responder};
+use actix_web::{get, post, put, web};
+use chrono::{NaiveDate, NaiveDateTime, Utc};
+use diesel::Connection;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// An entry of a mixed collection, stored with the other movie or show entries
+#[derive(Serialize, Deserialize, Debug, PartialEq, ToSchema)]
+#[serde(tag = "mediaType", rename_all = "camelCase")]
+pub enum MixedEntry {
+    Movie(MovieEntry),
+    Show(ShowEntry),
+}
+
+impl SortableEntry for MixedEntry {
+    fn title(&self) -> &str {
+        match self {
+            MixedEntry::Movie(entry) => entry.title(),
+            MixedEntry::Show(entry) => entry.title(),
+        }
+    }
+
+    fn date(&self) -> Option<NaiveDate> {
+        match self {
+            MixedEntry::Movie(entry) => entry.date(),
+            MixedEntry::Show(entry) => entry.date(),
+        }
+    }
+
+    fn added_at(&self) -> NaiveDateTime {
+        match self {
+            MixedEntry::Movie(entry) => entry.added_at,
+            MixedEntry::Show(entry) => entry.added_at,
+        }
+    }
+
+    fn status(&self) -> Option<&str> {
+        match self {
+            MixedEntry::Movie(entry) => entry.status.as_deref(),
+            MixedEntry::Show(entry) => entry.status.as_deref(),
+        }
+    }
+
+    fn position(&self) -> Option<i32> {
+        match self {
+            MixedEntry::Movie(entry) => entry.position,
+            MixedEntry::Show(entry) => entry.position,
+        }
+    }
+}
+
+impl MixedEntry {
+    /// Finds the movie and show entries of a collection, in the same orders as the
+    /// collections of a single media type
+    pub fn find_all(
+        conn: &mut DbConnection,
+        user_id: Uuid,
+        collection_id: Uuid,
+        sort: EntrySort,
+    ) -> Result<Vec<Self>, AppError> {
+        let movie_entries = MovieEntry::find_all(conn, user_id, collection_id, sort)?;
+        let show_entries = ShowEntry::find_all(conn, user_id, collection_id, sort)?;
+
+        let mut entries: Vec<MixedEntry> = movie_entries
+            .into_iter()
+            .map(MixedEntry::Movie)
+            .chain(show_entries.into_iter().map(MixedEntry::Show))
+            .collect();
+
+        entries.sort_by(|a, b| sort.compare(a, b));
+        Ok(entries)
+    }
+
+    /// Places the movie and show entries of a collection in the order of `entries`.
+    /// Entries left out lose their position and are listed after the ordered ones
+    pub fn reorder(
+        conn: &mut DbConnection,
+        collection_id: Uuid,
+        entries: &[MixedEntryKey],
+    ) -> Result<(), AppError> {
+        let mut movie_positions = Vec::new();
+        let mut show_positions = Vec::new();
+        for (entry, position) in entries.iter().zip(0..) {
+            match entry.media_type.as_str() {
+                MOVIE_MEDIA_TYPE => movie_positions.push((entry.media_id, position)),
+                SHOW_MEDIA_TYPE => show_positions.push((entry.media_id, position)),
+                _ => return Err(AppError::external(400, "Invalid media type")),
+            }
+        }
+
+        conn.transaction::<(), AppError, _>(|conn| {
+            MovieEntry::set_positions(conn, collection_id, &movie_positions)?;
+            ShowEntry::set_positions(conn, collection_id, &show_positions)
+        })
+    }
+}
+
+/// Details fetched from TMDB for a new entry
+enum MixedMedia {
+    Movie(Movie),
+    Show(Show),
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MixedCollection {
+    pub collection_id: Uuid,
+    pub user_id: Uuid,
+    pub name: String,
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entries: Option<Vec<MixedEntry>>,
+}
+
+impl From<Collection> for MixedCollection {
+    fn from(value: Collection) -> Self {
+        MixedCollection {
+            collection_id: value.collection_id,
+            name: value.name,
+            user_id: value.user_id,
+            entries: None,
+        }
+    }
+}
+
+impl MixedCollection {
+    fn entries(mut self, entries: Vec<MixedEntry>) -> Self {
+        self.entries = Some(entries);
+        self
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct NewMixedCollection {
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SaveMixedCollectionEntryRequest {
+    /// One of `movie` or `show`
+    pub media_type: String,
+    pub media_id: i32,
+}
+
+/// Identifies a movie or show entry of a mixed collection
+#[derive(Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MixedEntryKey {
+    /// One of `movie` or `show`
+    pub media_type: String,
+    pub media_id: i32,
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ReorderMixedCollectionRequest {
+    /// Entries in the order to show them in. Entries left out are listed after these
+    pub entries: Vec<MixedEntryKey>,
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferMixedCollectionRequest {
+    /// Another mixed collection, or a movie or show collection when all the entries
+    /// are of its media type
+    pub target_collection_id: Uuid,
+    pub entries: Vec<MixedEntryKey>,
+}
+
+#[utoipa::path(tag = "Mixed Collection", responses((status = OK, body = Vec<MixedCollection>)))]
+#[get("/mixed/collections")]
+async fn find_all(pool: web::Data<DbPool>, auth: Auth) -> actix_web::Result<impl Responder> {
+    let collections = web::block(move || {
+        let mut conn = pool.get()?;
+        Collection::find_by_media_type(&mut conn, auth.user_id, MIXED_MEDIA_TYPE)
+    })
+    .await??;
+
+    let collections = collections
+        .into_iter()
+        .map(MixedCollection::from)
+        .collect::<Vec<MixedCollection>>();
+
+    Ok(Success::new(collections))
+}
+
+#[utoipa::path(tag = "Mixed Collection", params(CollectionFindParameters), responses((status = OK, body = MixedCollection),(status = BAD_REQUEST)))]
+#[get("/mixed/collections/{collection_id}")]
+async fn find(
+    pool: web::Data<DbPool>,
+    auth: Auth,
+    collection_id: web::Path<Uuid>,
+    params: web::Query<CollectionFindParameters>,
+) -> actix_web::Result<impl Responder> {
+    let mixed_collection = web::block(move || {
+        let mut conn = pool.get()?;
+        let collection = Collection::find_shared(
+            &mut conn,
+            auth.user_id,
+            Some(MIXED_MEDIA_TYPE),
+            &collection_id,
+            &COLLECTION_ROLES,
+        )?;
+        let entries = MixedEntry::find_all(
+            &mut conn,
+            collection.user_id,
+            collection.collection_id,
+            params.sort.unwrap_or_default(),
+        )?;
+        Ok::<MixedCollection, AppError>(MixedCollection::from(collection).entries(entries))
+    })
+    .await??;
+
+    Ok(Success::new(mixed_collection))
+}
+
+#[utoipa::path(tag = "Mixed Collection", responses((status = OK, body = MixedCollection)))]
+#[post("/mixed/collections")]
+async fn create(
+    pool: web::Data<DbPool>,
+    auth: Auth,
+    params: web::Json<NewMixedCollection>,
+) -> actix_web::Result<impl Responder> {
+    let collection = Collection {
+        collection_id: Uuid::new_v4(),
+        user_id: auth.user_id,
+        name: params.into_inner().name,
+        media_type: MIXED_MEDIA_TYPE.to_string(),
+        default_for: None,
+        rules: None,
+    };
+
+    let collection = web::block(move || {
+        let mut conn = pool.get()?;
+        Collection::create(&mut conn, collection)
+    })
+    .await??;
+
+    Ok(Success::new(MixedCollection::from(collection)))
+}
+
+#[utoipa::path(tag = "Mixed Collection", responses((status = OK, body = MixedCollection)))]
+#[put("/mixed/collections/{collection_id}")]
+async fn update(
+    pool: web::Data<DbPool>,
+    auth: Auth,
+    collection_id: web::Path<Uuid>,
+    params: web::Json<UpdatedCollection>,
+) -> actix_web::Result<impl Responder> {
+    let params = params.into_inner();
+
+    let collection = web::block(move || {
+        let mut conn = pool.get()?;
+        Collection::update(&mut conn, &auth.user_id, &collection_id, params)
+    })
+    .await??;
+
+    Ok(Success::new(MixedCollection::from(collection)))
+}
+
+#[utoipa::path(tag = "Mixed Collection", responses((status = OK, body = DeleteResponse)))]
+#[delete("/mixed/collections/{collection_id}")]
+async fn delete(
+    pool: web::Data<DbPool>,
+    auth: Auth,
+    collection_id: web::Path<Uuid>,
+) -> actix_web::Result<impl Responder> {
+    let count = web::block(move || {
+        let mut conn = pool.get()?;
+        Collection::delete(&mut conn, &auth.user_id, &collection_id)
+    })
+    .await??;
+
+    if count == 0 {
+        return Err(AppError::external(404, "Collection entry not found"))?;
+    }
+
+    Ok(Success::new(DeleteResponse { count }))
+}
+
+#[utoipa::path(tag = "Mixed Collection", responses((status = OK, body = MixedEntry),(status = BAD_REQUEST)))]
+#[post("/mixed/collections/{collection_id}")]
+async fn create_entry(
+    pool: web::Data<DbPool>,
+    client: web::Data<TmdbClient>,
+    auth: Auth,
+    collection_id: web::Path<Uuid>,
+    params: web::Json<SaveMixedCollectionEntryRequest>,
+) -> actix_web::Result<impl Responder> {
+    let media = match params.media_type.as_str() {
+        MOVIE_MEDIA_TYPE => MixedMedia::Movie(Movie::find(&client, &params.media_id).await?),
+        SHOW_MEDIA_TYPE => MixedMedia::Show(Show::find(&client, &params.media_id).await?),
+        _ => return Err(AppError::external(400, "Invalid media type"))?,
+    };
+
+    let mixed_entry = web::block(move || {
+        let mut conn = pool.get()?;
+        let collection = Collection::find_shared(
+            &mut conn,
+            auth.user_id,
+            Some(MIXED_MEDIA_TYPE),
+            &collection_id,
+            &[COLLECTION_EDITOR],
+        )?;
+
+        conn.transaction::<MixedEntry, AppError, _>(|conn| {
+            let mixed_entry = match media {
+                MixedMedia::Movie(movie) => MixedEntry::Movie(MovieEntry::create(
+                    conn,
+                    MovieEntry {
+                        collection_id: collection.collection_id,
+                        user_id: collection.user_id,
+                        movie_id: params.media_id,
+                        imdb_id: movie.imdb_id,
+                        title: movie.title,
+                        poster_path: movie.poster_path,
+                        release_date: movie.release_date,
+                        status: movie.status,
+                        updated_at: Utc::now().naive_utc().date(),
+                        position: None,
+                        added_at: Utc::now().naive_utc(),
+                        note: None,
+                        priority: None,
+                    },
+                )?),
+                MixedMedia::Show(show) => MixedEntry::Show(ShowEntry::create(
+                    conn,
+                    ShowEntry {
+                        collection_id: collection.collection_id,
+                        user_id: collection.user_id,
+                        show_id: params.media_id,
+                        imdb_id: show.external_ids.and_then(|ids| ids.imdb_id),
+                        name: show.name,
+                        poster_path: show.poster_path,
+                        first_air_date: show.first_air_date,
+                        last_air_date: show.last_air_date,
+                        next_air_date: show.next_air_date,
+                        status: show.status,
+                        updated_at: Utc::now().naive_utc().date(),
+                        position: None,
+                        added_at: Utc::now().naive_utc(),
+                        note: None,
+                        priority: None,
+                    },
+                )?),
+            };
+
+            CollectionChange::create(
+                conn,
+                collection.collection_id,
+                auth.user_id,
+                &params.media_type,
+                params.media_id,
+                ENTRY_ADDED,
+            )?;
+
+            Ok(mixed_entry)
+        })
+    })
+    .await??;
+
+    Ok(Success::new(mixed_entry))
+}
+
+#[utoipa::path(tag = "Mixed Collection", responses((status = OK, body = DeleteResponse),(status = BAD_REQUEST)))]
+#[delete("/mixed/collections/{collection_id}/{media_type}/{media_id}")]
+async fn delete_entry(
+    pool: web::Data<DbPool>,
+    auth: Auth,
+    path: web::Path<(Uuid, String, i32)>,
+) -> actix_web::Result<impl Responder> {
+    let (collection_id, media_type, media_id) = path.into_inner();
+
+    if media_type != MOVIE_MEDIA_TYPE && media_type != SHOW_MEDIA_TYPE {
+        return Err(AppError::external(400, "Invalid media type"))?;
+    }
+
+    let count = web::block(move || {
+        let mut conn = pool.get()?;
+        let collection = Collection::find_shared(
+            &mut conn,
+            auth.user_id,
+            Some(MIXED_MEDIA_TYPE),
+            &collection_id,
+            &[COLLECTION_EDITOR],
+        )?;
+        conn.transaction::<usize, AppError, _>(|conn| {
+            let count = if media_type == MOVIE_MEDIA_TYPE {
+                MovieEntry::delete(conn, collection.collection_id, media_id)?
+            } else {
+                ShowEntry::delete(conn, collection.collection_id, media_id)?
+            };
+            if count > 0 {
+                CollectionChange::create(
+                    conn,
+                    collection.collection_id,
+                    auth.user_id,
+                    &media_type,
+                    media_id,
+                    ENTRY_REMOVED,
+                )?;
+            }
+            Ok(count)
+        })
+    })
+    .await??;
+
+    if count == 0 {
+        return Err(AppError::external(404, "Collection entry not found"))?;
+    }
+
+    Ok(Success::new(DeleteResponse { count }))
+}
+
+/// Updates a movie or show entry, with the same changes as the entries of movie
+/// collections
+#[utoipa::path(tag = "Mixed Collection", responses((status = OK, body = MixedEntry),(status = BAD_REQUEST)))]
+#[put("/mixed/collections/{collection_id}/{media_type}/{media_id}")]
+async fn update_entry(
+    pool: web::Data<DbPool>,
+    auth: Auth,
+    path: web::Path<(Uuid, String, i32)>,
+    params: web::Json<UpdatedMovieEntry>,
+) -> actix_web::Result<impl Responder> {
+    let (collection_id, media_type, media_id) = path.into_inner();
+    let params = params.into_inner();
+
+    if media_type != MOVIE_MEDIA_TYPE && media_type != SHOW_MEDIA_TYPE {
+        return Err(AppError::external(400, "Invalid media type"))?;
+    }
+    if params.added_at.is_none() && params.note.is_none() && params.priority.is_none() {
+        return Err(AppError::external(400, "No changes to save"))?;
+    }
+    if params
+        .priority
+        .flatten()
+        .is_some_and(|priority| !ENTRY_PRIORITIES.contains(&priority))
+    {
+        return Err(AppError::external(400, "Invalid priority"))?;
+    }
+
+    let mixed_entry = web::block(move || {
+        let mut conn = pool.get()?;
+        let collection = Collection::find_shared(
+            &mut conn,
+            auth.user_id,
+            Some(MIXED_MEDIA_TYPE),
+            &collection_id,
+            &[COLLECTION_EDITOR],
+        )?;
+        let mixed_entry = if media_type == MOVIE_MEDIA_TYPE {
+            MixedEntry::Movie(MovieEntry::update(
+                &mut conn,
+                collection.collection_id,
+                media_id,
+                params,
+            )?)
+        } else {
+            MixedEntry::Show(ShowEntry::update(
+                &mut conn,
+                collection.collection_id,
+                media_id,
+                UpdatedShowEntry {
+                    added_at: params.added_at,
+                    note: params.note,
+                    priority: params.priority,
+                },
+            )?)
+        };
+        Ok::<MixedEntry, AppError>(mixed_entry)
+    })
+    .await??;
+
+    Ok(Success::new(mixed_entry))
+}
+
+#[utoipa::path(tag = "Mixed Collection", responses((status = OK, body = MixedCollection),(status = BAD_REQUEST)))]
+#[put("/mixed/collections/{collection_id}/order")]
+async fn reorder(
+    pool: web::Data<DbPool>,
+    auth: Auth,
+    collection_id: web::Path<Uuid>,
+    params: web::Json<ReorderMixedCollectionRequest>,
+) -> actix_web::Result<impl Responder> {
+    let mixed_collection = web::block(move || {
+        let mut conn = pool.get()?;
+        let collection = Collection::find_shared(
+            &mut conn,
+            auth.user_id,
+            Some(MIXED_MEDIA_TYPE),
+            &collection_id,
+            &[COLLECTION_EDITOR],
+        )?;
+        MixedEntry::reorder(&mut conn, collection.collection_id, &params.entries)?;
+        let entries = MixedEntry::find_all(
+            &mut conn,
+            collection.user_id,
+            collection.collection_id,
+            EntrySort::Position,
+        )?;
+        Ok::<MixedCollection, AppError>(MixedCollection::from(collection).entries(entries))
+    })
+    .await??;
+
+    Ok(Success::new(mixed_collection))
+}
+
+/// Copies entries from a mixed collection to another collection, removing them from
+/// the source when moving. Entries already in the target are only removed
+fn transfer_entries(
+    conn: &mut DbConnection,
+    user_id: Uuid,
+    collection_id: Uuid,
+    params: TransferMixedCollectionRequest,
+    remove: bool,
+) -> Result<Vec<MixedEntry>, AppError> {
+    if collection_id == params.target_collection_id {
+        return Err(AppError::external(
+            400,
+            "Source and target collections must differ",
+        ));
+    }
+
+    let mut movie_ids = Vec::new();
+    let mut show_ids = Vec::new();
+    for entry in &params.entries {
+        match entry.media_type.as_str() {
+            MOVIE_MEDIA_TYPE => movie_ids.push(entry.media_id),
+            SHOW_MEDIA_TYPE => show_ids.push(entry.media_id),
+            _ => return Err(AppError::external(400, "Invalid media type")),
+        }
+    }
+
+    let source_roles: &[&str] = if remove {
+        &[COLLECTION_EDITOR]
+    } else {
+        &COLLECTION_ROLES
+    };
+    let source = Collection::find_shared(
+        conn,
+        user_id,
+        Some(MIXED_MEDIA_TYPE),
+        &collection_id,
+        source_roles,
+    )?;
+    let target = Collection::find_shared(
+        conn,
+        user_id,
+        None,
+        &params.target_collection_id,
+        &[COLLECTION_EDITOR],
+    )?;
+
+    if target.rules.is_some() {
+        return Err(AppError::external(
+            400,
+            "Entries cannot be added to smart collections",
+        ));
+    }
+    let holds = |media_type: &str, ids: &[i32]| {
+        ids.is_empty() || target.media_type == MIXED_MEDIA_TYPE || target.media_type == media_type
+    };
+    if !holds(MOVIE_MEDIA_TYPE, &movie_ids) || !holds(SHOW_MEDIA_TYPE, &show_ids) {
+        return Err(AppError::external(
+            400,
+            "Target collection cannot hold these entries",
+        ));
+    }
+
+    conn.transaction::<Vec<MixedEntry>, AppError, _>(|conn| {
+        let movie_entries = MovieEntry::copy(conn, source.collection_id, &target, &movie_ids)?;
+        for movie_entry in &movie_entries {
+            CollectionChange::create(
+                conn,
+                target.collection_id,
+                user_id,
+                MOVIE_MEDIA_TYPE,
+                movie_entry.movie_id,
+                ENTRY_ADDED,
+            )?;
+        }
+        let show_entries = ShowEntry::copy(conn, source.collection_id, &target, &show_ids)?;
+        for show_entry in &show_entries {
+            CollectionChange::create(
+                conn,
+                target.collection_id,
+                user_id,
+                SHOW_MEDIA_TYPE,
+                show_entry.show_id,
+                ENTRY_ADDED,
+            )?;
+        }
+
+        if remove {
+            for entry in &params.entries {
+                if entry.media_type == MOVIE_MEDIA_TYPE {
+                    MovieEntry::delete(conn, source.collection_id, entry.media_id)?;
+                } else {
+                    ShowEntry::delete(conn, source.collection_id, entry.media_id)?;
+                }
+                CollectionChange::create(
+                    conn,
+                    source.collection_id,
+                    user_id,
+                    &entry.media_type,
+                    entry.media_id,
+                    ENTRY_REMOVED,
+                )?;
+            }
+        }
+
+        Ok(movie_entries
+            .into_iter()
+            .map(MixedEntry::Movie)
+            .chain(show_entries.into_iter().map(MixedEntry::Show))
+            .collect())
+    })
+}
+
+#[utoipa::path(tag = "Mixed Collection", responses((status = OK, body = Vec<MixedEntry>),(status = BAD_REQUEST)))]
+#[post("/mixed/collections/{collection_id}/copy")]
+async fn copy_entries(
+    pool: web::Data<DbPool>,
+    auth: Auth,
+    collection_id: web::Path<Uuid>,
+    params: web::Json<TransferMixedCollectionRequest>,
+) -> actix_web::Result<impl Responder> {
+    let params = params.into_inner();
+
+    let mixed_entries = web::block(move || {
+        let mut conn = pool.get()?;
+        transfer_entries(&mut conn, auth.user_id, *collection_id, params, false)
+    })
+    .await??;
+
+    Ok(Success::new(mixed_entries))
+}
+
+#[utoipa::path(tag = "Mixed Collection", responses((status = OK, body = Vec<MixedEntry>),(status = BAD_REQUEST)))]
+#[post("/mixed/collections/{collection_id}/move")]
+async fn move_entries(
+    pool: web::Data<DbPool>,
+    auth: Auth,
+    collection_id: web::Path<Uuid>,
+    params: web::Json<TransferMixedCollectionRequest>,
+) -> actix_web::Result<impl Responder> {
+    let params = params.into_inner();
+
+    let mixed_entries = web::block(move || {
+        let mut conn = pool.get()?;
+        transfer_entries(&mut conn, auth.user_id, *collection_id, params, true)
+    })
+    .await??;
+
+    Ok(Success::new(mixed_entries))
+}
@@ -110,6 +110,31 @@ impl From<movie::Credits> for MovieCredits {
     }
 }
 
+/// The TMDB collection a movie belongs to, like a film series
+#[derive(ToSchema, Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct MovieFranchiseSummary {
+    pub id: i32,
+    pub name: String,
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub poster_path: Option<String>,
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub backdrop_path: Option<String>,
+}
+
+impl From<movie::BelongsToCollection> for MovieFranchiseSummary {
+    fn from(collection: movie::BelongsToCollection) -> Self {
+        MovieFranchiseSummary {
+            id: collection.id,
+            name: collection.name,
+            poster_path: collection.poster_path,
+            backdrop_path: collection.backdrop_path,
+        }
+    }
+}
+
 #[derive(ToSchema, Serialize, Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct Movie {
@@ -146,6 +171,50 @@ pub struct Movie {
     #[schema(nullable = false)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub credits: Option<MovieCredits>,
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub franchise: Option<MovieFranchiseSummary>,
+}
+
+/// A TMDB collection of movies, with its parts
+#[derive(ToSchema, Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct MovieFranchise {
+    pub id: i32,
+    pub name: String,
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub overview: Option<String>,
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub poster_path: Option<String>,
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub backdrop_path: Option<String>,
+    /// In release order, with unreleased movies without a date last
+    pub parts: Vec<Movie>,
+}
+
+impl From<movie::Collection> for MovieFranchise {
+    fn from(collection: movie::Collection) -> Self {
+        let mut parts: Vec<Movie> = collection.parts.into_iter().map(Movie::from).collect();
+        parts.sort_by(|a, b| {
+            (a.release_date.is_none(), a.release_date, &a.title).cmp(&(
+                b.release_date.is_none(),
+                b.release_date,
+                &b.title,
+            ))
+        });
+
+        MovieFranchise {
+            id: collection.id,
+            name: collection.name,
+            overview: collection.overview,
+            poster_path: collection.poster_path,
+            backdrop_path: collection.backdrop_path,
+            parts,
+        }
+    }
 }
 
 pub const MOVIE_ACTIVE_STATUSES: [&str; 4] =
@@ -195,6 +264,7 @@ impl From<movie::Movie> for Movie {
             imdb_id: movie.imdb_id,
             runtime: movie.runtime,
             credits,
+            franchise: movie.belongs_to_collection.map(MovieFranchiseSummary::from),
         }
     }
 }
@@ -222,6 +292,22 @@ impl Movie {
         Ok(Movie::from(movie))
     }
 
+    pub async fn find_franchise(client: &TmdbClient, id: &i32) -> Result<MovieFranchise, AppError> {
+        let request_url = generate_endpoint(format!("collection/{id}"), None);
+
+        let response = client.get(&request_url).send().await?;
+
+        if !response.status().is_success() {
+            return Err(AppError::tmdb_error(
+                response.status().as_u16(),
+                response.text().await?.as_str(),
+            ));
+        }
+
+        let collection = response.json::<movie::Collection>().await?;
+        Ok(MovieFranchise::from(collection))
+    }
+
     pub async fn search(client: &TmdbClient, query: &str) -> Result<Vec<Movie>, AppError> {
         let request_url = generate_endpoint(
             "search/movie".to_string(),
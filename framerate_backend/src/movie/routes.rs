@@ -1,5 +1,5 @@
 use crate::{
-    movie::Movie,
+    movie::{Movie, MovieFranchise},
     tmdb::TmdbClient,
     utils::{jwt::Auth, response_body::Success},
 };
@@ -43,3 +43,15 @@ async fn details(
 
     Ok(Success::new(movie))
 }
+
+#[utoipa::path(tag = "Movie", responses((status = OK, body = MovieFranchise),(status = NOT_FOUND)))]
+#[get("/movies/franchises/{franchise_id}")]
+async fn franchise(
+    _: Auth,
+    client: web::Data<TmdbClient>,
+    franchise_id: web::Path<i32>,
+) -> actix_web::Result<impl Responder> {
+    let franchise = Movie::find_franchise(&client, &franchise_id.into_inner()).await?;
+
+    Ok(Success::new(franchise))
+}
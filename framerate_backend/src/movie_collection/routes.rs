@@ -1,14 +1,22 @@
-use crate::collection::{Collection, UpdatedCollection};
-use crate::db::DbPool;
+use crate::collection::{
+    Collection, CollectionFindParameters, CollectionRules, EntrySort, UpdatedCollection,
+    ENTRY_PRIORITIES,
+};
+use crate::collection_change::{CollectionChange, ENTRY_ADDED, ENTRY_REMOVED};
+use crate::collection_member::{COLLECTION_EDITOR, COLLECTION_ROLES};
+use crate::db::{DbConnection, DbPool};
+use crate::import::find_each;
 use crate::movie::{Movie, MOVIE_MEDIA_TYPE};
-use crate::movie_entry::MovieEntry;
+use crate::movie_entry::{MovieEntry, UpdatedMovieEntry};
 use crate::tmdb::TmdbClient;
 use crate::utils::response_body::{DeleteResponse, Success};
 use crate::utils::{jwt::Auth, AppError};
 use actix_web::{delete, put, Responder};
 use actix_web::{get, post, web};
 use chrono::Utc;
+use diesel::Connection;
 use serde::{Deserialize, Serialize};
+use tracing::warn;
 use utoipa::ToSchema;
 use uuid::Uuid;
 
@@ -18,19 +26,27 @@ pub struct MovieCollection {
     pub collection_id: Uuid,
     pub user_id: Uuid,
     pub name: String,
+    /// Only set on smart collections
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rules: Option<CollectionRules>,
     #[schema(nullable = false)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub entries: Option<Vec<MovieEntry>>,
 }
 
-impl From<Collection> for MovieCollection {
-    fn from(value: Collection) -> Self {
-        MovieCollection {
+impl TryFrom<Collection> for MovieCollection {
+    type Error = AppError;
+
+    fn try_from(value: Collection) -> Result<Self, Self::Error> {
+        let rules = value.smart_rules()?;
+        Ok(MovieCollection {
             collection_id: value.collection_id,
             name: value.name,
             user_id: value.user_id,
+            rules,
             entries: None,
-        }
+        })
     }
 }
 
@@ -45,6 +61,10 @@ impl MovieCollection {
 #[serde(rename_all = "camelCase")]
 pub struct NewMovieCollection {
     pub name: String,
+    /// Creates a smart collection, which can't have entries added to it
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rules: Option<CollectionRules>,
 }
 
 #[derive(Serialize, Deserialize, ToSchema)]
@@ -53,6 +73,35 @@ pub struct SaveMovieCollectionEntryRequest {
     pub movie_id: i32,
 }
 
+#[derive(Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SaveMovieCollectionFranchiseRequest {
+    pub franchise_id: i32,
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SaveMovieCollectionFranchiseResponse {
+    pub entries: Vec<MovieEntry>,
+    /// Parts left out because TMDB couldn't return their details, which can be added
+    /// again later
+    pub failed_movie_ids: Vec<i32>,
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ReorderMovieCollectionRequest {
+    /// Entries in the order to show them in. Entries left out are listed after these
+    pub movie_ids: Vec<i32>,
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferMovieCollectionRequest {
+    pub target_collection_id: Uuid,
+    pub movie_ids: Vec<i32>,
+}
+
 #[utoipa::path(tag = "Movie Collection", responses((status = OK, body = Vec<MovieCollection>)))]
 #[get("/movies/collections")]
 async fn find_all(pool: web::Data<DbPool>, auth: Auth) -> actix_web::Result<impl Responder> {
@@ -64,25 +113,46 @@ async fn find_all(pool: web::Data<DbPool>, auth: Auth) -> actix_web::Result<impl
 
     let collections = collections
         .into_iter()
-        .map(MovieCollection::from)
-        .collect::<Vec<MovieCollection>>();
+        .map(MovieCollection::try_from)
+        .collect::<Result<Vec<MovieCollection>, AppError>>()?;
 
     Ok(Success::new(collections))
 }
 
-#[utoipa::path(tag = "Movie Collection", responses((status = OK, body = MovieCollection)))]
+#[utoipa::path(tag = "Movie Collection", params(CollectionFindParameters), responses((status = OK, body = MovieCollection),(status = BAD_REQUEST)))]
 #[get("/movies/collections/{collection_id}")]
 async fn find(
     pool: web::Data<DbPool>,
     auth: Auth,
     collection_id: web::Path<Uuid>,
+    params: web::Query<CollectionFindParameters>,
 ) -> actix_web::Result<impl Responder> {
     let movie_collection = web::block(move || {
         let mut conn = pool.get()?;
-        let collection =
-            Collection::find(&mut conn, auth.user_id, MOVIE_MEDIA_TYPE, &collection_id)?;
-        let entries = MovieEntry::find_all(&mut conn, auth.user_id, collection.collection_id)?;
-        Ok::<MovieCollection, AppError>(MovieCollection::from(collection).entries(entries))
+        let collection = Collection::find_shared(
+            &mut conn,
+            auth.user_id,
+            Some(MOVIE_MEDIA_TYPE),
+            &collection_id,
+            &COLLECTION_ROLES,
+        )?;
+        let sort = params.sort.unwrap_or_default();
+        let entries = match collection.smart_rules()? {
+            Some(rules) => MovieEntry::find_by_rules(
+                &mut conn,
+                collection.user_id,
+                collection.collection_id,
+                &rules,
+                sort,
+            )?,
+            None => MovieEntry::find_all(
+                &mut conn,
+                collection.user_id,
+                collection.collection_id,
+                sort,
+            )?,
+        };
+        Ok::<MovieCollection, AppError>(MovieCollection::try_from(collection)?.entries(entries))
     })
     .await??;
 
@@ -104,15 +174,19 @@ async fn create(
         name: params.name,
         media_type: MOVIE_MEDIA_TYPE.to_string(),
         default_for: None,
+        rules: params.rules.as_ref().map(|rules| serde_json::json!(rules)),
     };
 
     let collection = web::block(move || {
         let mut conn = pool.get()?;
+        if let Some(rules) = &params.rules {
+            rules.validate(&mut conn, auth.user_id, MOVIE_MEDIA_TYPE)?;
+        }
         Collection::create(&mut conn, collection)
     })
     .await??;
 
-    Ok(Success::new(MovieCollection::from(collection)))
+    Ok(Success::new(MovieCollection::try_from(collection)?))
 }
 
 #[utoipa::path(tag = "Movie Collection", responses((status = OK, body = MovieCollection)))]
@@ -131,7 +205,7 @@ async fn update(
     })
     .await??;
 
-    Ok(Success::new(MovieCollection::from(collection)))
+    Ok(Success::new(MovieCollection::try_from(collection)?))
 }
 
 #[utoipa::path(tag = "Movie Collection", responses((status = OK, body = DeleteResponse)))]
@@ -167,12 +241,24 @@ async fn create_entry(
 
     let movie_entry = web::block(move || {
         let mut conn = pool.get()?;
-        let collection =
-            Collection::find(&mut conn, auth.user_id, MOVIE_MEDIA_TYPE, &collection_id)?;
+        let collection = Collection::find_shared(
+            &mut conn,
+            auth.user_id,
+            Some(MOVIE_MEDIA_TYPE),
+            &collection_id,
+            &[COLLECTION_EDITOR],
+        )?;
+
+        if collection.rules.is_some() {
+            return Err(AppError::external(
+                400,
+                "Entries cannot be added to smart collections",
+            ));
+        }
 
         let movie_entry_to_save = MovieEntry {
             collection_id: collection.collection_id,
-            user_id: auth.user_id,
+            user_id: collection.user_id,
             movie_id: movie_entry.movie_id,
             imdb_id: movie.imdb_id,
             title: movie.title,
@@ -180,9 +266,172 @@ async fn create_entry(
             release_date: movie.release_date,
             status: movie.status,
             updated_at: Utc::now().naive_utc().date(),
+            position: None,
+            added_at: Utc::now().naive_utc(),
+            note: None,
+            priority: None,
         };
 
-        MovieEntry::create(&mut conn, movie_entry_to_save)
+        conn.transaction::<MovieEntry, AppError, _>(|conn| {
+            let movie_entry = MovieEntry::create(conn, movie_entry_to_save)?;
+            CollectionChange::create(
+                conn,
+                collection.collection_id,
+                auth.user_id,
+                MOVIE_MEDIA_TYPE,
+                movie_entry.movie_id,
+                ENTRY_ADDED,
+            )?;
+            Ok(movie_entry)
+        })
+    })
+    .await??;
+
+    Ok(Success::new(movie_entry))
+}
+
+/// Adds every part of a TMDB franchise to the collection. Parts already in the
+/// collection are left as they are and not requested from TMDB, and only the new
+/// entries are returned. Parts whose details TMDB fails to return are skipped and
+/// reported instead of failing the whole request
+#[utoipa::path(tag = "Movie Collection", responses((status = OK, body = SaveMovieCollectionFranchiseResponse),(status = BAD_REQUEST)))]
+#[post("/movies/collections/{collection_id}/franchise")]
+async fn create_franchise_entries(
+    pool: web::Data<DbPool>,
+    client: web::Data<TmdbClient>,
+    auth: Auth,
+    collection_id: web::Path<Uuid>,
+    params: web::Json<SaveMovieCollectionFranchiseRequest>,
+) -> actix_web::Result<impl Responder> {
+    let (collection, existing) = {
+        let pool = pool.clone();
+        web::block(move || {
+            let mut conn = pool.get()?;
+            let collection = Collection::find_shared(
+                &mut conn,
+                auth.user_id,
+                Some(MOVIE_MEDIA_TYPE),
+                &collection_id,
+                &[COLLECTION_EDITOR],
+            )?;
+
+            if collection.rules.is_some() {
+                return Err(AppError::external(
+                    400,
+                    "Entries cannot be added to smart collections",
+                ));
+            }
+
+            let existing = MovieEntry::find_movie_ids(&mut conn, collection.collection_id)?;
+
+            Ok((collection, existing))
+        })
+        .await??
+    };
+
+    let franchise = Movie::find_franchise(&client, &params.franchise_id).await?;
+
+    let client = client.get_ref();
+    let part_ids = franchise
+        .parts
+        .iter()
+        .map(|part| part.id)
+        .filter(|movie_id| !existing.contains(movie_id));
+    let mut parts = find_each(part_ids.clone(), |movie_id| async move {
+        Movie::find(client, &movie_id).await
+    })
+    .await;
+
+    let mut movies = vec![];
+    let mut failed_movie_ids = vec![];
+    for movie_id in part_ids {
+        match parts.remove(&movie_id) {
+            Some(Ok(movie)) => movies.push(movie),
+            Some(Err(error)) => {
+                warn!("Unable to add franchise part {movie_id}: {error}");
+                failed_movie_ids.push(movie_id);
+            }
+            None => {}
+        }
+    }
+
+    let movie_entries = web::block(move || {
+        let mut conn = pool.get()?;
+
+        let movie_entries_to_save = movies
+            .into_iter()
+            .map(|movie| MovieEntry {
+                collection_id: collection.collection_id,
+                user_id: collection.user_id,
+                movie_id: movie.id,
+                imdb_id: movie.imdb_id,
+                title: movie.title,
+                poster_path: movie.poster_path,
+                release_date: movie.release_date,
+                status: movie.status,
+                updated_at: Utc::now().naive_utc().date(),
+                position: None,
+                added_at: Utc::now().naive_utc(),
+                note: None,
+                priority: None,
+            })
+            .collect();
+
+        conn.transaction::<Vec<MovieEntry>, AppError, _>(|conn| {
+            let movie_entries = MovieEntry::create_all(conn, movie_entries_to_save)?;
+            for movie_entry in &movie_entries {
+                CollectionChange::create(
+                    conn,
+                    collection.collection_id,
+                    auth.user_id,
+                    MOVIE_MEDIA_TYPE,
+                    movie_entry.movie_id,
+                    ENTRY_ADDED,
+                )?;
+            }
+            Ok(movie_entries)
+        })
+    })
+    .await??;
+
+    Ok(Success::new(SaveMovieCollectionFranchiseResponse {
+        entries: movie_entries,
+        failed_movie_ids,
+    }))
+}
+
+#[utoipa::path(tag = "Movie Collection", responses((status = OK, body = MovieEntry),(status = BAD_REQUEST)))]
+#[put("/movies/collections/{collection_id}/{movie_id}")]
+async fn update_entry(
+    pool: web::Data<DbPool>,
+    auth: Auth,
+    path: web::Path<(Uuid, i32)>,
+    params: web::Json<UpdatedMovieEntry>,
+) -> actix_web::Result<impl Responder> {
+    let (collection_id, movie_id) = path.into_inner();
+    let params = params.into_inner();
+
+    if params.added_at.is_none() && params.note.is_none() && params.priority.is_none() {
+        return Err(AppError::external(400, "No changes to save"))?;
+    }
+    if params
+        .priority
+        .flatten()
+        .is_some_and(|priority| !ENTRY_PRIORITIES.contains(&priority))
+    {
+        return Err(AppError::external(400, "Invalid priority"))?;
+    }
+
+    let movie_entry = web::block(move || {
+        let mut conn = pool.get()?;
+        let collection = Collection::find_shared(
+            &mut conn,
+            auth.user_id,
+            Some(MOVIE_MEDIA_TYPE),
+            &collection_id,
+            &[COLLECTION_EDITOR],
+        )?;
+        MovieEntry::update(&mut conn, collection.collection_id, movie_id, params)
     })
     .await??;
 
@@ -200,9 +449,27 @@ async fn delete_entry(
 
     let count = web::block(move || {
         let mut conn = pool.get()?;
-        let collection =
-            Collection::find(&mut conn, auth.user_id, MOVIE_MEDIA_TYPE, &collection_id)?;
-        MovieEntry::delete(&mut conn, collection.collection_id, movie_id)
+        let collection = Collection::find_shared(
+            &mut conn,
+            auth.user_id,
+            Some(MOVIE_MEDIA_TYPE),
+            &collection_id,
+            &[COLLECTION_EDITOR],
+        )?;
+        conn.transaction::<usize, AppError, _>(|conn| {
+            let count = MovieEntry::delete(conn, collection.collection_id, movie_id)?;
+            if count > 0 {
+                CollectionChange::create(
+                    conn,
+                    collection.collection_id,
+                    auth.user_id,
+                    MOVIE_MEDIA_TYPE,
+                    movie_id,
+                    ENTRY_REMOVED,
+                )?;
+            }
+            Ok(count)
+        })
     })
     .await??;
 
@@ -213,6 +480,150 @@ async fn delete_entry(
     Ok(Success::new(DeleteResponse { count }))
 }
 
+#[utoipa::path(tag = "Movie Collection", responses((status = OK, body = MovieCollection),(status = BAD_REQUEST)))]
+#[put("/movies/collections/{collection_id}/order")]
+async fn reorder(
+    pool: web::Data<DbPool>,
+    auth: Auth,
+    collection_id: web::Path<Uuid>,
+    params: web::Json<ReorderMovieCollectionRequest>,
+) -> actix_web::Result<impl Responder> {
+    let movie_collection = web::block(move || {
+        let mut conn = pool.get()?;
+        let collection = Collection::find_shared(
+            &mut conn,
+            auth.user_id,
+            Some(MOVIE_MEDIA_TYPE),
+            &collection_id,
+            &[COLLECTION_EDITOR],
+        )?;
+        MovieEntry::reorder(&mut conn, collection.collection_id, &params.movie_ids)?;
+        let entries = MovieEntry::find_all(
+            &mut conn,
+            collection.user_id,
+            collection.collection_id,
+            EntrySort::Position,
+        )?;
+        Ok::<MovieCollection, AppError>(MovieCollection::try_from(collection)?.entries(entries))
+    })
+    .await??;
+
+    Ok(Success::new(movie_collection))
+}
+
+/// Copies entries from one collection to another, removing them from the source when
+/// moving. Entries already in the target are only removed
+fn transfer_entries(
+    conn: &mut DbConnection,
+    user_id: Uuid,
+    collection_id: Uuid,
+    params: TransferMovieCollectionRequest,
+    remove: bool,
+) -> Result<Vec<MovieEntry>, AppError> {
+    if collection_id == params.target_collection_id {
+        return Err(AppError::external(
+            400,
+            "Source and target collections must differ",
+        ));
+    }
+
+    let source_roles: &[&str] = if remove {
+        &[COLLECTION_EDITOR]
+    } else {
+        &COLLECTION_ROLES
+    };
+    let source = Collection::find_shared(
+        conn,
+        user_id,
+        Some(MOVIE_MEDIA_TYPE),
+        &collection_id,
+        source_roles,
+    )?;
+    let target = Collection::find_shared(
+        conn,
+        user_id,
+        Some(MOVIE_MEDIA_TYPE),
+        &params.target_collection_id,
+        &[COLLECTION_EDITOR],
+    )?;
+
+    if target.rules.is_some() {
+        return Err(AppError::external(
+            400,
+            "Entries cannot be added to smart collections",
+        ));
+    }
+
+    conn.transaction::<Vec<MovieEntry>, AppError, _>(|conn| {
+        let movie_entries =
+            MovieEntry::copy(conn, source.collection_id, &target, &params.movie_ids)?;
+        for movie_entry in &movie_entries {
+            CollectionChange::create(
+                conn,
+                target.collection_id,
+                user_id,
+                MOVIE_MEDIA_TYPE,
+                movie_entry.movie_id,
+                ENTRY_ADDED,
+            )?;
+        }
+
+        if remove {
+            for movie_id in &params.movie_ids {
+                MovieEntry::delete(conn, source.collection_id, *movie_id)?;
+                CollectionChange::create(
+                    conn,
+                    source.collection_id,
+                    user_id,
+                    MOVIE_MEDIA_TYPE,
+                    *movie_id,
+                    ENTRY_REMOVED,
+                )?;
+            }
+        }
+
+        Ok(movie_entries)
+    })
+}
+
+#[utoipa::path(tag = "Movie Collection", responses((status = OK, body = Vec<MovieEntry>),(status = BAD_REQUEST)))]
+#[post("/movies/collections/{collection_id}/copy")]
+async fn copy_entries(
+    pool: web::Data<DbPool>,
+    auth: Auth,
+    collection_id: web::Path<Uuid>,
+    params: web::Json<TransferMovieCollectionRequest>,
+) -> actix_web::Result<impl Responder> {
+    let params = params.into_inner();
+
+    let movie_entries = web::block(move || {
+        let mut conn = pool.get()?;
+        transfer_entries(&mut conn, auth.user_id, *collection_id, params, false)
+    })
+    .await??;
+
+    Ok(Success::new(movie_entries))
+}
+
+#[utoipa::path(tag = "Movie Collection", responses((status = OK, body = Vec<MovieEntry>),(status = BAD_REQUEST)))]
+#[post("/movies/collections/{collection_id}/move")]
+async fn move_entries(
+    pool: web::Data<DbPool>,
+    auth: Auth,
+    collection_id: web::Path<Uuid>,
+    params: web::Json<TransferMovieCollectionRequest>,
+) -> actix_web::Result<impl Responder> {
+    let params = params.into_inner();
+
+    let movie_entries = web::block(move || {
+        let mut conn = pool.get()?;
+        transfer_entries(&mut conn, auth.user_id, *collection_id, params, true)
+    })
+    .await??;
+
+    Ok(Success::new(movie_entries))
+}
+
 #[utoipa::path(tag = "Movie Collection", responses((status = OK, body = Vec<Uuid>)))]
 #[get("/movies/collections/movie/{movie_id}")]
 async fn find_by_movie(
@@ -1,12 +1,21 @@
+use crate::collection::{
+    self, code_point_order, Collection, CollectionRules, EntrySort, RuleSource, SortableEntry,
+    WatchlistOnReview, DEFAULT_WATCHED, DEFAULT_WATCHLIST,
+};
 use crate::db::DbConnection;
-use crate::movie::{Movie, MOVIE_ACTIVE_STATUSES};
-use crate::schema::movie_entries;
+use crate::movie::{Movie, MOVIE_ACTIVE_STATUSES, MOVIE_MEDIA_TYPE};
+use crate::movie_review::MovieReview;
+use crate::schema::{
+    collections, movie_entries, movie_reviews, review_company, review_tag, reviews, users,
+};
 use crate::tmdb::TmdbClient;
-use crate::utils::AppError;
-use crate::{collection, user};
-use chrono::{NaiveDate, TimeDelta, Utc};
+use crate::user;
+use crate::utils::{serialization::nullable, AppError};
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime, TimeDelta, Utc};
 use diesel::prelude::*;
+use diesel::sql_types::{Nullable, Text};
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 use utoipa::ToSchema;
 use uuid::Uuid;
 
@@ -44,6 +53,54 @@ pub struct MovieEntry {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status: Option<String>,
     pub updated_at: NaiveDate,
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<i32>,
+    pub added_at: NaiveDateTime,
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<i16>,
+}
+
+/// Changes to an entry. Fields left out are kept, and `note` and `priority` are
+/// cleared when set to null
+#[derive(Debug, Default, Deserialize, Serialize, AsChangeset, ToSchema)]
+#[diesel(table_name = movie_entries)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdatedMovieEntry {
+    #[schema(nullable = false)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub added_at: Option<NaiveDateTime>,
+    #[schema(value_type = Option<String>)]
+    #[serde(
+        default,
+        deserialize_with = "nullable",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub note: Option<Option<String>>,
+    /// From 1 (lowest) to 5 (highest)
+    #[schema(value_type = Option<i16>)]
+    #[serde(
+        default,
+        deserialize_with = "nullable",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub priority: Option<Option<i16>>,
+}
+
+/// A watchlist entry removed when the movie was reviewed, so the client can undo it
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RemovedMovieEntry {
+    /// The entry as it was in the watchlist
+    pub entry: MovieEntry,
+    /// The watched collection the entry was moved to, when it wasn't only removed
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub moved_to: Option<Uuid>,
 }
 
 impl MovieEntry {
@@ -51,16 +108,160 @@ impl MovieEntry {
         conn: &mut DbConnection,
         user_id: Uuid,
         collection_id: Uuid,
+        sort: EntrySort,
     ) -> Result<Vec<Self>, AppError> {
-        let movie_entries = movie_entries::table
+        let query = movie_entries::table
             .filter(movie_entries::user_id.eq(user_id))
             .filter(movie_entries::collection_id.eq(collection_id))
-            .order(movie_entries::release_date.desc())
             .select(MovieEntry::as_select())
-            .load(conn)?;
+            .into_boxed();
+
+        let query = match sort {
+            EntrySort::Position => query.order((
+                movie_entries::position.asc().nulls_last(),
+                movie_entries::added_at.asc(),
+                code_point_order::<Text>("movie_entries.title").asc(),
+            )),
+            EntrySort::Title => query.order(code_point_order::<Text>("movie_entries.title").asc()),
+            EntrySort::ReleaseDate => query.order(movie_entries::release_date.desc().nulls_last()),
+            EntrySort::DateAdded => query.order((
+                movie_entries::added_at.desc(),
+                code_point_order::<Text>("movie_entries.title").asc(),
+            )),
+            EntrySort::Status => query.order((
+                code_point_order::<Nullable<Text>>("movie_entries.status")
+                    .asc()
+                    .nulls_last(),
+                code_point_order::<Text>("movie_entries.title").asc(),
+            )),
+        };
+
+        let movie_entries = query.load(conn)?;
         Ok(movie_entries)
     }
 
+    /// Finds the entries of a smart collection, listing each movie once
+    pub fn find_by_rules(
+        conn: &mut DbConnection,
+        user_id: Uuid,
+        collection_id: Uuid,
+        rules: &CollectionRules,
+        sort: EntrySort,
+    ) -> Result<Vec<Self>, AppError> {
+        let mut entries = match rules.source {
+            RuleSource::Reviews => Self::find_reviewed(conn, user_id, rules)?,
+            RuleSource::Entries => Self::find_matching(conn, user_id, rules)?,
+        };
+
+        let mut seen = HashSet::new();
+        entries.retain(|entry| seen.insert(entry.movie_id));
+        for entry in &mut entries {
+            entry.collection_id = collection_id;
+            entry.position = None;
+        }
+
+        entries.sort_by(|a, b| sort.compare(a, b));
+        Ok(entries)
+    }
+
+    /// Reviewed movies matching the rules, latest review first
+    fn find_reviewed(
+        conn: &mut DbConnection,
+        user_id: Uuid,
+        rules: &CollectionRules,
+    ) -> Result<Vec<Self>, AppError> {
+        let mut query = movie_reviews::table
+            .inner_join(reviews::table)
+            .filter(movie_reviews::user_id.eq(user_id))
+            .select((MovieReview::as_select(), reviews::date))
+            .into_boxed();
+
+        if let Some(min_rating) = rules.min_rating {
+            query = query.filter(reviews::rating.ge(min_rating));
+        }
+
+        if let Some(company_id) = rules.company_id {
+            query = query.filter(
+                movie_reviews::review_id.eq_any(
+                    review_company::table
+                        .filter(review_company::company_id.eq(company_id))
+                        .select(review_company::review_id),
+                ),
+            );
+        }
+
+        if let Some(tag_id) = rules.tag_id {
+            query = query.filter(
+                movie_reviews::review_id.eq_any(
+                    review_tag::table
+                        .filter(review_tag::tag_id.eq(tag_id))
+                        .select(review_tag::review_id),
+                ),
+            );
+        }
+
+        let reviews = query
+            .order(reviews::date.desc().nulls_last())
+            .load::<(MovieReview, Option<NaiveDate>)>(conn)?;
+
+        let today = Utc::now().date_naive();
+        let entries = reviews
+            .into_iter()
+            .map(|(review, date)| MovieEntry {
+                collection_id: Uuid::nil(),
+                movie_id: review.movie_id,
+                user_id: review.user_id,
+                title: review.title,
+                imdb_id: review.imdb_id,
+                poster_path: review.poster_path,
+                release_date: review.release_date,
+                status: None,
+                updated_at: today,
+                position: None,
+                added_at: date.unwrap_or(today).and_time(NaiveTime::MIN),
+                note: None,
+                priority: None,
+            })
+            .collect();
+
+        Ok(entries)
+    }
+
+    /// Entries of static collections matching the rules, earliest added first
+    fn find_matching(
+        conn: &mut DbConnection,
+        user_id: Uuid,
+        rules: &CollectionRules,
+    ) -> Result<Vec<Self>, AppError> {
+        let mut query = movie_entries::table
+            .inner_join(collections::table)
+            .filter(movie_entries::user_id.eq(user_id))
+            .select(MovieEntry::as_select())
+            .into_boxed();
+
+        if let Some(collection_id) = rules.collection_id {
+            query = query.filter(movie_entries::collection_id.eq(collection_id));
+        }
+
+        if rules.watchlist {
+            query = query.filter(collections::default_for.eq(DEFAULT_WATCHLIST));
+        }
+
+        if let Some(statuses) = &rules.statuses {
+            query = query.filter(movie_entries::status.eq_any(statuses));
+        }
+
+        if let Some(days) = rules.upcoming_days {
+            let today = Utc::now().date_naive();
+            query = query.filter(
+                movie_entries::release_date.between(today, today + TimeDelta::days(days.into())),
+            );
+        }
+
+        let entries = query.order(movie_entries::added_at.asc()).load(conn)?;
+        Ok(entries)
+    }
+
     pub fn find(
         conn: &mut DbConnection,
         user_id: Uuid,
@@ -77,13 +278,57 @@ impl MovieEntry {
         Ok(movie_entries)
     }
 
+    /// The ids of the movies in a collection
+    pub fn find_movie_ids(
+        conn: &mut DbConnection,
+        collection_id: Uuid,
+    ) -> Result<HashSet<i32>, AppError> {
+        let movie_ids = movie_entries::table
+            .filter(movie_entries::collection_id.eq(collection_id))
+            .select(movie_entries::movie_id)
+            .load::<i32>(conn)?
+            .into_iter()
+            .collect();
+        Ok(movie_ids)
+    }
+
     pub fn create(conn: &mut DbConnection, watchlist_entry: MovieEntry) -> Result<Self, AppError> {
         let new_watchlist = diesel::insert_into(movie_entries::table)
             .values(watchlist_entry)
+            .returning(MovieEntry::as_returning())
             .get_result(conn)?;
         Ok(new_watchlist)
     }
 
+    /// Saves the entries, skipping any already in their collection. Returns the entries
+    /// that were saved
+    pub fn create_all(
+        conn: &mut DbConnection,
+        entries: Vec<MovieEntry>,
+    ) -> Result<Vec<Self>, AppError> {
+        let new_entries = diesel::insert_into(movie_entries::table)
+            .values(entries)
+            .on_conflict_do_nothing()
+            .returning(MovieEntry::as_returning())
+            .get_results(conn)?;
+        Ok(new_entries)
+    }
+
+    pub fn update(
+        conn: &mut DbConnection,
+        collection_id: Uuid,
+        movie_id: i32,
+        entry: UpdatedMovieEntry,
+    ) -> Result<Self, AppError> {
+        let updated_entry = diesel::update(movie_entries::table)
+            .filter(movie_entries::collection_id.eq(collection_id))
+            .filter(movie_entries::movie_id.eq(movie_id))
+            .set(entry)
+            .returning(MovieEntry::as_returning())
+            .get_result(conn)?;
+        Ok(updated_entry)
+    }
+
     pub fn delete(
         conn: &mut DbConnection,
         collection_id: Uuid,
@@ -100,6 +345,155 @@ impl MovieEntry {
         Ok(res)
     }
 
+    /// Copies entries to another collection without fetching them from TMDB again,
+    /// keeping their note and priority. Returns the entries that weren't already in
+    /// the target collection
+    pub fn copy(
+        conn: &mut DbConnection,
+        collection_id: Uuid,
+        target: &collection::Collection,
+        movie_ids: &[i32],
+    ) -> Result<Vec<Self>, AppError> {
+        if movie_ids.iter().collect::<HashSet<_>>().len() != movie_ids.len() {
+            return Err(AppError::external(400, "Duplicate collection entry"));
+        }
+
+        let entries = movie_entries::table
+            .filter(movie_entries::collection_id.eq(collection_id))
+            .filter(movie_entries::movie_id.eq_any(movie_ids))
+            .select(MovieEntry::as_select())
+            .load(conn)?;
+
+        if entries.len() != movie_ids.len() {
+            return Err(AppError::external(400, "Collection entry not found"));
+        }
+
+        let now = Utc::now().naive_utc();
+        let entries = entries
+            .into_iter()
+            .map(|entry| MovieEntry {
+                collection_id: target.collection_id,
+                user_id: target.user_id,
+                position: None,
+                added_at: now,
+                ..entry
+            })
+            .collect::<Vec<MovieEntry>>();
+
+        MovieEntry::create_all(conn, entries)
+    }
+
+    /// Takes a reviewed movie off the user's default watchlist, removing it or moving it
+    /// to their default watched collection as set in their configuration
+    pub fn remove_reviewed(
+        conn: &mut DbConnection,
+        user_id: Uuid,
+        movie_id: i32,
+    ) -> Result<Option<RemovedMovieEntry>, AppError> {
+        let configuration = users::table
+            .filter(users::user_id.eq(user_id))
+            .select(users::configuration)
+            .first::<serde_json::Value>(conn)?;
+        let on_review = WatchlistOnReview::from_configuration(&configuration);
+
+        if on_review == WatchlistOnReview::Keep {
+            return Ok(None);
+        }
+
+        let Some(watchlist) =
+            Collection::find_optional_default(conn, user_id, MOVIE_MEDIA_TYPE, DEFAULT_WATCHLIST)?
+        else {
+            return Ok(None);
+        };
+
+        let Some(entry) = movie_entries::table
+            .filter(movie_entries::collection_id.eq(watchlist.collection_id))
+            .filter(movie_entries::movie_id.eq(movie_id))
+            .select(MovieEntry::as_select())
+            .first(conn)
+            .optional()?
+        else {
+            return Ok(None);
+        };
+
+        let moved_to = match on_review {
+            WatchlistOnReview::Move => {
+                let watched = Collection::find_or_create_default(
+                    conn,
+                    user_id,
+                    MOVIE_MEDIA_TYPE,
+                    DEFAULT_WATCHED,
+                    "Watched Movies",
+                )?;
+                MovieEntry::copy(conn, watchlist.collection_id, &watched, &[movie_id])?;
+                Some(watched.collection_id)
+            }
+            _ => None,
+        };
+
+        MovieEntry::delete(conn, watchlist.collection_id, movie_id)?;
+
+        Ok(Some(RemovedMovieEntry { entry, moved_to }))
+    }
+
+    /// Places the entries of a collection in the order of `movie_ids`. Entries left out
+    /// lose their position and are listed after the ordered ones
+    pub fn reorder(
+        conn: &mut DbConnection,
+        collection_id: Uuid,
+        movie_ids: &[i32],
+    ) -> Result<(), AppError> {
+        let positions: Vec<(i32, i32)> = movie_ids.iter().copied().zip(0..).collect();
+        Self::set_positions(conn, collection_id, &positions)
+    }
+
+    /// Sets the positions of the entries of a collection, given as pairs of ID and
+    /// position. Entries left out lose their position
+    pub fn set_positions(
+        conn: &mut DbConnection,
+        collection_id: Uuid,
+        positions: &[(i32, i32)],
+    ) -> Result<(), AppError> {
+        conn.transaction::<(), AppError, _>(|conn| {
+            let entries: HashSet<i32> = movie_entries::table
+                .filter(movie_entries::collection_id.eq(collection_id))
+                .select(movie_entries::movie_id)
+                .load::<i32>(conn)?
+                .into_iter()
+                .collect();
+
+            let mut ordered = HashSet::new();
+            for (movie_id, _) in positions {
+                if !entries.contains(movie_id) {
+                    return Err(AppError::external(400, "Collection entry not found"));
+                }
+                if !ordered.insert(movie_id) {
+                    return Err(AppError::external(400, "Duplicate collection entry"));
+                }
+            }
+
+            diesel::update(
+                movie_entries::table.filter(movie_entries::collection_id.eq(collection_id)),
+            )
+            .set(movie_entries::position.eq(None::<i32>))
+            .execute(conn)?;
+
+            for (movie_id, position) in positions {
+                diesel::update(
+                    movie_entries::table.filter(
+                        movie_entries::collection_id
+                            .eq(collection_id)
+                            .and(movie_entries::movie_id.eq(movie_id)),
+                    ),
+                )
+                .set(movie_entries::position.eq(position))
+                .execute(conn)?;
+            }
+
+            Ok(())
+        })
+    }
+
     pub fn find_collections(
         conn: &mut DbConnection,
         user_id: Uuid,
@@ -146,9 +540,37 @@ impl MovieEntry {
 
         let updated = diesel::update(movie_entries::table)
             .filter(movie_entries::movie_id.eq(self.movie_id))
-            .set(self)
+            .set((
+                movie_entries::release_date.eq(self.release_date),
+                movie_entries::poster_path.eq(self.poster_path),
+                movie_entries::status.eq(self.status),
+                movie_entries::updated_at.eq(self.updated_at),
+            ))
+            .returning(MovieEntry::as_returning())
             .get_result(conn)?;
 
         Ok(updated)
     }
 }
+
+impl SortableEntry for MovieEntry {
+    fn title(&self) -> &str {
+        &self.title
+    }
+
+    fn date(&self) -> Option<NaiveDate> {
+        self.release_date
+    }
+
+    fn added_at(&self) -> NaiveDateTime {
+        self.added_at
+    }
+
+    fn status(&self) -> Option<&str> {
+        self.status.as_deref()
+    }
+
+    fn position(&self) -> Option<i32> {
+        self.position
+    }
+}
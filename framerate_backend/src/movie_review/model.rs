@@ -1,20 +1,22 @@
 use chrono::NaiveDate;
 use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::{
-    db::{DbConnection, DEFAULT_PAGE_SIZE},
+    db::DbConnection,
     movie::Movie,
     review::{self, Review, ReviewFindParameters, ReviewOrder, ReviewSort},
-    schema::{movie_reviews, review_company, reviews},
+    schema::{movie_reviews, reviews},
     user,
-    utils::AppError,
+    utils::{response_body::PaginatedResponse, AppError},
 };
 
-#[derive(AsChangeset, Insertable, Associations, Selectable, Queryable)]
+#[derive(Serialize, Deserialize, AsChangeset, Insertable, Associations, Selectable, Queryable)]
 #[diesel(belongs_to(user::User))]
 #[diesel(belongs_to(review::Review))]
 #[diesel(table_name = movie_reviews)]
+#[serde(rename_all = "camelCase")]
 pub struct MovieReview {
     pub review_id: Uuid,
     pub user_id: Uuid,
@@ -35,6 +37,7 @@ pub struct MovieReviewReadResponse {
     pub description: Option<String>,
     pub venue: Option<String>,
     pub movie: Movie,
+    pub snippet: Option<String>,
 }
 
 impl From<MovieReview> for Movie {
@@ -52,6 +55,7 @@ impl From<MovieReview> for Movie {
             runtime: None,
             tagline: None,
             credits: None,
+            franchise: None,
         }
     }
 }
@@ -78,6 +82,7 @@ impl MovieReview {
             description: review_details.description,
             venue: review_details.venue,
             movie: Movie::from(movie_review),
+            snippet: None,
         };
 
         Ok(review)
@@ -87,14 +92,35 @@ impl MovieReview {
         conn: &mut DbConnection,
         user_id: Uuid,
         params: ReviewFindParameters,
-    ) -> Result<Vec<MovieReviewReadResponse>, AppError> {
-        let mut query = movie_reviews::table
-            .filter(movie_reviews::user_id.eq(user_id))
-            .inner_join(reviews::table)
-            .into_boxed();
+    ) -> Result<PaginatedResponse<MovieReviewReadResponse>, AppError> {
+        let search = params.search();
+        let cursor = params.decode_cursor()?;
+        let order_column = params.order_column("movie_reviews.title", "movie_reviews.release_date");
+
+        let filtered_query = || {
+            let mut query = movie_reviews::table
+                .filter(movie_reviews::user_id.eq(user_id))
+                .inner_join(reviews::table)
+                .into_boxed();
+
+            if let Some(search) = &search {
+                query = query.filter(review::search_matches(
+                    "movie_reviews.title",
+                    search.clone(),
+                ));
+            }
+
+            for filter in params.filters(movie_reviews::release_date) {
+                query = query.filter(filter);
+            }
+
+            query
+        };
+
+        let mut query = filtered_query();
 
-        let order_by = params.order_by.unwrap_or(ReviewOrder::Date);
-        let sort = params.sort.unwrap_or(ReviewSort::Desc);
+        let order_by = params.order_by.as_ref().unwrap_or(&ReviewOrder::Date);
+        let sort = params.sort.as_ref().unwrap_or(&ReviewSort::Desc);
         query = match sort {
             ReviewSort::Asc => match order_by {
                 ReviewOrder::Date => query.order(reviews::date.asc().nulls_first()),
@@ -114,60 +140,57 @@ impl MovieReview {
             },
         };
 
-        query = query.then_order_by(reviews::review_id.asc());
-
-        if let Some(venue) = params.at_venue {
-            query = query.filter(reviews::venue.eq(venue));
+        if let (None, Some(search)) = (&params.order_by, &search) {
+            query = query.order(review::search_rank("movie_reviews.title", search.clone()).desc());
         }
 
-        if let Some(rating_max) = params.rating_max {
-            if rating_max < 0 {
-                query = query.filter(reviews::rating.is_null());
-            } else {
-                query = query.filter(reviews::rating.le(rating_max));
-                if let Some(rating_min) = params.rating_min {
-                    query = query.filter(reviews::rating.ge(rating_min));
-                }
-            }
-        } else {
-            if let Some(rating_min) = params.rating_min {
-                query = query.filter(reviews::rating.ge(rating_min));
-            }
-        }
-
-        if let Some(with_company) = params.with_company {
-            query = query.filter(
-                reviews::review_id.eq_any(
-                    review_company::table
-                        .select(review_company::review_id)
-                        .filter(review_company::company_id.eq(with_company)),
-                ),
-            );
-        }
+        query = query.then_order_by(reviews::review_id.asc());
 
-        if let Some(page) = params.page {
-            let page_size = params.page_size.unwrap_or(DEFAULT_PAGE_SIZE);
-            query = query.limit(page_size).offset((page - 1) * page_size);
+        if let Some(cursor) = &cursor {
+            query = query
+                .filter(order_column.after(cursor))
+                .limit(params.page_size() + 1);
+        } else if let Some(page) = params.page {
+            let page_size = params.page_size();
+            query = query.limit(page_size + 1).offset((page - 1) * page_size);
         }
 
         let reviews = query
-            .select((MovieReview::as_select(), Review::as_select()))
-            .load::<(MovieReview, Review)>(conn)?;
+            .select((
+                MovieReview::as_select(),
+                Review::as_select(),
+                review::search_snippet(search.clone()),
+                order_column.cursor_value(),
+            ))
+            .load::<(MovieReview, Review, Option<String>, Option<String>)>(conn)?;
 
-        let movie_reviews: Vec<MovieReviewReadResponse> = reviews
+        let total = if params.is_paginated() {
+            filtered_query().count().get_result::<i64>(conn)?
+        } else {
+            reviews.len() as i64
+        };
+
+        let movie_reviews: Vec<(MovieReviewReadResponse, Option<String>)> = reviews
             .into_iter()
-            .map(|(movie, review)| MovieReviewReadResponse {
-                review_id: movie.review_id,
-                user_id: movie.user_id,
-                date: review.date,
-                description: review.description,
-                rating: review.rating,
-                title: review.title,
-                venue: review.venue,
-                movie: Movie::from(movie),
+            .map(|(movie, review, snippet, cursor_value)| {
+                (
+                    MovieReviewReadResponse {
+                        review_id: movie.review_id,
+                        user_id: movie.user_id,
+                        date: review.date,
+                        description: review.description,
+                        rating: review.rating,
+                        title: review.title,
+                        venue: review.venue,
+                        movie: Movie::from(movie),
+                        snippet,
+                    },
+                    cursor_value,
+                )
             })
             .collect();
-        Ok(movie_reviews)
+
+        Ok(params.paginate(movie_reviews, total, |review| review.review_id))
     }
 
     pub fn find_by_movie_id(
@@ -194,6 +217,7 @@ impl MovieReview {
                 title: review.title,
                 venue: review.venue,
                 movie: Movie::from(movie),
+                snippet: None,
             })
             .collect();
         Ok(movie_reviews)
@@ -213,4 +237,20 @@ impl MovieReview {
             .get_result(conn)?;
         Ok(updated_review)
     }
+
+    pub fn delete(
+        conn: &mut DbConnection,
+        user_id: Uuid,
+        movie_id: i32,
+        review_id: Uuid,
+    ) -> Result<usize, AppError> {
+        let res = diesel::delete(
+            movie_reviews::table
+                .filter(movie_reviews::review_id.eq(review_id))
+                .filter(movie_reviews::user_id.eq(user_id))
+                .filter(movie_reviews::movie_id.eq(movie_id)),
+        )
+        .execute(conn)?;
+        Ok(res)
+    }
 }
@@ -1,4 +1,4 @@
-use crate::collection::Collection;
+use crate::collection::{Collection, CollectionFindParameters, DEFAULT_WATCHLIST};
 use crate::db::DbPool;
 use crate::movie::{Movie, MOVIE_MEDIA_TYPE};
 use crate::movie_entry::MovieEntry;
@@ -7,12 +7,9 @@ use crate::utils::response_body::{DeleteResponse, Success};
 use crate::utils::{jwt::Auth, AppError};
 use actix_web::{delete, Responder};
 use actix_web::{get, post, web};
-use chrono::{NaiveDate, Utc};
+use chrono::{NaiveDate, NaiveDateTime, Utc};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
-use uuid::Uuid;
-
-const DEFAULT_WATCHLIST: &str = "watchlist";
 
 #[derive(Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
@@ -32,6 +29,13 @@ pub struct MovieWatchlistEntry {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status: Option<String>,
     pub updated_at: NaiveDate,
+    pub added_at: NaiveDateTime,
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<i16>,
 }
 
 impl From<MovieEntry> for MovieWatchlistEntry {
@@ -44,6 +48,9 @@ impl From<MovieEntry> for MovieWatchlistEntry {
             release_date: value.release_date,
             status: value.status,
             updated_at: value.updated_at,
+            added_at: value.added_at,
+            note: value.note,
+            priority: value.priority,
         }
     }
 }
@@ -79,29 +86,29 @@ pub struct SaveMovieWatchlistEntryRequest {
     pub movie_id: i32,
 }
 
-#[utoipa::path(tag = "Movie Watchlist", responses((status = OK, body = MovieWatchlist)))]
+#[utoipa::path(tag = "Movie Watchlist", params(CollectionFindParameters), responses((status = OK, body = MovieWatchlist),(status = BAD_REQUEST)))]
 #[get("/movies/watchlist")]
-async fn find(pool: web::Data<DbPool>, auth: Auth) -> actix_web::Result<impl Responder> {
+async fn find(
+    pool: web::Data<DbPool>,
+    auth: Auth,
+    params: web::Query<CollectionFindParameters>,
+) -> actix_web::Result<impl Responder> {
     let watchlist = web::block(move || {
         let mut conn = pool.get()?;
-        let watchlist =
-            Collection::find_default(&mut conn, auth.user_id, MOVIE_MEDIA_TYPE, DEFAULT_WATCHLIST);
-
-        let watchlist = match watchlist {
-            Ok(watchlist) => Ok(watchlist),
-            _ => Collection::create(
-                &mut conn,
-                Collection {
-                    collection_id: Uuid::new_v4(),
-                    media_type: MOVIE_MEDIA_TYPE.to_string(),
-                    user_id: auth.user_id,
-                    name: "Movie Watchlist".to_string(),
-                    default_for: Some(DEFAULT_WATCHLIST.to_string()),
-                },
-            ),
-        }?;
-
-        let entries = MovieEntry::find_all(&mut conn, auth.user_id, watchlist.collection_id)?;
+        let watchlist = Collection::find_or_create_default(
+            &mut conn,
+            auth.user_id,
+            MOVIE_MEDIA_TYPE,
+            DEFAULT_WATCHLIST,
+            "Movie Watchlist",
+        )?;
+
+        let entries = MovieEntry::find_all(
+            &mut conn,
+            auth.user_id,
+            watchlist.collection_id,
+            params.sort.unwrap_or_default(),
+        )?;
 
         Ok::<MovieWatchlist, AppError>(MovieWatchlist::from(watchlist).entries(entries))
     })
@@ -155,6 +162,10 @@ async fn create_entry(
             release_date: movie.release_date,
             status: movie.status,
             updated_at: Utc::now().naive_utc().date(),
+            position: None,
+            added_at: Utc::now().naive_utc(),
+            note: None,
+            priority: None,
         };
 
         MovieEntry::create(&mut conn, movie_entry_to_save)
@@ -1,17 +1,36 @@
-use crate::db::DbConnection;
-use crate::schema::reviews;
+use crate::db::{DbConnection, DEFAULT_PAGE_SIZE};
+use crate::episode_review::EpisodeReview;
+use crate::movie::Movie;
+use crate::movie_review::MovieReview;
+use crate::schema::{
+    company, episode_reviews, movie_reviews, review_company, review_tag, reviews, season_reviews,
+    show_reviews,
+};
+use crate::season::{Episode, Season};
+use crate::season_review::SeasonReview;
+use crate::show::Show;
+use crate::show_review::ShowReview;
 use crate::user;
-use crate::utils::AppError;
-use chrono::{Datelike, Days, NaiveDate, Weekday};
+use crate::utils::{response_body::PaginatedResponse, AppError};
+use chrono::{Datelike, Months, NaiveDate, Utc, Weekday};
+use diesel::dsl::{self, count_star, sql};
+use diesel::expression::{is_aggregate, BoxableExpression, ValidGrouping};
+use diesel::helper_types::{InnerJoinQuerySource, LeftJoinQuerySource};
+use diesel::pg::Pg;
 use diesel::prelude::*;
+use diesel::query_builder::{AstPass, QueryFragment, QueryId};
+use diesel::sql_types::{Bool, Date, Float, Nullable, SingleValue, Text};
 use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
+use std::marker::PhantomData;
 use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 
-#[derive(AsChangeset, Insertable, Associations, Selectable, Queryable)]
+#[derive(Serialize, Deserialize, AsChangeset, Insertable, Associations, Selectable, Queryable)]
 #[diesel(belongs_to(user::User))]
 #[diesel(table_name = reviews)]
 #[diesel(treat_none_as_null = true)]
+#[serde(rename_all = "camelCase")]
 pub struct Review {
     pub review_id: Uuid,
     pub user_id: Uuid,
@@ -39,7 +58,7 @@ pub enum ReviewSort {
     Desc,
 }
 
-#[derive(Deserialize, IntoParams)]
+#[derive(Default, Deserialize, IntoParams)]
 #[serde(rename_all = "camelCase")]
 pub struct ReviewFindParameters {
     #[param(nullable = false)]
@@ -61,14 +80,754 @@ pub struct ReviewFindParameters {
     pub at_venue: Option<String>,
     #[param(nullable = false)]
     pub with_company: Option<Uuid>,
+    #[param(nullable = false)]
+    pub with_tag: Option<Uuid>,
+    /// Only reviews watched on or after this date
+    #[param(nullable = false)]
+    pub date_from: Option<NaiveDate>,
+    /// Only reviews watched on or before this date
+    #[param(nullable = false)]
+    pub date_to: Option<NaiveDate>,
+    /// Only media released on or after this date
+    #[param(nullable = false)]
+    pub release_from: Option<NaiveDate>,
+    /// Only media released on or before this date
+    #[param(nullable = false)]
+    pub release_to: Option<NaiveDate>,
+    /// Full-text search over the review title, description and media title.
+    /// Results are ranked by relevance unless `orderBy` is given
+    #[param(nullable = false)]
+    pub query: Option<String>,
+    /// `nextCursor` from a previous response. Takes the place of `page`, and
+    /// requires the same ordering and filters as the request that returned it
+    #[param(nullable = false)]
+    pub cursor: Option<String>,
+}
+
+/// A condition of a review list query
+pub type ReviewFilter<QS> = Box<dyn BoxableExpression<QS, Pg, SqlType = Nullable<Bool>>>;
+
+/// Reviews linked to a company or tag, through the table linking them
+type ReviewsLinkedTo<Table, ReviewId, Id> = dsl::Nullable<
+    dsl::EqAny<reviews::review_id, dsl::Filter<dsl::Select<Table, ReviewId>, dsl::Eq<Id, Uuid>>>,
+>;
+type ReviewsWithCompany =
+    ReviewsLinkedTo<review_company::table, review_company::review_id, review_company::company_id>;
+type ReviewsWithTag = ReviewsLinkedTo<review_tag::table, review_tag::review_id, review_tag::tag_id>;
+
+impl ReviewFindParameters {
+    /// The venue, rating, company, tag, watch date and release date filters shared by
+    /// the review lists, given the release date of the media reviewed
+    pub fn filters<QS, D>(&self, release_date: D) -> Vec<ReviewFilter<QS>>
+    where
+        D: Expression<SqlType = Nullable<Date>> + Clone + 'static,
+        dsl::Eq<reviews::venue, String>: BoxableExpression<QS, Pg, SqlType = Nullable<Bool>>,
+        dsl::Nullable<dsl::IsNull<reviews::rating>>:
+            BoxableExpression<QS, Pg, SqlType = Nullable<Bool>>,
+        dsl::LtEq<reviews::rating, i16>: BoxableExpression<QS, Pg, SqlType = Nullable<Bool>>,
+        dsl::GtEq<reviews::rating, i16>: BoxableExpression<QS, Pg, SqlType = Nullable<Bool>>,
+        ReviewsWithCompany: BoxableExpression<QS, Pg, SqlType = Nullable<Bool>>,
+        ReviewsWithTag: BoxableExpression<QS, Pg, SqlType = Nullable<Bool>>,
+        dsl::GtEq<reviews::date, NaiveDate>: BoxableExpression<QS, Pg, SqlType = Nullable<Bool>>,
+        dsl::LtEq<reviews::date, NaiveDate>: BoxableExpression<QS, Pg, SqlType = Nullable<Bool>>,
+        dsl::GtEq<D, NaiveDate>: BoxableExpression<QS, Pg, SqlType = Nullable<Bool>>,
+        dsl::LtEq<D, NaiveDate>: BoxableExpression<QS, Pg, SqlType = Nullable<Bool>>,
+    {
+        let mut filters: Vec<ReviewFilter<QS>> = vec![];
+
+        if let Some(venue) = &self.at_venue {
+            filters.push(Box::new(reviews::venue.eq(venue.clone())));
+        }
+
+        if let Some(rating_max) = self.rating_max {
+            if rating_max < 0 {
+                filters.push(Box::new(reviews::rating.is_null().nullable()));
+            } else {
+                filters.push(Box::new(reviews::rating.le(rating_max)));
+                if let Some(rating_min) = self.rating_min {
+                    filters.push(Box::new(reviews::rating.ge(rating_min)));
+                }
+            }
+        } else if let Some(rating_min) = self.rating_min {
+            filters.push(Box::new(reviews::rating.ge(rating_min)));
+        }
+
+        if let Some(with_company) = self.with_company {
+            let with_company: ReviewsWithCompany = reviews::review_id
+                .eq_any(
+                    review_company::table
+                        .select(review_company::review_id)
+                        .filter(review_company::company_id.eq(with_company)),
+                )
+                .nullable();
+            filters.push(Box::new(with_company));
+        }
+
+        if let Some(with_tag) = self.with_tag {
+            let with_tag: ReviewsWithTag = reviews::review_id
+                .eq_any(
+                    review_tag::table
+                        .select(review_tag::review_id)
+                        .filter(review_tag::tag_id.eq(with_tag)),
+                )
+                .nullable();
+            filters.push(Box::new(with_tag));
+        }
+
+        if let Some(date_from) = self.date_from {
+            filters.push(Box::new(reviews::date.ge(date_from)));
+        }
+
+        if let Some(date_to) = self.date_to {
+            filters.push(Box::new(reviews::date.le(date_to)));
+        }
+
+        if let Some(release_from) = self.release_from {
+            filters.push(Box::new(release_date.clone().ge(release_from)));
+        }
+
+        if let Some(release_to) = self.release_to {
+            filters.push(Box::new(release_date.le(release_to)));
+        }
+
+        filters
+    }
+
+    pub fn is_paginated(&self) -> bool {
+        self.page.is_some() || self.cursor.is_some()
+    }
+
+    pub fn page_size(&self) -> i64 {
+        self.page_size.unwrap_or(DEFAULT_PAGE_SIZE)
+    }
+
+    pub fn decode_cursor(&self) -> Result<Option<ReviewCursor>, AppError> {
+        self.cursor.as_deref().map(ReviewCursor::decode).transpose()
+    }
+
+    /// The column results are ordered by. This must agree with the ordering each
+    /// media type applies for `orderBy` and `sort`, including where nulls are placed
+    pub fn order_column(&self, media_title: &str, media_release_date: &str) -> ReviewOrderColumn {
+        let ascending = matches!(self.sort, Some(ReviewSort::Asc));
+
+        if let (None, Some(search)) = (&self.order_by, self.search()) {
+            return ReviewOrderColumn {
+                expression: vec![
+                    SqlPart::Sql(format!(
+                        "ts_rank({}, websearch_to_tsquery('english', ",
+                        search_document(media_title)
+                    )),
+                    SqlPart::Bind(search),
+                    SqlPart::Sql("))".to_string()),
+                ],
+                sql_type: "real",
+                ascending: false,
+                nulls_first: false,
+            };
+        }
+
+        let order_by = self.order_by.as_ref().unwrap_or(&ReviewOrder::Date);
+        let (column, sql_type) = match order_by {
+            ReviewOrder::Date => ("reviews.date", "date"),
+            ReviewOrder::Rating => ("reviews.rating", "int2"),
+            ReviewOrder::MediaTitle => (media_title, "text"),
+            ReviewOrder::MediaReleaseDate => (media_release_date, "date"),
+        };
+
+        // Rating uses the Postgres default of nulls being larger than any value
+        let nulls_first = match order_by {
+            ReviewOrder::Rating => !ascending,
+            _ => ascending,
+        };
+
+        ReviewOrderColumn {
+            expression: vec![SqlPart::Sql(column.to_string())],
+            sql_type,
+            ascending,
+            nulls_first,
+        }
+    }
+
+    /// Builds a page from results loaded with one row more than the page size, which
+    /// signals there is a following page
+    pub fn paginate<T>(
+        &self,
+        mut rows: Vec<(T, Option<String>)>,
+        total: i64,
+        review_id: impl Fn(&T) -> Uuid,
+    ) -> PaginatedResponse<T> {
+        if !self.is_paginated() {
+            return PaginatedResponse {
+                total,
+                items: rows.into_iter().map(|(item, _)| item).collect(),
+                page: None,
+                page_size: None,
+                next_cursor: None,
+            };
+        }
+
+        let page_size = self.page_size();
+        let has_next_page = rows.len() as i64 > page_size;
+        rows.truncate(page_size.max(0) as usize);
+
+        let next_cursor = match rows.last() {
+            Some((item, value)) if has_next_page => Some(
+                ReviewCursor {
+                    value: value.clone(),
+                    review_id: review_id(item),
+                }
+                .encode(),
+            ),
+            _ => None,
+        };
+
+        PaginatedResponse {
+            items: rows.into_iter().map(|(item, _)| item).collect(),
+            total,
+            page: self.cursor.is_none().then_some(self.page).flatten(),
+            page_size: Some(page_size),
+            next_cursor,
+        }
+    }
+
+    /// The search query, ignoring blank input
+    pub fn search(&self) -> Option<String> {
+        self.query
+            .as_ref()
+            .map(|query| query.trim().to_string())
+            .filter(|query| !query.is_empty())
+    }
+}
+
+/// Position of the last review on a page, as the text of its order column and its id
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReviewCursor {
+    pub value: Option<String>,
+    pub review_id: Uuid,
+}
+
+impl ReviewCursor {
+    pub fn encode(&self) -> String {
+        serde_json::to_vec(self)
+            .unwrap_or_default()
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect()
+    }
+
+    pub fn decode(cursor: &str) -> Result<Self, AppError> {
+        let invalid = || AppError::external(400, "Invalid cursor");
+
+        if !cursor.len().is_multiple_of(2) || !cursor.is_ascii() {
+            return Err(invalid());
+        }
+
+        let bytes = (0..cursor.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&cursor[i..i + 2], 16))
+            .collect::<Result<Vec<u8>, _>>()
+            .map_err(|_| invalid())?;
+
+        serde_json::from_slice(&bytes).map_err(|_| invalid())
+    }
+}
+
+#[derive(Debug, Clone)]
+enum SqlPart {
+    Sql(String),
+    Bind(String),
+}
+
+/// SQL built at runtime, with text parameters bound in place
+#[derive(Debug, Clone)]
+pub struct DynamicSql<ST> {
+    parts: Vec<SqlPart>,
+    sql_type: PhantomData<ST>,
+}
+
+impl<ST> DynamicSql<ST> {
+    fn new(parts: Vec<SqlPart>) -> Self {
+        DynamicSql {
+            parts,
+            sql_type: PhantomData,
+        }
+    }
+}
+
+impl<ST: diesel::sql_types::SqlType + diesel::expression::TypedExpressionType> Expression
+    for DynamicSql<ST>
+{
+    type SqlType = ST;
+}
+
+impl<ST> QueryFragment<Pg> for DynamicSql<ST> {
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, Pg>) -> QueryResult<()> {
+        out.unsafe_to_cache_prepared();
+        for part in &self.parts {
+            match part {
+                SqlPart::Sql(sql) => out.push_sql(sql),
+                SqlPart::Bind(value) => out.push_bind_param::<Text, String>(value)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<ST> QueryId for DynamicSql<ST> {
+    type QueryId = ();
+
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+/// The tables review lists are read from, which the SQL of `DynamicSql` refers to
+pub trait ReviewListSource {}
+
+impl ReviewListSource for InnerJoinQuerySource<movie_reviews::table, reviews::table> {}
+impl ReviewListSource for InnerJoinQuerySource<show_reviews::table, reviews::table> {}
+impl ReviewListSource for InnerJoinQuerySource<season_reviews::table, reviews::table> {}
+impl ReviewListSource for InnerJoinQuerySource<episode_reviews::table, reviews::table> {}
+impl ReviewListSource
+    for LeftJoinQuerySource<
+        LeftJoinQuerySource<
+            LeftJoinQuerySource<
+                LeftJoinQuerySource<reviews::table, movie_reviews::table>,
+                show_reviews::table,
+            >,
+            season_reviews::table,
+        >,
+        episode_reviews::table,
+    >
+{
+}
+
+impl<ST, QS: ReviewListSource> AppearsOnTable<QS> for DynamicSql<ST> where Self: Expression {}
+
+impl<ST, QS: ReviewListSource> SelectableExpression<QS> for DynamicSql<ST> where Self: Expression {}
+
+impl<ST, GB> ValidGrouping<GB> for DynamicSql<ST> {
+    type IsAggregate = is_aggregate::Never;
+}
+
+pub struct ReviewOrderColumn {
+    expression: Vec<SqlPart>,
+    sql_type: &'static str,
+    ascending: bool,
+    nulls_first: bool,
+}
+
+impl ReviewOrderColumn {
+    /// The order column as text, for building the next cursor
+    pub fn cursor_value(&self) -> DynamicSql<Nullable<Text>> {
+        let mut parts = vec![SqlPart::Sql("CAST(".to_string())];
+        parts.extend(self.expression.iter().cloned());
+        parts.push(SqlPart::Sql(" AS text)".to_string()));
+        DynamicSql::new(parts)
+    }
+
+    /// Reviews ordered after the cursor, with `review_id` as the tie-breaker
+    pub fn after(&self, cursor: &ReviewCursor) -> DynamicSql<Bool> {
+        let expression = || self.expression.iter().cloned();
+        let sql = |sql: &str| SqlPart::Sql(sql.to_string());
+        let review_id = [
+            sql("reviews.review_id > CAST("),
+            SqlPart::Bind(cursor.review_id.to_string()),
+            sql(" AS uuid)"),
+        ];
+
+        let mut parts = vec![sql("(")];
+        match &cursor.value {
+            Some(value) => {
+                let value = [
+                    sql("CAST("),
+                    SqlPart::Bind(value.clone()),
+                    sql(&format!(" AS {})", self.sql_type)),
+                ];
+                parts.extend(expression());
+                parts.push(sql(if self.ascending { " > " } else { " < " }));
+                parts.extend(value.iter().cloned());
+                parts.push(sql(" OR ("));
+                parts.extend(expression());
+                parts.push(sql(" = "));
+                parts.extend(value.iter().cloned());
+                parts.push(sql(" AND "));
+                parts.extend(review_id.iter().cloned());
+                parts.push(sql(")"));
+                if !self.nulls_first {
+                    parts.push(sql(" OR "));
+                    parts.extend(expression());
+                    parts.push(sql(" IS NULL"));
+                }
+            }
+            None => {
+                parts.push(sql("("));
+                parts.extend(expression());
+                parts.push(sql(" IS NULL AND "));
+                parts.extend(review_id.iter().cloned());
+                parts.push(sql(")"));
+                if self.nulls_first {
+                    parts.push(sql(" OR "));
+                    parts.extend(expression());
+                    parts.push(sql(" IS NOT NULL"));
+                }
+            }
+        }
+        parts.push(sql(")"));
+
+        DynamicSql::new(parts)
+    }
+}
+
+type SearchExpression<QS, ST> = Box<dyn BoxableExpression<QS, Pg, SqlType = ST>>;
+
+/// Review text combined with the denormalised media title, given as a SQL expression
+fn search_document(media_title: &str) -> String {
+    format!(
+        "(reviews.search_vector || setweight(to_tsvector('english', coalesce({media_title}, '')), 'A'))"
+    )
+}
+
+/// Reviews matching `search`, using web search syntax (quoted phrases, `or`, `-exclusions`)
+pub fn search_matches<QS>(media_title: &str, search: String) -> SearchExpression<QS, Bool> {
+    Box::new(
+        sql::<Bool>(&format!(
+            "{} @@ websearch_to_tsquery('english', ",
+            search_document(media_title)
+        ))
+        .bind::<Text, _>(search)
+        .sql(")"),
+    )
+}
+
+pub fn search_rank<QS>(media_title: &str, search: String) -> SearchExpression<QS, Float> {
+    Box::new(
+        sql::<Float>(&format!(
+            "ts_rank({}, websearch_to_tsquery('english', ",
+            search_document(media_title)
+        ))
+        .bind::<Text, _>(search)
+        .sql("))"),
+    )
+}
+
+/// Review text with the matching terms highlighted, or null without a search
+pub fn search_snippet<QS>(search: Option<String>) -> SearchExpression<QS, Nullable<Text>> {
+    match search {
+        Some(search) => Box::new(
+            sql::<Nullable<Text>>(
+                "ts_headline('english', concat_ws(' ', reviews.title, reviews.description), websearch_to_tsquery('english', ",
+            )
+            .bind::<Text, _>(search)
+            .sql("), 'StartSel=<mark>, StopSel=</mark>, MaxFragments=2')"),
+        ),
+        None => Box::new(sql::<Nullable<Text>>("NULL")),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum ReviewMediaType {
+    Movie,
+    Show,
+    Season,
+    Episode,
+}
+
+pub struct ReviewReadResponse {
+    pub review_id: Uuid,
+    pub user_id: Uuid,
+    pub date: Option<NaiveDate>,
+    pub rating: Option<i16>,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub venue: Option<String>,
+    pub media_type: ReviewMediaType,
+    pub movie: Option<Movie>,
+    pub show: Option<Show>,
+    pub season: Option<Season>,
+    pub episode: Option<Episode>,
+    pub snippet: Option<String>,
 }
 
-#[derive(Serialize)]
+type MediaReviews = (
+    Option<MovieReview>,
+    Option<ShowReview>,
+    Option<SeasonReview>,
+    Option<EpisodeReview>,
+);
+
+impl ReviewReadResponse {
+    fn new(review: Review, media: MediaReviews) -> Option<Self> {
+        let mut response = ReviewReadResponse {
+            review_id: review.review_id,
+            user_id: review.user_id,
+            date: review.date,
+            rating: review.rating,
+            title: review.title,
+            description: review.description,
+            venue: review.venue,
+            media_type: ReviewMediaType::Movie,
+            movie: None,
+            show: None,
+            season: None,
+            episode: None,
+            snippet: None,
+        };
+
+        match media {
+            (Some(movie_review), _, _, _) => {
+                response.movie = Some(Movie::from(movie_review));
+            }
+            (_, Some(show_review), _, _) => {
+                response.media_type = ReviewMediaType::Show;
+                response.show = Some(Show::from(show_review));
+            }
+            (_, _, Some(season_review), _) => {
+                response.media_type = ReviewMediaType::Season;
+                response.season = Some(Season::from(season_review));
+            }
+            (_, _, _, Some(episode_review)) => {
+                response.media_type = ReviewMediaType::Episode;
+                response.season = Some(Season {
+                    show_id: episode_review.show_id,
+                    season_number: episode_review.season_number,
+                    name: None,
+                    poster_path: None,
+                    overview: None,
+                    air_date: None,
+                    episode_count: None,
+                    episodes: None,
+                });
+                response.episode = Some(Episode::from(episode_review));
+            }
+            _ => return None,
+        }
+
+        Some(response)
+    }
+}
+
+const MEDIA_TITLE_SQL: &str =
+    "coalesce(movie_reviews.title, show_reviews.name, season_reviews.name, episode_reviews.name)";
+const MEDIA_RELEASE_DATE_SQL: &str = "coalesce(movie_reviews.release_date, show_reviews.first_air_date, season_reviews.air_date, episode_reviews.air_date)";
+
+define_sql_function!(fn coalesce<T: SingleValue>(x: Nullable<T>, y: Nullable<T>) -> Nullable<T>);
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum StatisticsPeriod {
+    Week,
+    Month,
+    Year,
+    Custom,
+}
+
+#[derive(Deserialize, IntoParams)]
+#[serde(rename_all = "camelCase")]
+pub struct ReviewStatisticsParameters {
+    #[param(nullable = false)]
+    #[param(value_type=Option<String>)]
+    pub period: Option<StatisticsPeriod>,
+    /// Date within the week, month or year to report on. Defaults to today
+    #[param(nullable = false)]
+    pub date: Option<NaiveDate>,
+    /// Start of a custom period (inclusive)
+    #[param(nullable = false)]
+    pub from: Option<NaiveDate>,
+    /// End of a custom period (inclusive)
+    #[param(nullable = false)]
+    pub to: Option<NaiveDate>,
+    #[param(nullable = false)]
+    #[param(value_type=Option<String>)]
+    pub media_type: Option<ReviewMediaType>,
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RatingCount {
+    pub rating: i16,
+    pub count: i64,
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ReviewStatistics {
-    pub reviews_this_week: i64,
-    pub reviews_this_month: i64,
-    pub reviews_this_year: i64,
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    pub count: i64,
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub average_rating: Option<f64>,
+    pub rating_histogram: Vec<RatingCount>,
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MediaTypeCount {
+    pub media_type: ReviewMediaType,
+    pub count: i64,
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MonthCount {
+    /// Calendar month, from 1 for January
+    pub month: u32,
+    pub count: i64,
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct VenueCount {
+    pub venue: String,
+    pub count: i64,
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CompanionCount {
+    pub company_id: Uuid,
+    pub first_name: String,
+    pub last_name: String,
+    pub count: i64,
+}
+
+/// A reviewed movie, show, season or episode, identified by its TMDB show or movie id
+#[derive(Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ReviewedTitle {
+    pub review_id: Uuid,
+    pub media_type: ReviewMediaType,
+    pub media_id: i32,
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub season_number: Option<i32>,
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub episode_number: Option<i32>,
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date: Option<NaiveDate>,
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rating: Option<i16>,
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct YearInReview {
+    pub year: i32,
+    pub count: i64,
+    pub media_types: Vec<MediaTypeCount>,
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub average_rating: Option<f64>,
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub highest_rated: Option<ReviewedTitle>,
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lowest_rated: Option<ReviewedTitle>,
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub busiest_month: Option<MonthCount>,
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_venue: Option<VenueCount>,
+    /// Most frequent company, most reviews first
+    pub top_companions: Vec<CompanionCount>,
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_watch: Option<ReviewedTitle>,
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_watch: Option<ReviewedTitle>,
+}
+
+const YEAR_IN_REVIEW_COMPANIONS: i64 = 5;
+
+impl From<&ReviewReadResponse> for ReviewedTitle {
+    fn from(review: &ReviewReadResponse) -> Self {
+        let (media_id, title) = match (&review.movie, &review.show, &review.season) {
+            (Some(movie), _, _) => (movie.id, Some(movie.title.clone())),
+            (_, Some(show), _) => (show.id, Some(show.name.clone())),
+            (_, _, Some(season)) => match &review.episode {
+                Some(episode) => (season.show_id, episode.name.clone()),
+                None => (season.show_id, season.name.clone()),
+            },
+            _ => (0, None),
+        };
+
+        ReviewedTitle {
+            review_id: review.review_id,
+            media_type: review.media_type,
+            media_id,
+            season_number: review.season.as_ref().map(|season| season.season_number),
+            episode_number: review
+                .episode
+                .as_ref()
+                .map(|episode| episode.episode_number),
+            title,
+            date: review.date,
+            rating: review.rating,
+        }
+    }
+}
+
+impl StatisticsPeriod {
+    /// Inclusive first and last day of the calendar period containing `date`
+    pub fn bounds(&self, date: NaiveDate) -> Option<(NaiveDate, NaiveDate)> {
+        match self {
+            StatisticsPeriod::Week => {
+                let week = date.week(Weekday::Mon);
+                Some((week.first_day(), week.last_day()))
+            }
+            StatisticsPeriod::Month => {
+                let start = date.with_day(1)?;
+                let end = start.checked_add_months(Months::new(1))?.pred_opt()?;
+                Some((start, end))
+            }
+            StatisticsPeriod::Year => {
+                let start = NaiveDate::from_ymd_opt(date.year(), 1, 1)?;
+                let end = NaiveDate::from_ymd_opt(date.year(), 12, 31)?;
+                Some((start, end))
+            }
+            StatisticsPeriod::Custom => None,
+        }
+    }
+}
+
+impl ReviewStatisticsParameters {
+    pub fn bounds(&self) -> Result<(NaiveDate, NaiveDate), AppError> {
+        let period = self.period.as_ref().unwrap_or(&StatisticsPeriod::Month);
+
+        let (from, to) = match period {
+            StatisticsPeriod::Custom => {
+                let (Some(from), Some(to)) = (self.from, self.to) else {
+                    return Err(AppError::external(
+                        400,
+                        "Custom period requires a from and to date",
+                    ));
+                };
+                (from, to)
+            }
+            period => period
+                .bounds(self.date.unwrap_or(Utc::now().date_naive()))
+                .ok_or(AppError::external(400, "Invalid statistics date"))?,
+        };
+
+        if from > to {
+            return Err(AppError::external(
+                400,
+                "Period start must not be after end",
+            ));
+        }
+
+        Ok((from, to))
+    }
 }
 
 impl Review {
@@ -84,6 +843,7 @@ impl Review {
     pub fn create(conn: &mut DbConnection, review: Review) -> Result<Self, AppError> {
         let new_review = diesel::insert_into(reviews::table)
             .values(review)
+            .returning(Review::as_returning())
             .get_result(conn)?;
         Ok(new_review)
     }
@@ -92,60 +852,366 @@ impl Review {
         let updated_review = diesel::update(reviews::table)
             .filter(reviews::review_id.eq(review.review_id))
             .set(review)
+            .returning(Review::as_returning())
             .get_result(conn)?;
         Ok(updated_review)
     }
 
-    pub fn delete(conn: &mut DbConnection, review_id: Uuid) -> Result<usize, AppError> {
-        let res = diesel::delete(reviews::table.filter(reviews::review_id.eq(review_id)))
-            .execute(conn)?;
+    pub fn delete(
+        conn: &mut DbConnection,
+        user_id: Uuid,
+        review_id: Uuid,
+    ) -> Result<usize, AppError> {
+        let res = diesel::delete(
+            reviews::table
+                .filter(reviews::review_id.eq(review_id))
+                .filter(reviews::user_id.eq(user_id)),
+        )
+        .execute(conn)?;
         Ok(res)
     }
 
+    /// Reviews of every media type, merged into a single timeline
+    pub fn find_all_reviews(
+        conn: &mut DbConnection,
+        user_id: Uuid,
+        params: ReviewFindParameters,
+    ) -> Result<PaginatedResponse<ReviewReadResponse>, AppError> {
+        let search = params.search();
+        let cursor = params.decode_cursor()?;
+        let order_column = params.order_column(MEDIA_TITLE_SQL, MEDIA_RELEASE_DATE_SQL);
+
+        let media_title = coalesce(
+            movie_reviews::title.nullable(),
+            coalesce(
+                show_reviews::name.nullable(),
+                coalesce(season_reviews::name, episode_reviews::name),
+            ),
+        );
+        let media_release_date = coalesce(
+            movie_reviews::release_date.nullable(),
+            coalesce(
+                show_reviews::first_air_date.nullable(),
+                coalesce(
+                    season_reviews::air_date.nullable(),
+                    episode_reviews::air_date.nullable(),
+                ),
+            ),
+        );
+
+        let filtered_query = || {
+            let mut query = reviews::table
+                .filter(reviews::user_id.eq(user_id))
+                .left_join(movie_reviews::table)
+                .left_join(show_reviews::table)
+                .left_join(season_reviews::table)
+                .left_join(episode_reviews::table)
+                .filter(
+                    movie_reviews::review_id
+                        .nullable()
+                        .is_not_null()
+                        .or(show_reviews::review_id.nullable().is_not_null())
+                        .or(season_reviews::review_id.nullable().is_not_null())
+                        .or(episode_reviews::review_id.nullable().is_not_null()),
+                )
+                .into_boxed();
+
+            if let Some(search) = &search {
+                query = query.filter(search_matches(MEDIA_TITLE_SQL, search.clone()));
+            }
+
+            for filter in params.filters(media_release_date) {
+                query = query.filter(filter);
+            }
+
+            query
+        };
+
+        let mut query = filtered_query();
+
+        let order_by = params.order_by.as_ref().unwrap_or(&ReviewOrder::Date);
+        let sort = params.sort.as_ref().unwrap_or(&ReviewSort::Desc);
+        query = match sort {
+            ReviewSort::Asc => match order_by {
+                ReviewOrder::Date => query.order(reviews::date.asc().nulls_first()),
+                ReviewOrder::MediaReleaseDate => {
+                    query.order(media_release_date.asc().nulls_first())
+                }
+                ReviewOrder::Rating => query.order(reviews::rating.asc()),
+                ReviewOrder::MediaTitle => query.order(media_title.asc().nulls_first()),
+            },
+            ReviewSort::Desc => match order_by {
+                ReviewOrder::Date => query.order(reviews::date.desc().nulls_last()),
+                ReviewOrder::MediaReleaseDate => {
+                    query.order(media_release_date.desc().nulls_last())
+                }
+                ReviewOrder::Rating => query.order(reviews::rating.desc()),
+                ReviewOrder::MediaTitle => query.order(media_title.desc().nulls_last()),
+            },
+        };
+
+        if let (None, Some(search)) = (&params.order_by, &search) {
+            query = query.order(search_rank(MEDIA_TITLE_SQL, search.clone()).desc());
+        }
+
+        query = query.then_order_by(reviews::review_id.asc());
+
+        if let Some(cursor) = &cursor {
+            query = query
+                .filter(order_column.after(cursor))
+                .limit(params.page_size() + 1);
+        } else if let Some(page) = params.page {
+            let page_size = params.page_size();
+            query = query.limit(page_size + 1).offset((page - 1) * page_size);
+        }
+
+        let reviews = query
+            .select((
+                Review::as_select(),
+                Option::<MovieReview>::as_select(),
+                Option::<ShowReview>::as_select(),
+                Option::<SeasonReview>::as_select(),
+                Option::<EpisodeReview>::as_select(),
+                search_snippet(search.clone()),
+                order_column.cursor_value(),
+            ))
+            .load::<(
+                Review,
+                Option<MovieReview>,
+                Option<ShowReview>,
+                Option<SeasonReview>,
+                Option<EpisodeReview>,
+                Option<String>,
+                Option<String>,
+            )>(conn)?;
+
+        let total = if params.is_paginated() {
+            filtered_query().count().get_result::<i64>(conn)?
+        } else {
+            reviews.len() as i64
+        };
+
+        let reviews: Vec<(ReviewReadResponse, Option<String>)> = reviews
+            .into_iter()
+            .filter_map(
+                |(review, movie, show, season, episode, snippet, cursor_value)| {
+                    ReviewReadResponse::new(review, (movie, show, season, episode))
+                        .map(|review| (ReviewReadResponse { snippet, ..review }, cursor_value))
+                },
+            )
+            .collect();
+
+        Ok(params.paginate(reviews, total, |review| review.review_id))
+    }
+
     pub fn find_statistics(
         conn: &mut DbConnection,
         user_id: Uuid,
+        params: ReviewStatisticsParameters,
     ) -> Result<ReviewStatistics, AppError> {
-        let current_year = chrono::offset::Local::now().year();
-        let current_year_start = NaiveDate::from_ymd_opt(current_year, 1, 1).unwrap();
-        let current_year_end = NaiveDate::from_ymd_opt(current_year + 1, 1, 1)
-            .unwrap()
-            .checked_sub_days(Days::new(1));
+        let (from, to) = params.bounds()?;
 
-        let reviews_this_year: i64 = reviews::table
+        let mut query = reviews::table
             .filter(reviews::user_id.eq(user_id))
-            .filter(reviews::date.between(current_year_start, current_year_end))
-            .count()
-            .get_result(conn)?;
+            .filter(reviews::date.between(from, to))
+            .group_by(reviews::rating)
+            .select((reviews::rating, count_star()))
+            .into_boxed();
 
-        let current_month = chrono::Utc::now().month();
-        let current_month_start = NaiveDate::from_ymd_opt(current_year, current_month, 1).unwrap();
-        let current_month_end = NaiveDate::from_ymd_opt(current_year, current_month + 1, 1)
-            .unwrap()
-            .checked_sub_days(Days::new(1));
+        query = match params.media_type {
+            Some(ReviewMediaType::Movie) => query.filter(
+                reviews::review_id.eq_any(movie_reviews::table.select(movie_reviews::review_id)),
+            ),
+            Some(ReviewMediaType::Show) => query.filter(
+                reviews::review_id.eq_any(show_reviews::table.select(show_reviews::review_id)),
+            ),
+            Some(ReviewMediaType::Season) => query.filter(
+                reviews::review_id.eq_any(season_reviews::table.select(season_reviews::review_id)),
+            ),
+            Some(ReviewMediaType::Episode) => query.filter(
+                reviews::review_id
+                    .eq_any(episode_reviews::table.select(episode_reviews::review_id)),
+            ),
+            None => query,
+        };
 
-        let reviews_this_month: i64 = reviews::table
-            .filter(reviews::user_id.eq(user_id))
-            .filter(reviews::date.between(current_month_start, current_month_end))
-            .count()
-            .get_result(conn)?;
+        let rating_counts = query.load::<(Option<i16>, i64)>(conn)?;
 
-        let current_week = chrono::Utc::now().iso_week().week();
-        let current_week_start =
-            NaiveDate::from_isoywd_opt(current_year, current_week, Weekday::Mon).unwrap();
-        let current_week_end =
-            NaiveDate::from_isoywd_opt(current_year, current_week + 1, Weekday::Mon).unwrap();
+        let count = rating_counts.iter().map(|(_, count)| count).sum();
 
-        let reviews_this_week: i64 = reviews::table
-            .filter(reviews::user_id.eq(user_id))
-            .filter(reviews::date.between(current_week_start, current_week_end))
-            .count()
-            .get_result(conn)?;
+        let mut rating_histogram: Vec<RatingCount> = rating_counts
+            .into_iter()
+            .filter_map(|(rating, count)| rating.map(|rating| RatingCount { rating, count }))
+            .collect();
+
+        rating_histogram.sort_by_key(|rating_count| rating_count.rating);
+
+        let rated_count: i64 = rating_histogram.iter().map(|bucket| bucket.count).sum();
+        let average_rating = if rated_count > 0 {
+            let rating_total: i64 = rating_histogram
+                .iter()
+                .map(|bucket| i64::from(bucket.rating) * bucket.count)
+                .sum();
+            Some(rating_total as f64 / rated_count as f64)
+        } else {
+            None
+        };
 
         Ok(ReviewStatistics {
-            reviews_this_week,
-            reviews_this_month,
-            reviews_this_year,
+            from,
+            to,
+            count,
+            average_rating,
+            rating_histogram,
+        })
+    }
+
+    pub fn find_year_in_review(
+        conn: &mut DbConnection,
+        user_id: Uuid,
+        year: i32,
+    ) -> Result<YearInReview, AppError> {
+        let (Some(from), Some(to)) = (
+            NaiveDate::from_ymd_opt(year, 1, 1),
+            NaiveDate::from_ymd_opt(year, 12, 31),
+        ) else {
+            return Err(AppError::external(400, "Invalid year"));
+        };
+
+        let reviews = reviews::table
+            .filter(reviews::user_id.eq(user_id))
+            .filter(reviews::date.between(from, to))
+            .left_join(movie_reviews::table)
+            .left_join(show_reviews::table)
+            .left_join(season_reviews::table)
+            .left_join(episode_reviews::table)
+            .order((reviews::date.asc(), reviews::review_id.asc()))
+            .select((
+                Review::as_select(),
+                Option::<MovieReview>::as_select(),
+                Option::<ShowReview>::as_select(),
+                Option::<SeasonReview>::as_select(),
+                Option::<EpisodeReview>::as_select(),
+            ))
+            .load::<(
+                Review,
+                Option<MovieReview>,
+                Option<ShowReview>,
+                Option<SeasonReview>,
+                Option<EpisodeReview>,
+            )>(conn)?;
+
+        let reviews: Vec<ReviewReadResponse> = reviews
+            .into_iter()
+            .filter_map(|(review, movie, show, season, episode)| {
+                ReviewReadResponse::new(review, (movie, show, season, episode))
+            })
+            .collect();
+
+        let media_types = [
+            ReviewMediaType::Movie,
+            ReviewMediaType::Show,
+            ReviewMediaType::Season,
+            ReviewMediaType::Episode,
+        ]
+        .into_iter()
+        .map(|media_type| MediaTypeCount {
+            media_type,
+            count: reviews
+                .iter()
+                .filter(|review| review.media_type == media_type)
+                .count() as i64,
+        })
+        .collect();
+
+        let ratings: Vec<i64> = reviews
+            .iter()
+            .filter_map(|review| review.rating.map(i64::from))
+            .collect();
+        let average_rating = if ratings.is_empty() {
+            None
+        } else {
+            Some(ratings.iter().sum::<i64>() as f64 / ratings.len() as f64)
+        };
+
+        // Reviews are in watch order, and `min_by_key` keeps the first of equal
+        // elements, so ties go to the earliest watch
+        let rated = reviews.iter().filter(|review| review.rating.is_some());
+        let highest_rated = rated
+            .clone()
+            .min_by_key(|review| Reverse(review.rating))
+            .map(ReviewedTitle::from);
+        let lowest_rated = rated
+            .min_by_key(|review| review.rating)
+            .map(ReviewedTitle::from);
+
+        let mut month_counts = [0i64; 12];
+        for review in &reviews {
+            if let Some(date) = review.date {
+                month_counts[date.month0() as usize] += 1;
+            }
+        }
+        let busiest_month = (1..=12u32)
+            .zip(month_counts)
+            .filter(|(_, count)| *count > 0)
+            .min_by_key(|(_, count)| Reverse(*count))
+            .map(|(month, count)| MonthCount { month, count });
+
+        let mut venue_counts: Vec<VenueCount> = vec![];
+        for venue in reviews.iter().filter_map(|review| review.venue.as_ref()) {
+            match venue_counts.iter_mut().find(|count| &count.venue == venue) {
+                Some(venue_count) => venue_count.count += 1,
+                None => venue_counts.push(VenueCount {
+                    venue: venue.clone(),
+                    count: 1,
+                }),
+            }
+        }
+        let top_venue = venue_counts
+            .into_iter()
+            .min_by_key(|venue_count| Reverse(venue_count.count));
+
+        let review_ids: Vec<Uuid> = reviews.iter().map(|review| review.review_id).collect();
+        let top_companions = review_company::table
+            .inner_join(company::table)
+            .filter(review_company::review_id.eq_any(review_ids))
+            .group_by((company::company_id, company::first_name, company::last_name))
+            .select((
+                company::company_id,
+                company::first_name,
+                company::last_name,
+                count_star(),
+            ))
+            .order((
+                count_star().desc(),
+                company::first_name.asc(),
+                company::last_name.asc(),
+            ))
+            .limit(YEAR_IN_REVIEW_COMPANIONS)
+            .load::<(Uuid, String, String, i64)>(conn)?
+            .into_iter()
+            .map(
+                |(company_id, first_name, last_name, count)| CompanionCount {
+                    company_id,
+                    first_name,
+                    last_name,
+                    count,
+                },
+            )
+            .collect();
+
+        Ok(YearInReview {
+            year,
+            count: reviews.len() as i64,
+            media_types,
+            average_rating,
+            highest_rated,
+            lowest_rated,
+            busiest_month,
+            top_venue,
+            top_companions,
+            first_watch: reviews.first().map(ReviewedTitle::from),
+            last_watch: reviews.last().map(ReviewedTitle::from),
         })
     }
 }
@@ -0,0 +1,128 @@
+use crate::db::DbPool;
+use crate::movie::Movie;
+use crate::review::{
+    Review, ReviewFindParameters, ReviewMediaType, ReviewReadResponse, ReviewStatistics,
+    ReviewStatisticsParameters, YearInReview,
+};
+use crate::season::{Episode, Season};
+use crate::show::Show;
+use crate::utils::{
+    jwt::Auth,
+    response_body::{ListResponse, Success},
+};
+use actix_web::{get, web, Responder};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ReviewResponse {
+    pub review_id: Uuid,
+    pub user_id: Uuid,
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date: Option<NaiveDate>,
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rating: Option<i16>,
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub venue: Option<String>,
+    pub media_type: ReviewMediaType,
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub movie: Option<Movie>,
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show: Option<Show>,
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub season: Option<Season>,
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub episode: Option<Episode>,
+    /// Matching review text with search terms highlighted, when searching
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snippet: Option<String>,
+}
+
+impl From<ReviewReadResponse> for ReviewResponse {
+    fn from(review: ReviewReadResponse) -> Self {
+        ReviewResponse {
+            review_id: review.review_id,
+            user_id: review.user_id,
+            date: review.date,
+            rating: review.rating,
+            title: review.title,
+            description: review.description,
+            venue: review.venue,
+            media_type: review.media_type,
+            movie: review.movie,
+            show: review.show,
+            season: review.season,
+            episode: review.episode,
+            snippet: review.snippet,
+        }
+    }
+}
+
+#[utoipa::path(tag = "Review", params(ReviewFindParameters), responses((status = OK, body = ListResponse<ReviewResponse>),(status = BAD_REQUEST)))]
+#[get("/reviews")]
+async fn find_all(
+    pool: web::Data<DbPool>,
+    auth: Auth,
+    params: web::Query<ReviewFindParameters>,
+) -> actix_web::Result<impl Responder> {
+    let paginated = params.is_paginated();
+    let reviews = web::block(move || {
+        let mut conn = pool.get()?;
+        Review::find_all_reviews(&mut conn, auth.user_id, params.into_inner())
+    })
+    .await??;
+
+    Ok(Success::new(ListResponse::new(
+        reviews.map(ReviewResponse::from),
+        paginated,
+    )))
+}
+
+#[utoipa::path(tag = "Review", params(ReviewStatisticsParameters), responses((status = OK, body = ReviewStatistics),(status = BAD_REQUEST)))]
+#[get("/reviews/statistics")]
+async fn find_statistics(
+    pool: web::Data<DbPool>,
+    auth: Auth,
+    params: web::Query<ReviewStatisticsParameters>,
+) -> actix_web::Result<impl Responder> {
+    let statistics = web::block(move || {
+        let mut conn = pool.get()?;
+        Review::find_statistics(&mut conn, auth.user_id, params.into_inner())
+    })
+    .await??;
+
+    Ok(Success::new(statistics))
+}
+
+#[utoipa::path(tag = "Review", responses((status = OK, body = YearInReview),(status = BAD_REQUEST)))]
+#[get("/reviews/year/{year}")]
+async fn find_year_in_review(
+    pool: web::Data<DbPool>,
+    auth: Auth,
+    year: web::Path<i32>,
+) -> actix_web::Result<impl Responder> {
+    let report = web::block(move || {
+        let mut conn = pool.get()?;
+        Review::find_year_in_review(&mut conn, auth.user_id, year.into_inner())
+    })
+    .await??;
+
+    Ok(Success::new(report))
+}
@@ -94,4 +94,11 @@ impl ReviewCompany {
         let review_company_details = Self::find_by_review(conn, review_id)?;
         Ok(review_company_details)
     }
+
+    pub fn delete_by_review(conn: &mut DbConnection, review_id: Uuid) -> Result<usize, AppError> {
+        let res =
+            diesel::delete(review_company::table.filter(review_company::review_id.eq(review_id)))
+                .execute(conn)?;
+        Ok(res)
+    }
 }
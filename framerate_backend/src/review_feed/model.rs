@@ -0,0 +1,157 @@
+use crate::db::DbConnection;
+use crate::feed_token::FeedToken;
+use crate::review::{
+    Review, ReviewFindParameters, ReviewMediaType, ReviewReadResponse, ReviewedTitle,
+};
+use crate::schema::users;
+use crate::utils::AppError;
+use chrono::{NaiveDate, NaiveDateTime};
+use diesel::prelude::*;
+use uuid::Uuid;
+
+/// Number of most recent reviews listed in a feed
+const REVIEW_FEED_LENGTH: i64 = 20;
+
+pub struct ReviewFeed {
+    pub user_id: Uuid,
+    pub author: String,
+    pub reviews: Vec<ReviewReadResponse>,
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+fn format_timestamp(timestamp: NaiveDateTime) -> String {
+    timestamp.format("%Y-%m-%dT%H:%M:%SZ").to_string()
+}
+
+fn format_date(date: NaiveDate, fallback: NaiveDateTime) -> String {
+    date.and_hms_opt(0, 0, 0)
+        .map_or_else(|| format_timestamp(fallback), format_timestamp)
+}
+
+/// Formats a rating out of 100 as a score out of 10
+fn format_rating(rating: i16) -> String {
+    let score = format!("{:.1}", rating as f32 / 10.0);
+    format!("{}/10", score.trim_end_matches(".0"))
+}
+
+fn media_type_term(media_type: ReviewMediaType) -> &'static str {
+    match media_type {
+        ReviewMediaType::Movie => "movie",
+        ReviewMediaType::Show => "show",
+        ReviewMediaType::Season => "season",
+        ReviewMediaType::Episode => "episode",
+    }
+}
+
+impl ReviewFeed {
+    /// Finds the user's most recent reviews. The feed is only available for public
+    /// users, or with the user's feed token
+    pub fn find(
+        conn: &mut DbConnection,
+        user_id: Uuid,
+        token: Option<Uuid>,
+    ) -> Result<Self, AppError> {
+        let (first_name, last_name, public) = users::table
+            .filter(users::user_id.eq(user_id))
+            .select((users::first_name, users::last_name, users::public))
+            .first::<(String, String, bool)>(conn)
+            .optional()?
+            .ok_or(AppError::external(404, "Feed not found"))?;
+
+        let authorised = match token {
+            Some(token) => FeedToken::find_user_id(conn, token).ok() == Some(user_id),
+            None => false,
+        };
+        if !public && !authorised {
+            return Err(AppError::external(404, "Feed not found"));
+        }
+
+        let params = ReviewFindParameters {
+            page: Some(1),
+            page_size: Some(REVIEW_FEED_LENGTH),
+            ..ReviewFindParameters::default()
+        };
+        let reviews = Review::find_all_reviews(conn, user_id, params)?.items;
+
+        Ok(ReviewFeed {
+            user_id,
+            author: format!("{first_name} {last_name}"),
+            reviews,
+        })
+    }
+
+    /// Writes the feed as an Atom document. Reviews without a watch date are dated
+    /// when the feed was generated, and reviews without a description use the entry
+    /// title as content, since Atom entries need content or an alternate link
+    pub fn to_atom(&self, feed_url: &str, generated_at: NaiveDateTime) -> String {
+        let updated = self
+            .reviews
+            .iter()
+            .filter_map(|review| review.date)
+            .max()
+            .map_or_else(
+                || format_timestamp(generated_at),
+                |date| format_date(date, generated_at),
+            );
+
+        let mut atom = String::from("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n");
+        atom.push_str("<feed xmlns=\"http://www.w3.org/2005/Atom\">\n");
+        atom.push_str(&format!("  <id>urn:uuid:{}</id>\n", self.user_id));
+        atom.push_str(&format!(
+            "  <title>{}</title>\n",
+            escape_xml(&format!("{}'s reviews", self.author))
+        ));
+        atom.push_str(&format!(
+            "  <link rel=\"self\" href=\"{}\"/>\n",
+            escape_xml(feed_url)
+        ));
+        atom.push_str(&format!("  <updated>{updated}</updated>\n"));
+        atom.push_str(&format!(
+            "  <author><name>{}</name></author>\n",
+            escape_xml(&self.author)
+        ));
+
+        for review in &self.reviews {
+            let reviewed = ReviewedTitle::from(review);
+            let mut title = reviewed.title.unwrap_or_default();
+            if let Some(rating) = review.rating {
+                title = format!("{title} ({})", format_rating(rating));
+            }
+            let updated = review.date.map_or_else(
+                || format_timestamp(generated_at),
+                |date| format_date(date, generated_at),
+            );
+
+            atom.push_str("  <entry>\n");
+            atom.push_str(&format!("    <id>urn:uuid:{}</id>\n", review.review_id));
+            atom.push_str(&format!("    <title>{}</title>\n", escape_xml(&title)));
+            atom.push_str(&format!("    <updated>{updated}</updated>\n"));
+            atom.push_str(&format!(
+                "    <category term=\"{}\"/>\n",
+                media_type_term(review.media_type)
+            ));
+            if let Some(review_title) = &review.title {
+                atom.push_str(&format!(
+                    "    <summary>{}</summary>\n",
+                    escape_xml(review_title)
+                ));
+            }
+            atom.push_str(&format!(
+                "    <content type=\"text\">{}</content>\n",
+                escape_xml(review.description.as_deref().unwrap_or(&title))
+            ));
+            atom.push_str("  </entry>\n");
+        }
+
+        atom.push_str("</feed>\n");
+
+        atom
+    }
+}
@@ -0,0 +1,37 @@
+use super::ReviewFeed;
+use crate::db::DbPool;
+use actix_web::{get, web, HttpRequest, HttpResponse, Responder};
+use chrono::Utc;
+use serde::Deserialize;
+use utoipa::IntoParams;
+use uuid::Uuid;
+
+#[derive(Deserialize, IntoParams)]
+pub struct ReviewFeedParameters {
+    /// The user's feed token, required unless the user is public
+    #[param(nullable = false)]
+    pub token: Option<Uuid>,
+}
+
+#[utoipa::path(tag = "Review", params(ReviewFeedParameters), responses((status = OK, body = String, content_type = "application/atom+xml"),(status = NOT_FOUND)))]
+#[get("/users/{user_id}/reviews.atom")]
+async fn find(
+    pool: web::Data<DbPool>,
+    req: HttpRequest,
+    user_id: web::Path<Uuid>,
+    params: web::Query<ReviewFeedParameters>,
+) -> actix_web::Result<impl Responder> {
+    let feed = web::block(move || {
+        let mut conn = pool.get()?;
+        ReviewFeed::find(&mut conn, user_id.into_inner(), params.token)
+    })
+    .await??;
+
+    // Leave out the query string, so the link doesn't expose the feed token
+    let mut feed_url = req.full_url();
+    feed_url.set_query(None);
+
+    Ok(HttpResponse::Ok()
+        .content_type("application/atom+xml; charset=utf-8")
+        .body(feed.to_atom(feed_url.as_str(), Utc::now().naive_utc())))
+}
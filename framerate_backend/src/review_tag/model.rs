@@ -0,0 +1,106 @@
+use crate::db::DbConnection;
+use crate::review::Review;
+use crate::schema::{review_tag, tags};
+use crate::tag::Tag;
+use crate::utils::AppError;
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Serialize, Deserialize, Insertable, Associations, Selectable, Queryable)]
+#[diesel(belongs_to(Tag))]
+#[diesel(belongs_to(Review))]
+#[diesel(table_name = review_tag)]
+#[serde(rename_all = "camelCase")]
+pub struct ReviewTag {
+    pub review_id: Uuid,
+    pub tag_id: Uuid,
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ReviewTagSummary {
+    pub tag_id: Uuid,
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ReviewTagDetails {
+    pub tag_id: Uuid,
+    pub name: String,
+}
+
+impl ReviewTag {
+    pub fn find_by_review(
+        conn: &mut DbConnection,
+        review_id: Uuid,
+    ) -> Result<Vec<ReviewTagDetails>, AppError> {
+        let review_tags = review_tag::table
+            .filter(review_tag::review_id.eq(review_id))
+            .inner_join(tags::table)
+            .order(tags::name.asc())
+            .select(Tag::as_select())
+            .load::<Tag>(conn)?;
+
+        let review_tag_details: Vec<ReviewTagDetails> = review_tags
+            .into_iter()
+            .map(|tag| ReviewTagDetails {
+                tag_id: tag.tag_id,
+                name: tag.name,
+            })
+            .collect();
+        Ok(review_tag_details)
+    }
+
+    /// Replaces the tags on a review, only accepting tags owned by the reviewing user
+    pub fn replace(
+        conn: &mut DbConnection,
+        user_id: Uuid,
+        review_id: Uuid,
+        review_tags: Option<&Vec<ReviewTagSummary>>,
+    ) -> Result<Vec<ReviewTagDetails>, AppError> {
+        conn.transaction::<_, AppError, _>(|conn| {
+            diesel::delete(review_tag::table.filter(review_tag::review_id.eq(review_id)))
+                .execute(conn)?;
+
+            let Some(review_tags) = review_tags else {
+                return Ok(());
+            };
+
+            let tag_ids: Vec<Uuid> = review_tags
+                .iter()
+                .map(|review_tag_summary| review_tag_summary.tag_id)
+                .collect();
+
+            let owned_tag_ids = tags::table
+                .filter(tags::user_id.eq(user_id))
+                .filter(tags::tag_id.eq_any(&tag_ids))
+                .select(tags::tag_id)
+                .load::<Uuid>(conn)?;
+
+            if tag_ids.iter().any(|tag_id| !owned_tag_ids.contains(tag_id)) {
+                return Err(AppError::external(400, "Tag not found"));
+            }
+
+            let review_tag_items: Vec<ReviewTag> = owned_tag_ids
+                .into_iter()
+                .map(|tag_id| ReviewTag { review_id, tag_id })
+                .collect();
+
+            diesel::insert_into(review_tag::table)
+                .values(review_tag_items)
+                .execute(conn)?;
+            Ok(())
+        })?;
+
+        let review_tag_details = Self::find_by_review(conn, review_id)?;
+        Ok(review_tag_details)
+    }
+
+    pub fn delete_by_review(conn: &mut DbConnection, review_id: Uuid) -> Result<usize, AppError> {
+        let res = diesel::delete(review_tag::table.filter(review_tag::review_id.eq(review_id)))
+            .execute(conn)?;
+        Ok(res)
+    }
+}
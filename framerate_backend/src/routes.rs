@@ -5,18 +5,29 @@ use utoipa_actix_web::service_config::ServiceConfig;
 
 use crate::administration;
 use crate::authentication;
+use crate::calendar;
+use crate::collection_change;
+use crate::collection_member;
 use crate::company;
+use crate::episode_review;
+use crate::export;
+use crate::feed_token;
+use crate::import;
 use crate::meta;
+use crate::mixed_collection;
 use crate::movie;
 use crate::movie_collection;
 use crate::movie_review;
 use crate::movie_watchlist;
+use crate::review;
+use crate::review_feed;
 use crate::season;
 use crate::season_review;
 use crate::show;
 use crate::show_collection;
 use crate::show_review;
 use crate::show_watchlist;
+use crate::tag;
 use crate::user;
 
 #[get("/health")]
@@ -33,21 +44,62 @@ pub fn init_routes(config: &mut ServiceConfig) {
         .service(administration::generate_setup_token)
         .service(authentication::login)
         .service(authentication::register)
+        .service(calendar::find_upcoming)
+        .service(calendar::find)
+        .service(collection_change::find_all)
+        .service(collection_member::accept_invite)
+        .service(collection_member::create)
+        .service(collection_member::delete)
+        .service(collection_member::find_all)
+        .service(collection_member::find_invites)
+        .service(collection_member::update)
         .service(company::create)
         .service(company::delete)
         .service(company::find_all)
         .service(company::update)
+        .service(episode_review::create)
+        .service(episode_review::delete)
+        .service(episode_review::find_all)
+        .service(episode_review::find_by_review_id)
+        .service(episode_review::find_by_show_season_episode)
+        .service(episode_review::update)
+        .service(export::export_data)
+        .service(export::export_letterboxd)
+        .service(export::import_data)
+        .service(feed_token::create)
+        .service(feed_token::delete)
+        .service(feed_token::find)
+        .service(import::import_imdb)
+        .service(import::import_letterboxd)
+        .service(import::import_trakt)
         .service(meta::get_client_config)
         .service(meta::update_client_config)
+        .service(mixed_collection::copy_entries)
+        .service(mixed_collection::create_entry)
+        .service(mixed_collection::create)
+        .service(mixed_collection::delete_entry)
+        .service(mixed_collection::delete)
+        .service(mixed_collection::find_all)
+        .service(mixed_collection::find)
+        .service(mixed_collection::move_entries)
+        .service(mixed_collection::reorder)
+        .service(mixed_collection::update_entry)
+        .service(mixed_collection::update)
+        .service(movie_collection::copy_entries)
         .service(movie_collection::create_entry)
+        .service(movie_collection::create_franchise_entries)
         .service(movie_collection::create)
         .service(movie_collection::delete_entry)
         .service(movie_collection::delete)
         .service(movie_collection::find_all)
         .service(movie_collection::find_by_movie)
         .service(movie_collection::find)
+        .service(movie_collection::move_entries)
+        .service(movie_collection::reorder)
+        .service(movie_collection::update_entry)
         .service(movie_collection::update)
         .service(movie_review::create)
+        .service(movie_review::delete)
         .service(movie_review::find_all)
         .service(movie_review::find_by_movie_id)
         .service(movie_review::find_by_review_id)
@@ -57,13 +109,21 @@ pub fn init_routes(config: &mut ServiceConfig) {
         .service(movie_watchlist::find_entry)
         .service(movie_watchlist::find)
         .service(movie::details)
+        .service(movie::franchise)
         .service(movie::popular)
         .service(movie::search)
+        .service(review::find_all)
+        .service(review::find_statistics)
+        .service(review::find_year_in_review)
+        .service(review_feed::find)
         .service(season_review::create)
+        .service(season_review::delete)
+        .service(season_review::find_all)
         .service(season_review::find_by_review_id)
         .service(season_review::find_by_show_season)
         .service(season_review::update)
         .service(season::details)
+        .service(show_collection::copy_entries)
         .service(show_collection::create_entry)
         .service(show_collection::create)
         .service(show_collection::delete_entry)
@@ -71,8 +131,12 @@ pub fn init_routes(config: &mut ServiceConfig) {
         .service(show_collection::find_all)
         .service(show_collection::find_by_show)
         .service(show_collection::find)
+        .service(show_collection::move_entries)
+        .service(show_collection::reorder)
+        .service(show_collection::update_entry)
         .service(show_collection::update)
         .service(show_review::create)
+        .service(show_review::delete)
         .service(show_review::find_all)
         .service(show_review::find_by_review_id)
         .service(show_review::find_by_show_id)
@@ -84,6 +148,10 @@ pub fn init_routes(config: &mut ServiceConfig) {
         .service(show::details)
         .service(show::popular)
         .service(show::search)
+        .service(tag::create)
+        .service(tag::delete)
+        .service(tag::find_all)
+        .service(tag::update)
         .service(user::create)
         .service(user::delete)
         .service(user::find_all)
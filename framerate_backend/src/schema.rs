@@ -1,5 +1,33 @@
 // @generated automatically by Diesel CLI.
 
+pub mod sql_types {
+    #[derive(diesel::query_builder::QueryId, Clone, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "tsvector", schema = "pg_catalog"))]
+    pub struct Tsvector;
+}
+
+diesel::table! {
+    collection_changes (change_id) {
+        change_id -> Uuid,
+        collection_id -> Uuid,
+        user_id -> Uuid,
+        media_type -> Text,
+        media_id -> Int4,
+        action -> Text,
+        date_created -> Timestamp,
+    }
+}
+
+diesel::table! {
+    collection_members (collection_id, user_id) {
+        collection_id -> Uuid,
+        user_id -> Uuid,
+        role -> Text,
+        accepted -> Bool,
+        date_created -> Timestamp,
+    }
+}
+
 diesel::table! {
     collections (collection_id) {
         collection_id -> Uuid,
@@ -7,6 +35,7 @@ diesel::table! {
         name -> Text,
         media_type -> Text,
         default_for -> Nullable<Text>,
+        rules -> Nullable<Jsonb>,
     }
 }
 
@@ -21,6 +50,27 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    episode_reviews (review_id) {
+        review_id -> Uuid,
+        user_id -> Uuid,
+        show_id -> Int4,
+        season_number -> Int4,
+        episode_number -> Int4,
+        name -> Nullable<Text>,
+        still_path -> Nullable<Text>,
+        air_date -> Nullable<Date>,
+    }
+}
+
+diesel::table! {
+    feed_tokens (user_id) {
+        user_id -> Uuid,
+        token -> Uuid,
+        date_created -> Timestamp,
+    }
+}
+
 diesel::table! {
     movie_entries (collection_id, movie_id) {
         collection_id -> Uuid,
@@ -32,6 +82,10 @@ diesel::table! {
         release_date -> Nullable<Date>,
         status -> Nullable<Text>,
         updated_at -> Date,
+        position -> Nullable<Int4>,
+        added_at -> Timestamp,
+        note -> Nullable<Text>,
+        priority -> Nullable<Int2>,
     }
 }
 
@@ -55,6 +109,16 @@ diesel::table! {
 }
 
 diesel::table! {
+    review_tag (review_id, tag_id) {
+        review_id -> Uuid,
+        tag_id -> Uuid,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::Tsvector;
+
     reviews (review_id) {
         review_id -> Uuid,
         user_id -> Uuid,
@@ -63,6 +127,7 @@ diesel::table! {
         title -> Nullable<Text>,
         description -> Nullable<Text>,
         venue -> Nullable<Text>,
+        search_vector -> Tsvector,
     }
 }
 
@@ -98,6 +163,10 @@ diesel::table! {
         first_air_date -> Nullable<Date>,
         last_air_date -> Nullable<Date>,
         next_air_date -> Nullable<Date>,
+        position -> Nullable<Int4>,
+        added_at -> Timestamp,
+        note -> Nullable<Text>,
+        priority -> Nullable<Int2>,
     }
 }
 
@@ -113,6 +182,14 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    tags (tag_id) {
+        tag_id -> Uuid,
+        user_id -> Uuid,
+        name -> Text,
+    }
+}
+
 diesel::table! {
     users (user_id) {
         user_id -> Uuid,
@@ -129,13 +206,22 @@ diesel::table! {
     }
 }
 
+diesel::joinable!(collection_changes -> collections (collection_id));
+diesel::joinable!(collection_changes -> users (user_id));
+diesel::joinable!(collection_members -> collections (collection_id));
+diesel::joinable!(collection_members -> users (user_id));
 diesel::joinable!(collections -> users (user_id));
+diesel::joinable!(episode_reviews -> reviews (review_id));
+diesel::joinable!(episode_reviews -> users (user_id));
+diesel::joinable!(feed_tokens -> users (user_id));
 diesel::joinable!(movie_entries -> collections (collection_id));
 diesel::joinable!(movie_entries -> users (user_id));
 diesel::joinable!(movie_reviews -> reviews (review_id));
 diesel::joinable!(movie_reviews -> users (user_id));
 diesel::joinable!(review_company -> company (company_id));
 diesel::joinable!(review_company -> reviews (review_id));
+diesel::joinable!(review_tag -> reviews (review_id));
+diesel::joinable!(review_tag -> tags (tag_id));
 diesel::joinable!(reviews -> users (user_id));
 diesel::joinable!(season_reviews -> reviews (review_id));
 diesel::joinable!(season_reviews -> users (user_id));
@@ -143,17 +229,24 @@ diesel::joinable!(show_entries -> collections (collection_id));
 diesel::joinable!(show_entries -> users (user_id));
 diesel::joinable!(show_reviews -> reviews (review_id));
 diesel::joinable!(show_reviews -> users (user_id));
+diesel::joinable!(tags -> users (user_id));
 
 diesel::allow_tables_to_appear_in_same_query!(
+    collection_changes,
+    collection_members,
     collections,
     company,
+    episode_reviews,
+    feed_tokens,
     movie_entries,
     movie_reviews,
     review_company,
+    review_tag,
     reviews,
     season_reviews,
     server_meta,
     show_entries,
     show_reviews,
+    tags,
     users,
 );
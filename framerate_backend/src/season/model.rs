@@ -63,6 +63,33 @@ impl From<episode::Episode> for Episode {
     }
 }
 
+impl Episode {
+    pub async fn find(
+        client: &TmdbClient,
+        show_id: &i32,
+        season_number: &i32,
+        episode_number: &i32,
+    ) -> Result<Episode, AppError> {
+        let request_url = generate_endpoint(
+            format!("tv/{show_id}/season/{season_number}/episode/{episode_number}"),
+            None,
+        );
+
+        let response = client.get(&request_url).send().await?;
+
+        if !response.status().is_success() {
+            return Err(AppError::tmdb_error(
+                response.status().as_u16(),
+                response.text().await?.as_str(),
+            ));
+        }
+
+        let episode = response.json::<episode::Episode>().await?;
+
+        Ok(Episode::from(episode))
+    }
+}
+
 impl Season {
     pub async fn find(
         client: &TmdbClient,
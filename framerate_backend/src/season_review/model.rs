@@ -1,20 +1,22 @@
 use chrono::NaiveDate;
 use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::{
     db::DbConnection,
-    utils::AppError,
-    review::{self, Review},
+    review::{self, Review, ReviewFindParameters, ReviewOrder, ReviewSort},
     schema::{reviews, season_reviews},
     season::Season,
     user,
+    utils::{response_body::PaginatedResponse, AppError},
 };
 
-#[derive(AsChangeset, Insertable, Associations, Selectable, Queryable)]
+#[derive(Serialize, Deserialize, AsChangeset, Insertable, Associations, Selectable, Queryable)]
 #[diesel(belongs_to(user::User))]
 #[diesel(belongs_to(review::Review))]
 #[diesel(table_name = season_reviews)]
+#[serde(rename_all = "camelCase")]
 pub struct SeasonReview {
     pub review_id: Uuid,
     pub user_id: Uuid,
@@ -35,6 +37,7 @@ pub struct SeasonReviewReadResponse {
     pub description: Option<String>,
     pub venue: Option<String>,
     pub season: Season,
+    pub snippet: Option<String>,
 }
 
 impl From<SeasonReview> for Season {
@@ -74,11 +77,117 @@ impl SeasonReview {
             description: review_details.description,
             venue: review_details.venue,
             season: Season::from(season_review),
+            snippet: None,
         };
 
         Ok(review)
     }
 
+    pub fn find_all_reviews(
+        conn: &mut DbConnection,
+        user_id: Uuid,
+        params: ReviewFindParameters,
+    ) -> Result<PaginatedResponse<SeasonReviewReadResponse>, AppError> {
+        let search = params.search();
+        let cursor = params.decode_cursor()?;
+        let order_column = params.order_column("season_reviews.name", "season_reviews.air_date");
+
+        let filtered_query = || {
+            let mut query = season_reviews::table
+                .filter(season_reviews::user_id.eq(user_id))
+                .inner_join(reviews::table)
+                .into_boxed();
+
+            if let Some(search) = &search {
+                query = query.filter(review::search_matches(
+                    "season_reviews.name",
+                    search.clone(),
+                ));
+            }
+
+            for filter in params.filters(season_reviews::air_date) {
+                query = query.filter(filter);
+            }
+
+            query
+        };
+
+        let mut query = filtered_query();
+
+        let order_by = params.order_by.as_ref().unwrap_or(&ReviewOrder::Date);
+        let sort = params.sort.as_ref().unwrap_or(&ReviewSort::Desc);
+        query = match sort {
+            ReviewSort::Asc => match order_by {
+                ReviewOrder::Date => query.order(reviews::date.asc().nulls_first()),
+                ReviewOrder::MediaReleaseDate => {
+                    query.order(season_reviews::air_date.asc().nulls_first())
+                }
+                ReviewOrder::Rating => query.order(reviews::rating.asc()),
+                ReviewOrder::MediaTitle => query.order(season_reviews::name.asc().nulls_first()),
+            },
+            ReviewSort::Desc => match order_by {
+                ReviewOrder::Date => query.order(reviews::date.desc().nulls_last()),
+                ReviewOrder::MediaReleaseDate => {
+                    query.order(season_reviews::air_date.desc().nulls_last())
+                }
+                ReviewOrder::Rating => query.order(reviews::rating.desc()),
+                ReviewOrder::MediaTitle => query.order(season_reviews::name.desc().nulls_last()),
+            },
+        };
+
+        if let (None, Some(search)) = (&params.order_by, &search) {
+            query = query.order(review::search_rank("season_reviews.name", search.clone()).desc());
+        }
+
+        query = query.then_order_by(reviews::review_id.asc());
+
+        if let Some(cursor) = &cursor {
+            query = query
+                .filter(order_column.after(cursor))
+                .limit(params.page_size() + 1);
+        } else if let Some(page) = params.page {
+            let page_size = params.page_size();
+            query = query.limit(page_size + 1).offset((page - 1) * page_size);
+        }
+
+        let reviews = query
+            .select((
+                SeasonReview::as_select(),
+                Review::as_select(),
+                review::search_snippet(search.clone()),
+                order_column.cursor_value(),
+            ))
+            .load::<(SeasonReview, Review, Option<String>, Option<String>)>(conn)?;
+
+        let total = if params.is_paginated() {
+            filtered_query().count().get_result::<i64>(conn)?
+        } else {
+            reviews.len() as i64
+        };
+
+        let season_reviews: Vec<(SeasonReviewReadResponse, Option<String>)> = reviews
+            .into_iter()
+            .map(|(season, review, snippet, cursor_value)| {
+                (
+                    SeasonReviewReadResponse {
+                        review_id: season.review_id,
+                        user_id: season.user_id,
+                        date: review.date,
+                        description: review.description,
+                        rating: review.rating,
+                        title: review.title,
+                        venue: review.venue,
+                        season: Season::from(season),
+                        snippet,
+                    },
+                    cursor_value,
+                )
+            })
+            .collect();
+
+        Ok(params.paginate(season_reviews, total, |review| review.review_id))
+    }
+
     pub fn find_by_show_season(
         conn: &mut DbConnection,
         user_id: Uuid,
@@ -105,6 +214,7 @@ impl SeasonReview {
                 title: review.title,
                 venue: review.venue,
                 season: Season::from(season),
+                snippet: None,
             })
             .collect();
         Ok(season_reviews)
@@ -124,4 +234,22 @@ impl SeasonReview {
             .get_result(conn)?;
         Ok(updated_review)
     }
+
+    pub fn delete(
+        conn: &mut DbConnection,
+        user_id: Uuid,
+        show_id: i32,
+        season_number: i32,
+        review_id: Uuid,
+    ) -> Result<usize, AppError> {
+        let res = diesel::delete(
+            season_reviews::table
+                .filter(season_reviews::review_id.eq(review_id))
+                .filter(season_reviews::user_id.eq(user_id))
+                .filter(season_reviews::show_id.eq(show_id))
+                .filter(season_reviews::season_number.eq(season_number)),
+        )
+        .execute(conn)?;
+        Ok(res)
+    }
 }
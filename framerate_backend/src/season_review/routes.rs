@@ -1,16 +1,21 @@
 use super::SeasonReviewReadResponse;
 
 use crate::db::DbPool;
-use crate::review::Review;
+use crate::review::{Review, ReviewFindParameters};
 use crate::review_company::{ReviewCompany, ReviewCompanyDetails, ReviewCompanySummary};
+use crate::review_tag::{ReviewTag, ReviewTagDetails, ReviewTagSummary};
 use crate::season::Season;
 use crate::season_review::SeasonReview;
+use crate::show::Show;
+use crate::show_entry::{RemovedShowEntry, ShowEntry};
 use crate::tmdb::TmdbClient;
-use crate::utils::{jwt::Auth, response_body::Success, AppError};
-use actix_web::{get, post, web};
+use crate::utils::response_body::{DeleteResponse, ListResponse, Success};
+use crate::utils::{jwt::Auth, AppError};
+use actix_web::{delete, get, post, web};
 use actix_web::{put, Responder};
 use chrono::NaiveDate;
 use diesel::Connection;
+use futures_util::future::try_join;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use uuid::Uuid;
@@ -30,6 +35,8 @@ pub struct SaveSeasonReviewRequest {
     pub venue: Option<String>,
     #[schema(nullable = false)]
     pub company: Option<Vec<ReviewCompanySummary>>,
+    #[schema(nullable = false)]
+    pub tags: Option<Vec<ReviewTagSummary>>,
 }
 
 impl SaveSeasonReviewRequest {
@@ -37,6 +44,11 @@ impl SaveSeasonReviewRequest {
         self.company = Some(company);
         self
     }
+
+    pub fn tags(mut self, tags: Vec<ReviewTagSummary>) -> Self {
+        self.tags = Some(tags);
+        self
+    }
 }
 
 #[derive(Serialize, Deserialize, ToSchema)]
@@ -62,7 +74,19 @@ pub struct SeasonReviewResponse {
     #[schema(nullable = false)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub company: Option<Vec<ReviewCompanyDetails>>,
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<ReviewTagDetails>>,
     pub season: Season,
+    /// Matching review text with search terms highlighted, when searching
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snippet: Option<String>,
+    /// The watchlist entry taken off the watchlist when the review was created, which
+    /// only happens for the final season of a show that is no longer airing
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub watchlist_removal: Option<RemovedShowEntry>,
 }
 
 impl From<SeasonReviewReadResponse> for SeasonReviewResponse {
@@ -76,7 +100,10 @@ impl From<SeasonReviewReadResponse> for SeasonReviewResponse {
             description: review.description,
             venue: review.venue,
             company: None,
+            tags: None,
             season: review.season,
+            snippet: review.snippet,
+            watchlist_removal: None,
         }
     }
 }
@@ -86,6 +113,31 @@ impl SeasonReviewResponse {
         self.company = Some(company);
         self
     }
+
+    pub fn tags(mut self, tags: Vec<ReviewTagDetails>) -> Self {
+        self.tags = Some(tags);
+        self
+    }
+}
+
+#[utoipa::path(tag = "Season Review", params(ReviewFindParameters), responses((status = OK, body = ListResponse<SeasonReviewResponse>),(status = BAD_REQUEST)))]
+#[get("/shows/seasons/reviews")]
+async fn find_all(
+    pool: web::Data<DbPool>,
+    auth: Auth,
+    params: web::Query<ReviewFindParameters>,
+) -> actix_web::Result<impl Responder> {
+    let paginated = params.is_paginated();
+    let reviews = web::block(move || {
+        let mut conn = pool.get()?;
+        SeasonReview::find_all_reviews(&mut conn, auth.user_id, params.into_inner())
+    })
+    .await??;
+
+    Ok(Success::new(ListResponse::new(
+        reviews.map(SeasonReviewResponse::from),
+        paginated,
+    )))
 }
 
 #[utoipa::path(tag = "Season Review", responses((status = OK, body = Vec<SeasonReviewResponse>)))]
@@ -123,8 +175,13 @@ async fn find_by_review_id(
         let review =
             SeasonReview::find_by_review_id(&mut conn, auth.user_id, review_id.into_inner())?;
         let company = ReviewCompany::find_by_review(&mut conn, review.review_id)?;
+        let tags = ReviewTag::find_by_review(&mut conn, review.review_id)?;
 
-        Ok::<SeasonReviewResponse, AppError>(SeasonReviewResponse::from(review).company(company))
+        Ok::<SeasonReviewResponse, AppError>(
+            SeasonReviewResponse::from(review)
+                .company(company)
+                .tags(tags),
+        )
     })
     .await??;
 
@@ -143,7 +200,12 @@ async fn create(
     let (show_id, season_number) = path.into_inner();
     let review = review.into_inner();
 
-    let season = Season::find(&client, &show_id, &season_number).await?;
+    let (season, show) = try_join(
+        Season::find(&client, &show_id, &season_number),
+        Show::find(&client, &show_id),
+    )
+    .await?;
+    let finishes_show = show.is_final_season(season_number);
 
     let review_id = Uuid::new_v4();
 
@@ -176,6 +238,18 @@ async fn create(
 
             let company =
                 ReviewCompany::replace(conn, created_review.review_id, review.company.as_ref())?;
+            let tags = ReviewTag::replace(
+                conn,
+                auth.user_id,
+                created_review.review_id,
+                review.tags.as_ref(),
+            )?;
+
+            let watchlist_removal = if finishes_show {
+                ShowEntry::remove_reviewed(conn, auth.user_id, show_id)?
+            } else {
+                None
+            };
 
             let review_response = SeasonReviewResponse {
                 review_id: created_review.review_id,
@@ -187,6 +261,9 @@ async fn create(
                 venue: created_review.venue,
                 season: Season::from(created_season_review),
                 company: Some(company),
+                tags: Some(tags),
+                snippet: None,
+                watchlist_removal,
             };
 
             Ok(review_response)
@@ -252,6 +329,12 @@ async fn update(
 
             let company =
                 ReviewCompany::replace(conn, updated_review.review_id, review.company.as_ref())?;
+            let tags = ReviewTag::replace(
+                conn,
+                auth.user_id,
+                updated_review.review_id,
+                review.tags.as_ref(),
+            )?;
 
             let review_response = SeasonReviewResponse {
                 review_id: updated_review.review_id,
@@ -263,6 +346,9 @@ async fn update(
                 venue: updated_review.venue,
                 season: Season::from(updated_season_review),
                 company: Some(company),
+                tags: Some(tags),
+                snippet: None,
+                watchlist_removal: None,
             };
 
             Ok(review_response)
@@ -272,3 +358,37 @@ async fn update(
 
     Ok(Success::new(review))
 }
+
+#[utoipa::path(tag = "Season Review", responses((status = OK, body = DeleteResponse)))]
+#[delete("/shows/{show_id}/seasons/{season_number}/reviews/{review_id}")]
+async fn delete(
+    pool: web::Data<DbPool>,
+    auth: Auth,
+    path: web::Path<(i32, i32, Uuid)>,
+) -> actix_web::Result<impl Responder> {
+    let (show_id, season_number, review_id) = path.into_inner();
+
+    let count = web::block(move || {
+        let mut conn = pool.get()?;
+
+        conn.transaction::<usize, AppError, _>(|conn| {
+            let count =
+                SeasonReview::delete(conn, auth.user_id, show_id, season_number, review_id)?;
+
+            if count == 0 {
+                return Ok(count);
+            }
+
+            ReviewCompany::delete_by_review(conn, review_id)?;
+            ReviewTag::delete_by_review(conn, review_id)?;
+            Review::delete(conn, auth.user_id, review_id)
+        })
+    })
+    .await??;
+
+    if count == 0 {
+        return Err(AppError::external(404, "Review not found"))?;
+    }
+
+    Ok(Success::new(DeleteResponse { count }))
+}
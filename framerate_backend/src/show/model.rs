@@ -287,6 +287,23 @@ struct ShowSearchResults {
 }
 
 impl Show {
+    /// Whether the season is the last one of a show that is no longer airing, so
+    /// watching it finishes the show
+    pub fn is_final_season(&self, season_number: i32) -> bool {
+        let ended = self
+            .status
+            .as_deref()
+            .is_some_and(|status| !SHOW_ACTIVE_STATUSES.contains(&status));
+        let last_season = self
+            .seasons
+            .iter()
+            .flatten()
+            .map(|season| season.season_number)
+            .max();
+
+        ended && last_season == Some(season_number)
+    }
+
     pub async fn find(client: &TmdbClient, id: &i32) -> Result<Show, AppError> {
         let generate_endpoint = generate_endpoint(
             format!("tv/{id}"),
@@ -1,13 +1,19 @@
-use crate::collection::{Collection, UpdatedCollection};
-use crate::db::DbPool;
+use crate::collection::{
+    Collection, CollectionFindParameters, CollectionRules, EntrySort, UpdatedCollection,
+    ENTRY_PRIORITIES,
+};
+use crate::collection_change::{CollectionChange, ENTRY_ADDED, ENTRY_REMOVED};
+use crate::collection_member::{COLLECTION_EDITOR, COLLECTION_ROLES};
+use crate::db::{DbConnection, DbPool};
 use crate::show::{Show, SHOW_MEDIA_TYPE};
-use crate::show_entry::ShowEntry;
+use crate::show_entry::{ShowEntry, UpdatedShowEntry};
 use crate::tmdb::TmdbClient;
 use crate::utils::response_body::{DeleteResponse, Success};
 use crate::utils::{jwt::Auth, AppError};
 use actix_web::{delete, put, Responder};
 use actix_web::{get, post, web};
 use chrono::Utc;
+use diesel::Connection;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use uuid::Uuid;
@@ -18,19 +24,27 @@ pub struct ShowCollection {
     pub collection_id: Uuid,
     pub user_id: Uuid,
     pub name: String,
+    /// Only set on smart collections
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rules: Option<CollectionRules>,
     #[schema(nullable = false)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub entries: Option<Vec<ShowEntry>>,
 }
 
-impl From<Collection> for ShowCollection {
-    fn from(value: Collection) -> Self {
-        ShowCollection {
+impl TryFrom<Collection> for ShowCollection {
+    type Error = AppError;
+
+    fn try_from(value: Collection) -> Result<Self, Self::Error> {
+        let rules = value.smart_rules()?;
+        Ok(ShowCollection {
             collection_id: value.collection_id,
             name: value.name,
             user_id: value.user_id,
+            rules,
             entries: None,
-        }
+        })
     }
 }
 
@@ -45,6 +59,10 @@ impl ShowCollection {
 #[serde(rename_all = "camelCase")]
 pub struct NewShowCollection {
     pub name: String,
+    /// Creates a smart collection, which can't have entries added to it
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rules: Option<CollectionRules>,
 }
 
 #[derive(Serialize, Deserialize, ToSchema)]
@@ -53,6 +71,20 @@ pub struct SaveShowCollectionEntryRequest {
     pub show_id: i32,
 }
 
+#[derive(Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ReorderShowCollectionRequest {
+    /// Entries in the order to show them in. Entries left out are listed after these
+    pub show_ids: Vec<i32>,
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferShowCollectionRequest {
+    pub target_collection_id: Uuid,
+    pub show_ids: Vec<i32>,
+}
+
 #[utoipa::path(tag = "Show Collection", responses((status = OK, body = Vec<ShowCollection>)))]
 #[get("/shows/collections")]
 async fn find_all(pool: web::Data<DbPool>, auth: Auth) -> actix_web::Result<impl Responder> {
@@ -64,25 +96,46 @@ async fn find_all(pool: web::Data<DbPool>, auth: Auth) -> actix_web::Result<impl
 
     let collections = collections
         .into_iter()
-        .map(ShowCollection::from)
-        .collect::<Vec<ShowCollection>>();
+        .map(ShowCollection::try_from)
+        .collect::<Result<Vec<ShowCollection>, AppError>>()?;
 
     Ok(Success::new(collections))
 }
 
-#[utoipa::path(tag = "Show Collection", responses((status = OK, body = ShowCollection)))]
+#[utoipa::path(tag = "Show Collection", params(CollectionFindParameters), responses((status = OK, body = ShowCollection),(status = BAD_REQUEST)))]
 #[get("/shows/collections/{collection_id}")]
 async fn find(
     pool: web::Data<DbPool>,
     auth: Auth,
     collection_id: web::Path<Uuid>,
+    params: web::Query<CollectionFindParameters>,
 ) -> actix_web::Result<impl Responder> {
     let show_collection = web::block(move || {
         let mut conn = pool.get()?;
-        let collection =
-            Collection::find(&mut conn, auth.user_id, SHOW_MEDIA_TYPE, &collection_id)?;
-        let entries = ShowEntry::find_all(&mut conn, auth.user_id, collection.collection_id)?;
-        Ok::<ShowCollection, AppError>(ShowCollection::from(collection).entries(entries))
+        let collection = Collection::find_shared(
+            &mut conn,
+            auth.user_id,
+            Some(SHOW_MEDIA_TYPE),
+            &collection_id,
+            &COLLECTION_ROLES,
+        )?;
+        let sort = params.sort.unwrap_or_default();
+        let entries = match collection.smart_rules()? {
+            Some(rules) => ShowEntry::find_by_rules(
+                &mut conn,
+                collection.user_id,
+                collection.collection_id,
+                &rules,
+                sort,
+            )?,
+            None => ShowEntry::find_all(
+                &mut conn,
+                collection.user_id,
+                collection.collection_id,
+                sort,
+            )?,
+        };
+        Ok::<ShowCollection, AppError>(ShowCollection::try_from(collection)?.entries(entries))
     })
     .await??;
 
@@ -104,15 +157,19 @@ async fn create(
         name: params.name,
         media_type: SHOW_MEDIA_TYPE.to_string(),
         default_for: None,
+        rules: params.rules.as_ref().map(|rules| serde_json::json!(rules)),
     };
 
     let collection = web::block(move || {
         let mut conn = pool.get()?;
+        if let Some(rules) = &params.rules {
+            rules.validate(&mut conn, auth.user_id, SHOW_MEDIA_TYPE)?;
+        }
         Collection::create(&mut conn, collection)
     })
     .await??;
 
-    Ok(Success::new(ShowCollection::from(collection)))
+    Ok(Success::new(ShowCollection::try_from(collection)?))
 }
 
 #[utoipa::path(tag = "Show Collection", responses((status = OK, body = ShowCollection)))]
@@ -131,7 +188,7 @@ async fn update(
     })
     .await??;
 
-    Ok(Success::new(ShowCollection::from(collection)))
+    Ok(Success::new(ShowCollection::try_from(collection)?))
 }
 
 #[utoipa::path(tag = "Show Collection", responses((status = OK, body = DeleteResponse)))]
@@ -167,8 +224,20 @@ async fn create_entry(
 
     let show_entry = web::block(move || {
         let mut conn = pool.get()?;
-        let collection =
-            Collection::find(&mut conn, auth.user_id, SHOW_MEDIA_TYPE, &collection_id)?;
+        let collection = Collection::find_shared(
+            &mut conn,
+            auth.user_id,
+            Some(SHOW_MEDIA_TYPE),
+            &collection_id,
+            &[COLLECTION_EDITOR],
+        )?;
+
+        if collection.rules.is_some() {
+            return Err(AppError::external(
+                400,
+                "Entries cannot be added to smart collections",
+            ));
+        }
 
         let imdb_id = if let Some(external_ids) = show.external_ids {
             external_ids.imdb_id
@@ -178,7 +247,7 @@ async fn create_entry(
 
         let show_entry_to_save = ShowEntry {
             collection_id: collection.collection_id,
-            user_id: auth.user_id,
+            user_id: collection.user_id,
             show_id: show_entry.show_id,
             imdb_id,
             name: show.name,
@@ -188,9 +257,62 @@ async fn create_entry(
             next_air_date: show.next_air_date,
             status: show.status,
             updated_at: Utc::now().naive_utc().date(),
+            position: None,
+            added_at: Utc::now().naive_utc(),
+            note: None,
+            priority: None,
         };
 
-        ShowEntry::create(&mut conn, show_entry_to_save)
+        conn.transaction::<ShowEntry, AppError, _>(|conn| {
+            let show_entry = ShowEntry::create(conn, show_entry_to_save)?;
+            CollectionChange::create(
+                conn,
+                collection.collection_id,
+                auth.user_id,
+                SHOW_MEDIA_TYPE,
+                show_entry.show_id,
+                ENTRY_ADDED,
+            )?;
+            Ok(show_entry)
+        })
+    })
+    .await??;
+
+    Ok(Success::new(show_entry))
+}
+
+#[utoipa::path(tag = "Show Collection", responses((status = OK, body = ShowEntry),(status = BAD_REQUEST)))]
+#[put("/shows/collections/{collection_id}/{show_id}")]
+async fn update_entry(
+    pool: web::Data<DbPool>,
+    auth: Auth,
+    path: web::Path<(Uuid, i32)>,
+    params: web::Json<UpdatedShowEntry>,
+) -> actix_web::Result<impl Responder> {
+    let (collection_id, show_id) = path.into_inner();
+    let params = params.into_inner();
+
+    if params.added_at.is_none() && params.note.is_none() && params.priority.is_none() {
+        return Err(AppError::external(400, "No changes to save"))?;
+    }
+    if params
+        .priority
+        .flatten()
+        .is_some_and(|priority| !ENTRY_PRIORITIES.contains(&priority))
+    {
+        return Err(AppError::external(400, "Invalid priority"))?;
+    }
+
+    let show_entry = web::block(move || {
+        let mut conn = pool.get()?;
+        let collection = Collection::find_shared(
+            &mut conn,
+            auth.user_id,
+            Some(SHOW_MEDIA_TYPE),
+            &collection_id,
+            &[COLLECTION_EDITOR],
+        )?;
+        ShowEntry::update(&mut conn, collection.collection_id, show_id, params)
     })
     .await??;
 
@@ -208,9 +330,27 @@ async fn delete_entry(
 
     let count = web::block(move || {
         let mut conn = pool.get()?;
-        let collection =
-            Collection::find(&mut conn, auth.user_id, SHOW_MEDIA_TYPE, &collection_id)?;
-        ShowEntry::delete(&mut conn, collection.collection_id, show_id)
+        let collection = Collection::find_shared(
+            &mut conn,
+            auth.user_id,
+            Some(SHOW_MEDIA_TYPE),
+            &collection_id,
+            &[COLLECTION_EDITOR],
+        )?;
+        conn.transaction::<usize, AppError, _>(|conn| {
+            let count = ShowEntry::delete(conn, collection.collection_id, show_id)?;
+            if count > 0 {
+                CollectionChange::create(
+                    conn,
+                    collection.collection_id,
+                    auth.user_id,
+                    SHOW_MEDIA_TYPE,
+                    show_id,
+                    ENTRY_REMOVED,
+                )?;
+            }
+            Ok(count)
+        })
     })
     .await??;
 
@@ -221,6 +361,149 @@ async fn delete_entry(
     Ok(Success::new(DeleteResponse { count }))
 }
 
+#[utoipa::path(tag = "Show Collection", responses((status = OK, body = ShowCollection),(status = BAD_REQUEST)))]
+#[put("/shows/collections/{collection_id}/order")]
+async fn reorder(
+    pool: web::Data<DbPool>,
+    auth: Auth,
+    collection_id: web::Path<Uuid>,
+    params: web::Json<ReorderShowCollectionRequest>,
+) -> actix_web::Result<impl Responder> {
+    let show_collection = web::block(move || {
+        let mut conn = pool.get()?;
+        let collection = Collection::find_shared(
+            &mut conn,
+            auth.user_id,
+            Some(SHOW_MEDIA_TYPE),
+            &collection_id,
+            &[COLLECTION_EDITOR],
+        )?;
+        ShowEntry::reorder(&mut conn, collection.collection_id, &params.show_ids)?;
+        let entries = ShowEntry::find_all(
+            &mut conn,
+            collection.user_id,
+            collection.collection_id,
+            EntrySort::Position,
+        )?;
+        Ok::<ShowCollection, AppError>(ShowCollection::try_from(collection)?.entries(entries))
+    })
+    .await??;
+
+    Ok(Success::new(show_collection))
+}
+
+/// Copies entries from one collection to another, removing them from the source when
+/// moving. Entries already in the target are only removed
+fn transfer_entries(
+    conn: &mut DbConnection,
+    user_id: Uuid,
+    collection_id: Uuid,
+    params: TransferShowCollectionRequest,
+    remove: bool,
+) -> Result<Vec<ShowEntry>, AppError> {
+    if collection_id == params.target_collection_id {
+        return Err(AppError::external(
+            400,
+            "Source and target collections must differ",
+        ));
+    }
+
+    let source_roles: &[&str] = if remove {
+        &[COLLECTION_EDITOR]
+    } else {
+        &COLLECTION_ROLES
+    };
+    let source = Collection::find_shared(
+        conn,
+        user_id,
+        Some(SHOW_MEDIA_TYPE),
+        &collection_id,
+        source_roles,
+    )?;
+    let target = Collection::find_shared(
+        conn,
+        user_id,
+        Some(SHOW_MEDIA_TYPE),
+        &params.target_collection_id,
+        &[COLLECTION_EDITOR],
+    )?;
+
+    if target.rules.is_some() {
+        return Err(AppError::external(
+            400,
+            "Entries cannot be added to smart collections",
+        ));
+    }
+
+    conn.transaction::<Vec<ShowEntry>, AppError, _>(|conn| {
+        let show_entries = ShowEntry::copy(conn, source.collection_id, &target, &params.show_ids)?;
+        for show_entry in &show_entries {
+            CollectionChange::create(
+                conn,
+                target.collection_id,
+                user_id,
+                SHOW_MEDIA_TYPE,
+                show_entry.show_id,
+                ENTRY_ADDED,
+            )?;
+        }
+
+        if remove {
+            for show_id in &params.show_ids {
+                ShowEntry::delete(conn, source.collection_id, *show_id)?;
+                CollectionChange::create(
+                    conn,
+                    source.collection_id,
+                    user_id,
+                    SHOW_MEDIA_TYPE,
+                    *show_id,
+                    ENTRY_REMOVED,
+                )?;
+            }
+        }
+
+        Ok(show_entries)
+    })
+}
+
+#[utoipa::path(tag = "Show Collection", responses((status = OK, body = Vec<ShowEntry>),(status = BAD_REQUEST)))]
+#[post("/shows/collections/{collection_id}/copy")]
+async fn copy_entries(
+    pool: web::Data<DbPool>,
+    auth: Auth,
+    collection_id: web::Path<Uuid>,
+    params: web::Json<TransferShowCollectionRequest>,
+) -> actix_web::Result<impl Responder> {
+    let params = params.into_inner();
+
+    let show_entries = web::block(move || {
+        let mut conn = pool.get()?;
+        transfer_entries(&mut conn, auth.user_id, *collection_id, params, false)
+    })
+    .await??;
+
+    Ok(Success::new(show_entries))
+}
+
+#[utoipa::path(tag = "Show Collection", responses((status = OK, body = Vec<ShowEntry>),(status = BAD_REQUEST)))]
+#[post("/shows/collections/{collection_id}/move")]
+async fn move_entries(
+    pool: web::Data<DbPool>,
+    auth: Auth,
+    collection_id: web::Path<Uuid>,
+    params: web::Json<TransferShowCollectionRequest>,
+) -> actix_web::Result<impl Responder> {
+    let params = params.into_inner();
+
+    let show_entries = web::block(move || {
+        let mut conn = pool.get()?;
+        transfer_entries(&mut conn, auth.user_id, *collection_id, params, true)
+    })
+    .await??;
+
+    Ok(Success::new(show_entries))
+}
+
 #[utoipa::path(tag = "Show Collection", responses((status = OK, body = Vec<Uuid>)))]
 #[get("/shows/collections/show/{show_id}")]
 async fn find_by_show(
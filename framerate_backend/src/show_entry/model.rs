@@ -1,12 +1,21 @@
+use crate::collection::{
+    self, code_point_order, Collection, CollectionRules, EntrySort, RuleSource, SortableEntry,
+    WatchlistOnReview, DEFAULT_WATCHED, DEFAULT_WATCHLIST,
+};
 use crate::db::DbConnection;
-use crate::schema::show_entries;
-use crate::show::{Show, SHOW_ACTIVE_STATUSES};
+use crate::schema::{
+    collections, review_company, review_tag, reviews, show_entries, show_reviews, users,
+};
+use crate::show::{Show, SHOW_ACTIVE_STATUSES, SHOW_MEDIA_TYPE};
+use crate::show_review::ShowReview;
 use crate::tmdb::TmdbClient;
-use crate::utils::AppError;
-use crate::{collection, user};
-use chrono::{Duration, NaiveDate, TimeDelta, Utc};
+use crate::user;
+use crate::utils::{serialization::nullable, AppError};
+use chrono::{Duration, NaiveDate, NaiveDateTime, NaiveTime, TimeDelta, Utc};
 use diesel::prelude::*;
+use diesel::sql_types::{Nullable, Text};
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 use utoipa::ToSchema;
 use uuid::Uuid;
 
@@ -50,6 +59,54 @@ pub struct ShowEntry {
     #[schema(nullable = false)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub next_air_date: Option<NaiveDate>,
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<i32>,
+    pub added_at: NaiveDateTime,
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<i16>,
+}
+
+/// Changes to an entry. Fields left out are kept, and `note` and `priority` are
+/// cleared when set to null
+#[derive(Debug, Default, Deserialize, Serialize, AsChangeset, ToSchema)]
+#[diesel(table_name = show_entries)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdatedShowEntry {
+    #[schema(nullable = false)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub added_at: Option<NaiveDateTime>,
+    #[schema(value_type = Option<String>)]
+    #[serde(
+        default,
+        deserialize_with = "nullable",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub note: Option<Option<String>>,
+    /// From 1 (lowest) to 5 (highest)
+    #[schema(value_type = Option<i16>)]
+    #[serde(
+        default,
+        deserialize_with = "nullable",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub priority: Option<Option<i16>>,
+}
+
+/// A watchlist entry removed when the show or one of its seasons was reviewed, so the client can undo it
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RemovedShowEntry {
+    /// The entry as it was in the watchlist
+    pub entry: ShowEntry,
+    /// The watched collection the entry was moved to, when it wasn't only removed
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub moved_to: Option<Uuid>,
 }
 
 impl ShowEntry {
@@ -57,16 +114,162 @@ impl ShowEntry {
         conn: &mut DbConnection,
         user_id: Uuid,
         collection_id: Uuid,
+        sort: EntrySort,
     ) -> Result<Vec<Self>, AppError> {
-        let show_entries = show_entries::table
+        let query = show_entries::table
             .filter(show_entries::user_id.eq(user_id))
             .filter(show_entries::collection_id.eq(collection_id))
-            .order(show_entries::first_air_date.desc())
             .select(ShowEntry::as_select())
-            .load(conn)?;
+            .into_boxed();
+
+        let query = match sort {
+            EntrySort::Position => query.order((
+                show_entries::position.asc().nulls_last(),
+                show_entries::added_at.asc(),
+                code_point_order::<Text>("show_entries.name").asc(),
+            )),
+            EntrySort::Title => query.order(code_point_order::<Text>("show_entries.name").asc()),
+            EntrySort::ReleaseDate => query.order(show_entries::first_air_date.desc().nulls_last()),
+            EntrySort::DateAdded => query.order((
+                show_entries::added_at.desc(),
+                code_point_order::<Text>("show_entries.name").asc(),
+            )),
+            EntrySort::Status => query.order((
+                code_point_order::<Nullable<Text>>("show_entries.status")
+                    .asc()
+                    .nulls_last(),
+                code_point_order::<Text>("show_entries.name").asc(),
+            )),
+        };
+
+        let show_entries = query.load(conn)?;
         Ok(show_entries)
     }
 
+    /// Finds the entries of a smart collection, listing each show once
+    pub fn find_by_rules(
+        conn: &mut DbConnection,
+        user_id: Uuid,
+        collection_id: Uuid,
+        rules: &CollectionRules,
+        sort: EntrySort,
+    ) -> Result<Vec<Self>, AppError> {
+        let mut entries = match rules.source {
+            RuleSource::Reviews => Self::find_reviewed(conn, user_id, rules)?,
+            RuleSource::Entries => Self::find_matching(conn, user_id, rules)?,
+        };
+
+        let mut seen = HashSet::new();
+        entries.retain(|entry| seen.insert(entry.show_id));
+        for entry in &mut entries {
+            entry.collection_id = collection_id;
+            entry.position = None;
+        }
+
+        entries.sort_by(|a, b| sort.compare(a, b));
+        Ok(entries)
+    }
+
+    /// Reviewed shows matching the rules, latest review first
+    fn find_reviewed(
+        conn: &mut DbConnection,
+        user_id: Uuid,
+        rules: &CollectionRules,
+    ) -> Result<Vec<Self>, AppError> {
+        let mut query = show_reviews::table
+            .inner_join(reviews::table)
+            .filter(show_reviews::user_id.eq(user_id))
+            .select((ShowReview::as_select(), reviews::date))
+            .into_boxed();
+
+        if let Some(min_rating) = rules.min_rating {
+            query = query.filter(reviews::rating.ge(min_rating));
+        }
+
+        if let Some(company_id) = rules.company_id {
+            query = query.filter(
+                show_reviews::review_id.eq_any(
+                    review_company::table
+                        .filter(review_company::company_id.eq(company_id))
+                        .select(review_company::review_id),
+                ),
+            );
+        }
+
+        if let Some(tag_id) = rules.tag_id {
+            query = query.filter(
+                show_reviews::review_id.eq_any(
+                    review_tag::table
+                        .filter(review_tag::tag_id.eq(tag_id))
+                        .select(review_tag::review_id),
+                ),
+            );
+        }
+
+        let reviews = query
+            .order(reviews::date.desc().nulls_last())
+            .load::<(ShowReview, Option<NaiveDate>)>(conn)?;
+
+        let today = Utc::now().date_naive();
+        let entries = reviews
+            .into_iter()
+            .map(|(review, date)| ShowEntry {
+                collection_id: Uuid::nil(),
+                show_id: review.show_id,
+                user_id: review.user_id,
+                name: review.name,
+                imdb_id: review.imdb_id,
+                poster_path: review.poster_path,
+                first_air_date: review.first_air_date,
+                last_air_date: None,
+                next_air_date: None,
+                status: None,
+                updated_at: today,
+                position: None,
+                added_at: date.unwrap_or(today).and_time(NaiveTime::MIN),
+                note: None,
+                priority: None,
+            })
+            .collect();
+
+        Ok(entries)
+    }
+
+    /// Entries of static collections matching the rules, earliest added first
+    fn find_matching(
+        conn: &mut DbConnection,
+        user_id: Uuid,
+        rules: &CollectionRules,
+    ) -> Result<Vec<Self>, AppError> {
+        let mut query = show_entries::table
+            .inner_join(collections::table)
+            .filter(show_entries::user_id.eq(user_id))
+            .select(ShowEntry::as_select())
+            .into_boxed();
+
+        if let Some(collection_id) = rules.collection_id {
+            query = query.filter(show_entries::collection_id.eq(collection_id));
+        }
+
+        if rules.watchlist {
+            query = query.filter(collections::default_for.eq(DEFAULT_WATCHLIST));
+        }
+
+        if let Some(statuses) = &rules.statuses {
+            query = query.filter(show_entries::status.eq_any(statuses));
+        }
+
+        if let Some(days) = rules.upcoming_days {
+            let today = Utc::now().date_naive();
+            query = query.filter(
+                show_entries::next_air_date.between(today, today + TimeDelta::days(days.into())),
+            );
+        }
+
+        let entries = query.order(show_entries::added_at.asc()).load(conn)?;
+        Ok(entries)
+    }
+
     pub fn find(
         conn: &mut DbConnection,
         user_id: Uuid,
@@ -86,10 +289,26 @@ impl ShowEntry {
     pub fn create(conn: &mut DbConnection, watchlist_entry: ShowEntry) -> Result<Self, AppError> {
         let new_watchlist = diesel::insert_into(show_entries::table)
             .values(watchlist_entry)
+            .returning(ShowEntry::as_returning())
             .get_result(conn)?;
         Ok(new_watchlist)
     }
 
+    pub fn update(
+        conn: &mut DbConnection,
+        collection_id: Uuid,
+        show_id: i32,
+        entry: UpdatedShowEntry,
+    ) -> Result<Self, AppError> {
+        let updated_entry = diesel::update(show_entries::table)
+            .filter(show_entries::collection_id.eq(collection_id))
+            .filter(show_entries::show_id.eq(show_id))
+            .set(entry)
+            .returning(ShowEntry::as_returning())
+            .get_result(conn)?;
+        Ok(updated_entry)
+    }
+
     pub fn delete(
         conn: &mut DbConnection,
         collection_id: Uuid,
@@ -106,6 +325,160 @@ impl ShowEntry {
         Ok(res)
     }
 
+    /// Copies entries to another collection without fetching them from TMDB again,
+    /// keeping their note and priority. Returns the entries that weren't already in
+    /// the target collection
+    pub fn copy(
+        conn: &mut DbConnection,
+        collection_id: Uuid,
+        target: &collection::Collection,
+        show_ids: &[i32],
+    ) -> Result<Vec<Self>, AppError> {
+        if show_ids.iter().collect::<HashSet<_>>().len() != show_ids.len() {
+            return Err(AppError::external(400, "Duplicate collection entry"));
+        }
+
+        let entries = show_entries::table
+            .filter(show_entries::collection_id.eq(collection_id))
+            .filter(show_entries::show_id.eq_any(show_ids))
+            .select(ShowEntry::as_select())
+            .load(conn)?;
+
+        if entries.len() != show_ids.len() {
+            return Err(AppError::external(400, "Collection entry not found"));
+        }
+
+        let now = Utc::now().naive_utc();
+        let entries = entries
+            .into_iter()
+            .map(|entry| ShowEntry {
+                collection_id: target.collection_id,
+                user_id: target.user_id,
+                position: None,
+                added_at: now,
+                ..entry
+            })
+            .collect::<Vec<ShowEntry>>();
+
+        let copied_entries = diesel::insert_into(show_entries::table)
+            .values(entries)
+            .on_conflict_do_nothing()
+            .returning(ShowEntry::as_returning())
+            .get_results(conn)?;
+        Ok(copied_entries)
+    }
+
+    /// Takes a reviewed show off the user's default watchlist, removing it or moving it
+    /// to their default watched collection as set in their configuration
+    pub fn remove_reviewed(
+        conn: &mut DbConnection,
+        user_id: Uuid,
+        show_id: i32,
+    ) -> Result<Option<RemovedShowEntry>, AppError> {
+        let configuration = users::table
+            .filter(users::user_id.eq(user_id))
+            .select(users::configuration)
+            .first::<serde_json::Value>(conn)?;
+        let on_review = WatchlistOnReview::from_configuration(&configuration);
+
+        if on_review == WatchlistOnReview::Keep {
+            return Ok(None);
+        }
+
+        let Some(watchlist) =
+            Collection::find_optional_default(conn, user_id, SHOW_MEDIA_TYPE, DEFAULT_WATCHLIST)?
+        else {
+            return Ok(None);
+        };
+
+        let Some(entry) = show_entries::table
+            .filter(show_entries::collection_id.eq(watchlist.collection_id))
+            .filter(show_entries::show_id.eq(show_id))
+            .select(ShowEntry::as_select())
+            .first(conn)
+            .optional()?
+        else {
+            return Ok(None);
+        };
+
+        let moved_to = match on_review {
+            WatchlistOnReview::Move => {
+                let watched = Collection::find_or_create_default(
+                    conn,
+                    user_id,
+                    SHOW_MEDIA_TYPE,
+                    DEFAULT_WATCHED,
+                    "Watched Shows",
+                )?;
+                ShowEntry::copy(conn, watchlist.collection_id, &watched, &[show_id])?;
+                Some(watched.collection_id)
+            }
+            _ => None,
+        };
+
+        ShowEntry::delete(conn, watchlist.collection_id, show_id)?;
+
+        Ok(Some(RemovedShowEntry { entry, moved_to }))
+    }
+
+    /// Places the entries of a collection in the order of `show_ids`. Entries left out
+    /// lose their position and are listed after the ordered ones
+    pub fn reorder(
+        conn: &mut DbConnection,
+        collection_id: Uuid,
+        show_ids: &[i32],
+    ) -> Result<(), AppError> {
+        let positions: Vec<(i32, i32)> = show_ids.iter().copied().zip(0..).collect();
+        Self::set_positions(conn, collection_id, &positions)
+    }
+
+    /// Sets the positions of the entries of a collection, given as pairs of ID and
+    /// position. Entries left out lose their position
+    pub fn set_positions(
+        conn: &mut DbConnection,
+        collection_id: Uuid,
+        positions: &[(i32, i32)],
+    ) -> Result<(), AppError> {
+        conn.transaction::<(), AppError, _>(|conn| {
+            let entries: HashSet<i32> = show_entries::table
+                .filter(show_entries::collection_id.eq(collection_id))
+                .select(show_entries::show_id)
+                .load::<i32>(conn)?
+                .into_iter()
+                .collect();
+
+            let mut ordered = HashSet::new();
+            for (show_id, _) in positions {
+                if !entries.contains(show_id) {
+                    return Err(AppError::external(400, "Collection entry not found"));
+                }
+                if !ordered.insert(show_id) {
+                    return Err(AppError::external(400, "Duplicate collection entry"));
+                }
+            }
+
+            diesel::update(
+                show_entries::table.filter(show_entries::collection_id.eq(collection_id)),
+            )
+            .set(show_entries::position.eq(None::<i32>))
+            .execute(conn)?;
+
+            for (show_id, position) in positions {
+                diesel::update(
+                    show_entries::table.filter(
+                        show_entries::collection_id
+                            .eq(collection_id)
+                            .and(show_entries::show_id.eq(show_id)),
+                    ),
+                )
+                .set(show_entries::position.eq(position))
+                .execute(conn)?;
+            }
+
+            Ok(())
+        })
+    }
+
     pub fn find_collections(
         conn: &mut DbConnection,
         user_id: Uuid,
@@ -162,9 +535,38 @@ impl ShowEntry {
 
         let updated = diesel::update(show_entries::table)
             .filter(show_entries::show_id.eq(self.show_id))
-            .set(self)
+            .set((
+                show_entries::last_air_date.eq(self.last_air_date),
+                show_entries::next_air_date.eq(self.next_air_date),
+                show_entries::poster_path.eq(self.poster_path),
+                show_entries::status.eq(self.status),
+                show_entries::updated_at.eq(self.updated_at),
+            ))
+            .returning(ShowEntry::as_returning())
             .get_result(conn)?;
 
         Ok(updated)
     }
 }
+
+impl SortableEntry for ShowEntry {
+    fn title(&self) -> &str {
+        &self.name
+    }
+
+    fn date(&self) -> Option<NaiveDate> {
+        self.first_air_date
+    }
+
+    fn added_at(&self) -> NaiveDateTime {
+        self.added_at
+    }
+
+    fn status(&self) -> Option<&str> {
+        self.status.as_deref()
+    }
+
+    fn position(&self) -> Option<i32> {
+        self.position
+    }
+}
@@ -1,20 +1,22 @@
 use chrono::NaiveDate;
 use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::{
-    db::{DbConnection, DEFAULT_PAGE_SIZE},
+    db::DbConnection,
     review::{self, Review, ReviewFindParameters, ReviewOrder, ReviewSort},
-    schema::{review_company, reviews, show_reviews},
+    schema::{reviews, show_reviews},
     show::Show,
     user,
-    utils::AppError,
+    utils::{response_body::PaginatedResponse, AppError},
 };
 
-#[derive(AsChangeset, Insertable, Associations, Selectable, Queryable)]
+#[derive(Serialize, Deserialize, AsChangeset, Insertable, Associations, Selectable, Queryable)]
 #[diesel(belongs_to(user::User))]
 #[diesel(belongs_to(review::Review))]
 #[diesel(table_name = show_reviews)]
+#[serde(rename_all = "camelCase")]
 pub struct ShowReview {
     pub review_id: Uuid,
     pub user_id: Uuid,
@@ -35,6 +37,7 @@ pub struct ShowReviewReadResponse {
     pub description: Option<String>,
     pub venue: Option<String>,
     pub show: Show,
+    pub snippet: Option<String>,
 }
 
 impl From<ShowReview> for Show {
@@ -80,6 +83,7 @@ impl ShowReview {
             description: review_details.description,
             venue: review_details.venue,
             show: Show::from(show_review),
+            snippet: None,
         };
 
         Ok(review)
@@ -89,14 +93,32 @@ impl ShowReview {
         conn: &mut DbConnection,
         user_id: Uuid,
         params: ReviewFindParameters,
-    ) -> Result<Vec<ShowReviewReadResponse>, AppError> {
-        let mut query = show_reviews::table
-            .filter(show_reviews::user_id.eq(user_id))
-            .inner_join(reviews::table)
-            .into_boxed();
+    ) -> Result<PaginatedResponse<ShowReviewReadResponse>, AppError> {
+        let search = params.search();
+        let cursor = params.decode_cursor()?;
+        let order_column = params.order_column("show_reviews.name", "show_reviews.first_air_date");
+
+        let filtered_query = || {
+            let mut query = show_reviews::table
+                .filter(show_reviews::user_id.eq(user_id))
+                .inner_join(reviews::table)
+                .into_boxed();
+
+            if let Some(search) = &search {
+                query = query.filter(review::search_matches("show_reviews.name", search.clone()));
+            }
+
+            for filter in params.filters(show_reviews::first_air_date) {
+                query = query.filter(filter);
+            }
 
-        let order_by = params.order_by.unwrap_or(ReviewOrder::Date);
-        let sort = params.sort.unwrap_or(ReviewSort::Desc);
+            query
+        };
+
+        let mut query = filtered_query();
+
+        let order_by = params.order_by.as_ref().unwrap_or(&ReviewOrder::Date);
+        let sort = params.sort.as_ref().unwrap_or(&ReviewSort::Desc);
         query = match sort {
             ReviewSort::Asc => match order_by {
                 ReviewOrder::Date => query.order(reviews::date.asc().nulls_first()),
@@ -116,60 +138,57 @@ impl ShowReview {
             },
         };
 
-        query = query.then_order_by(reviews::review_id.asc());
-
-        if let Some(venue) = params.at_venue {
-            query = query.filter(reviews::venue.eq(venue));
+        if let (None, Some(search)) = (&params.order_by, &search) {
+            query = query.order(review::search_rank("show_reviews.name", search.clone()).desc());
         }
 
-        if let Some(rating_max) = params.rating_max {
-            if rating_max < 0 {
-                query = query.filter(reviews::rating.is_null());
-            } else {
-                query = query.filter(reviews::rating.le(rating_max));
-                if let Some(rating_min) = params.rating_min {
-                    query = query.filter(reviews::rating.ge(rating_min));
-                }
-            }
-        } else {
-            if let Some(rating_min) = params.rating_min {
-                query = query.filter(reviews::rating.ge(rating_min));
-            }
-        }
-
-        if let Some(with_company) = params.with_company {
-            query = query.filter(
-                reviews::review_id.eq_any(
-                    review_company::table
-                        .select(review_company::review_id)
-                        .filter(review_company::company_id.eq(with_company)),
-                ),
-            );
-        }
+        query = query.then_order_by(reviews::review_id.asc());
 
-        if let Some(page) = params.page {
-            let page_size = params.page_size.unwrap_or(DEFAULT_PAGE_SIZE);
-            query = query.limit(page_size).offset((page - 1) * page_size);
+        if let Some(cursor) = &cursor {
+            query = query
+                .filter(order_column.after(cursor))
+                .limit(params.page_size() + 1);
+        } else if let Some(page) = params.page {
+            let page_size = params.page_size();
+            query = query.limit(page_size + 1).offset((page - 1) * page_size);
         }
 
         let reviews = query
-            .select((ShowReview::as_select(), Review::as_select()))
-            .load::<(ShowReview, Review)>(conn)?;
+            .select((
+                ShowReview::as_select(),
+                Review::as_select(),
+                review::search_snippet(search.clone()),
+                order_column.cursor_value(),
+            ))
+            .load::<(ShowReview, Review, Option<String>, Option<String>)>(conn)?;
+
+        let total = if params.is_paginated() {
+            filtered_query().count().get_result::<i64>(conn)?
+        } else {
+            reviews.len() as i64
+        };
 
-        let show_reviews: Vec<ShowReviewReadResponse> = reviews
+        let show_reviews: Vec<(ShowReviewReadResponse, Option<String>)> = reviews
             .into_iter()
-            .map(|(show, review)| ShowReviewReadResponse {
-                review_id: show.review_id,
-                user_id: show.user_id,
-                date: review.date,
-                description: review.description,
-                rating: review.rating,
-                title: review.title,
-                venue: review.venue,
-                show: Show::from(show),
+            .map(|(show, review, snippet, cursor_value)| {
+                (
+                    ShowReviewReadResponse {
+                        review_id: show.review_id,
+                        user_id: show.user_id,
+                        date: review.date,
+                        description: review.description,
+                        rating: review.rating,
+                        title: review.title,
+                        venue: review.venue,
+                        show: Show::from(show),
+                        snippet,
+                    },
+                    cursor_value,
+                )
             })
             .collect();
-        Ok(show_reviews)
+
+        Ok(params.paginate(show_reviews, total, |review| review.review_id))
     }
 
     pub fn find_by_show_id(
@@ -196,6 +215,7 @@ impl ShowReview {
                 title: review.title,
                 venue: review.venue,
                 show: Show::from(show),
+                snippet: None,
             })
             .collect();
         Ok(show_reviews)
@@ -215,4 +235,20 @@ impl ShowReview {
             .get_result(conn)?;
         Ok(updated_review)
     }
+
+    pub fn delete(
+        conn: &mut DbConnection,
+        user_id: Uuid,
+        show_id: i32,
+        review_id: Uuid,
+    ) -> Result<usize, AppError> {
+        let res = diesel::delete(
+            show_reviews::table
+                .filter(show_reviews::review_id.eq(review_id))
+                .filter(show_reviews::user_id.eq(user_id))
+                .filter(show_reviews::show_id.eq(show_id)),
+        )
+        .execute(conn)?;
+        Ok(res)
+    }
 }
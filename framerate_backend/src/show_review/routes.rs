@@ -3,11 +3,14 @@ use super::ShowReviewReadResponse;
 use crate::db::DbPool;
 use crate::review::{Review, ReviewFindParameters};
 use crate::review_company::{ReviewCompany, ReviewCompanyDetails, ReviewCompanySummary};
+use crate::review_tag::{ReviewTag, ReviewTagDetails, ReviewTagSummary};
 use crate::show::Show;
+use crate::show_entry::{RemovedShowEntry, ShowEntry};
 use crate::show_review::ShowReview;
 use crate::tmdb::TmdbClient;
-use crate::utils::{jwt::Auth, response_body::Success, AppError};
-use actix_web::{get, post, web};
+use crate::utils::response_body::{DeleteResponse, ListResponse, Success};
+use crate::utils::{jwt::Auth, AppError};
+use actix_web::{delete, get, post, web};
 use actix_web::{put, Responder};
 use chrono::NaiveDate;
 use diesel::Connection;
@@ -30,6 +33,8 @@ pub struct SaveShowReviewRequest {
     pub venue: Option<String>,
     #[schema(nullable = false)]
     pub company: Option<Vec<ReviewCompanySummary>>,
+    #[schema(nullable = false)]
+    pub tags: Option<Vec<ReviewTagSummary>>,
 }
 
 impl SaveShowReviewRequest {
@@ -37,6 +42,11 @@ impl SaveShowReviewRequest {
         self.company = Some(company);
         self
     }
+
+    pub fn tags(mut self, tags: Vec<ReviewTagSummary>) -> Self {
+        self.tags = Some(tags);
+        self
+    }
 }
 
 #[derive(Serialize, Deserialize, ToSchema)]
@@ -62,7 +72,18 @@ pub struct ShowReviewResponse {
     #[schema(nullable = false)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub company: Option<Vec<ReviewCompanyDetails>>,
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<ReviewTagDetails>>,
     pub show: Show,
+    /// Matching review text with search terms highlighted, when searching
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snippet: Option<String>,
+    /// The watchlist entry taken off the watchlist when the review was created
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub watchlist_removal: Option<RemovedShowEntry>,
 }
 
 impl From<ShowReviewReadResponse> for ShowReviewResponse {
@@ -76,7 +97,10 @@ impl From<ShowReviewReadResponse> for ShowReviewResponse {
             description: review.description,
             venue: review.venue,
             company: None,
+            tags: None,
             show: review.show,
+            snippet: review.snippet,
+            watchlist_removal: None,
         }
     }
 }
@@ -86,27 +110,31 @@ impl ShowReviewResponse {
         self.company = Some(company);
         self
     }
+
+    pub fn tags(mut self, tags: Vec<ReviewTagDetails>) -> Self {
+        self.tags = Some(tags);
+        self
+    }
 }
 
-#[utoipa::path(tag = "Show Review", params(ReviewFindParameters), responses((status = OK, body = Vec<ShowReviewResponse>)))]
+#[utoipa::path(tag = "Show Review", params(ReviewFindParameters), responses((status = OK, body = ListResponse<ShowReviewResponse>),(status = BAD_REQUEST)))]
 #[get("/shows/reviews")]
 async fn find_all(
     pool: web::Data<DbPool>,
     auth: Auth,
     params: web::Query<ReviewFindParameters>,
 ) -> actix_web::Result<impl Responder> {
+    let paginated = params.is_paginated();
     let reviews = web::block(move || {
         let mut conn = pool.get()?;
         ShowReview::find_all_reviews(&mut conn, auth.user_id, params.into_inner())
     })
     .await??;
 
-    Ok(Success::new(
-        reviews
-            .into_iter()
-            .map(ShowReviewResponse::from)
-            .collect::<Vec<ShowReviewResponse>>(),
-    ))
+    Ok(Success::new(ListResponse::new(
+        reviews.map(ShowReviewResponse::from),
+        paginated,
+    )))
 }
 
 #[utoipa::path(tag = "Show Review", responses((status = OK, body = ShowReviewResponse)))]
@@ -121,8 +149,11 @@ async fn find_by_review_id(
         let review =
             ShowReview::find_by_review_id(&mut conn, auth.user_id, review_id.into_inner())?;
         let company = ReviewCompany::find_by_review(&mut conn, review.review_id)?;
+        let tags = ReviewTag::find_by_review(&mut conn, review.review_id)?;
 
-        Ok::<ShowReviewResponse, AppError>(ShowReviewResponse::from(review).company(company))
+        Ok::<ShowReviewResponse, AppError>(
+            ShowReviewResponse::from(review).company(company).tags(tags),
+        )
     })
     .await??;
 
@@ -201,6 +232,14 @@ async fn create(
 
             let company =
                 ReviewCompany::replace(conn, created_review.review_id, review.company.as_ref())?;
+            let tags = ReviewTag::replace(
+                conn,
+                auth.user_id,
+                created_review.review_id,
+                review.tags.as_ref(),
+            )?;
+
+            let watchlist_removal = ShowEntry::remove_reviewed(conn, auth.user_id, show_id)?;
 
             let review_response = ShowReviewResponse {
                 review_id: created_review.review_id,
@@ -212,6 +251,9 @@ async fn create(
                 venue: created_review.venue,
                 show: Show::from(created_show_review),
                 company: Some(company),
+                tags: Some(tags),
+                snippet: None,
+                watchlist_removal,
             };
 
             Ok(review_response)
@@ -278,6 +320,12 @@ async fn update(
 
             let company =
                 ReviewCompany::replace(conn, updated_review.review_id, review.company.as_ref())?;
+            let tags = ReviewTag::replace(
+                conn,
+                auth.user_id,
+                updated_review.review_id,
+                review.tags.as_ref(),
+            )?;
 
             let review_response = ShowReviewResponse {
                 review_id: updated_review.review_id,
@@ -289,6 +337,9 @@ async fn update(
                 venue: updated_review.venue,
                 show: Show::from(updated_show_review),
                 company: Some(company),
+                tags: Some(tags),
+                snippet: None,
+                watchlist_removal: None,
             };
 
             Ok(review_response)
@@ -298,3 +349,36 @@ async fn update(
 
     Ok(Success::new(review))
 }
+
+#[utoipa::path(tag = "Show Review", responses((status = OK, body = DeleteResponse)))]
+#[delete("/shows/{show_id}/reviews/{review_id}")]
+async fn delete(
+    pool: web::Data<DbPool>,
+    auth: Auth,
+    path: web::Path<(i32, Uuid)>,
+) -> actix_web::Result<impl Responder> {
+    let (show_id, review_id) = path.into_inner();
+
+    let count = web::block(move || {
+        let mut conn = pool.get()?;
+
+        conn.transaction::<usize, AppError, _>(|conn| {
+            let count = ShowReview::delete(conn, auth.user_id, show_id, review_id)?;
+
+            if count == 0 {
+                return Ok(count);
+            }
+
+            ReviewCompany::delete_by_review(conn, review_id)?;
+            ReviewTag::delete_by_review(conn, review_id)?;
+            Review::delete(conn, auth.user_id, review_id)
+        })
+    })
+    .await??;
+
+    if count == 0 {
+        return Err(AppError::external(404, "Review not found"))?;
+    }
+
+    Ok(Success::new(DeleteResponse { count }))
+}
@@ -1,4 +1,4 @@
-use crate::collection::Collection;
+use crate::collection::{Collection, CollectionFindParameters, DEFAULT_WATCHLIST};
 use crate::db::DbPool;
 use crate::show::{Show, SHOW_MEDIA_TYPE};
 use crate::show_entry::ShowEntry;
@@ -7,12 +7,9 @@ use crate::utils::response_body::{DeleteResponse, Success};
 use crate::utils::{jwt::Auth, AppError};
 use actix_web::{delete, Responder};
 use actix_web::{get, post, web};
-use chrono::{NaiveDate, Utc};
+use chrono::{NaiveDate, NaiveDateTime, Utc};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
-use uuid::Uuid;
-
-const DEFAULT_WATCHLIST: &str = "watchlist";
 
 #[derive(Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
@@ -38,6 +35,13 @@ pub struct ShowWatchlistEntry {
     #[schema(nullable = false)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub next_air_date: Option<NaiveDate>,
+    pub added_at: NaiveDateTime,
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<i16>,
 }
 
 impl From<ShowEntry> for ShowWatchlistEntry {
@@ -52,6 +56,9 @@ impl From<ShowEntry> for ShowWatchlistEntry {
             show_id: value.show_id,
             status: value.status,
             updated_at: value.updated_at,
+            added_at: value.added_at,
+            note: value.note,
+            priority: value.priority,
         }
     }
 }
@@ -87,29 +94,29 @@ pub struct SaveShowWatchlistEntryRequest {
     pub show_id: i32,
 }
 
-#[utoipa::path(tag = "Show Watchlist", responses((status = OK, body = ShowWatchlist)))]
+#[utoipa::path(tag = "Show Watchlist", params(CollectionFindParameters), responses((status = OK, body = ShowWatchlist),(status = BAD_REQUEST)))]
 #[get("/shows/watchlist")]
-async fn find(pool: web::Data<DbPool>, auth: Auth) -> actix_web::Result<impl Responder> {
+async fn find(
+    pool: web::Data<DbPool>,
+    auth: Auth,
+    params: web::Query<CollectionFindParameters>,
+) -> actix_web::Result<impl Responder> {
     let watchlist = web::block(move || {
         let mut conn = pool.get()?;
-        let watchlist =
-            Collection::find_default(&mut conn, auth.user_id, SHOW_MEDIA_TYPE, DEFAULT_WATCHLIST);
-
-        let watchlist = match watchlist {
-            Ok(watchlist) => Ok(watchlist),
-            _ => Collection::create(
-                &mut conn,
-                Collection {
-                    collection_id: Uuid::new_v4(),
-                    media_type: SHOW_MEDIA_TYPE.to_string(),
-                    user_id: auth.user_id,
-                    name: "Show Watchlist".to_string(),
-                    default_for: Some(DEFAULT_WATCHLIST.to_string()),
-                },
-            ),
-        }?;
-
-        let entries = ShowEntry::find_all(&mut conn, auth.user_id, watchlist.collection_id)?;
+        let watchlist = Collection::find_or_create_default(
+            &mut conn,
+            auth.user_id,
+            SHOW_MEDIA_TYPE,
+            DEFAULT_WATCHLIST,
+            "Show Watchlist",
+        )?;
+
+        let entries = ShowEntry::find_all(
+            &mut conn,
+            auth.user_id,
+            watchlist.collection_id,
+            params.sort.unwrap_or_default(),
+        )?;
 
         Ok::<ShowWatchlist, AppError>(ShowWatchlist::from(watchlist).entries(entries))
     })
@@ -171,6 +178,10 @@ async fn create_entry(
             next_air_date: show.next_air_date,
             status: show.status,
             updated_at: Utc::now().naive_utc().date(),
+            position: None,
+            added_at: Utc::now().naive_utc(),
+            note: None,
+            priority: None,
         };
 
         ShowEntry::create(&mut conn, show_entry_to_save)
@@ -0,0 +1,87 @@
+use crate::{db::DbConnection, schema::tags, user, utils::AppError};
+use diesel::{
+    prelude::*,
+    result::{DatabaseErrorKind, Error as DieselError},
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Serialize, Deserialize, Selectable, Queryable, Associations, Insertable, ToSchema)]
+#[diesel(belongs_to(user::User))]
+#[diesel(table_name = tags)]
+#[serde(rename_all = "camelCase")]
+pub struct Tag {
+    pub tag_id: Uuid,
+    pub user_id: Uuid,
+    pub name: String,
+}
+
+#[derive(AsChangeset, Serialize, Deserialize, ToSchema)]
+#[diesel(table_name = tags)]
+#[serde(rename_all = "camelCase")]
+pub struct SaveTag {
+    pub name: String,
+}
+
+fn map_duplicate_name(error: DieselError) -> AppError {
+    match error {
+        DieselError::DatabaseError(DatabaseErrorKind::UniqueViolation, _) => {
+            AppError::external(409, "Tag already exists")
+        }
+        error => error.into(),
+    }
+}
+
+impl Tag {
+    pub fn find_all(conn: &mut DbConnection, user_id: &Uuid) -> Result<Vec<Self>, AppError> {
+        let tags = tags::table
+            .select(Tag::as_select())
+            .filter(tags::user_id.eq(user_id))
+            .order(tags::name.asc())
+            .load(conn)?;
+        Ok(tags)
+    }
+
+    pub fn create(conn: &mut DbConnection, tag: SaveTag, user_id: Uuid) -> Result<Self, AppError> {
+        let tag_to_save = Tag {
+            tag_id: Uuid::new_v4(),
+            user_id,
+            name: tag.name,
+        };
+
+        let new_tag = diesel::insert_into(tags::table)
+            .values(tag_to_save)
+            .get_result(conn)
+            .map_err(map_duplicate_name)?;
+
+        Ok(new_tag)
+    }
+
+    pub fn update(
+        conn: &mut DbConnection,
+        tag_id: Uuid,
+        tag: SaveTag,
+        user_id: &Uuid,
+    ) -> Result<Self, AppError> {
+        let updated_tag = diesel::update(tags::table)
+            .filter(tags::user_id.eq(user_id))
+            .filter(tags::tag_id.eq(tag_id))
+            .set(tag)
+            .get_result(conn)
+            .map_err(map_duplicate_name)?;
+        Ok(updated_tag)
+    }
+
+    pub fn delete(
+        conn: &mut DbConnection,
+        tag_id: Uuid,
+        user_id: &Uuid,
+    ) -> Result<usize, AppError> {
+        let res = diesel::delete(tags::table)
+            .filter(tags::user_id.eq(user_id))
+            .filter(tags::tag_id.eq(tag_id))
+            .execute(conn)?;
+        Ok(res)
+    }
+}
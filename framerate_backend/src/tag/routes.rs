@@ -0,0 +1,90 @@
+use crate::{
+    db::DbPool,
+    tag::model::{SaveTag, Tag},
+    utils::{
+        jwt::Auth,
+        response_body::{DeleteResponse, Success},
+        AppError,
+    },
+};
+use actix_web::{delete, get, post, put, web, Responder};
+use diesel::result::Error as DieselError;
+use uuid::Uuid;
+
+#[utoipa::path(tag = "Tag", responses((status = OK, body = Vec<Tag>)))]
+#[get("/tags")]
+async fn find_all(pool: web::Data<DbPool>, auth: Auth) -> actix_web::Result<impl Responder> {
+    let tags = web::block(move || {
+        let mut conn = pool.get()?;
+        Tag::find_all(&mut conn, &auth.user_id)
+    })
+    .await??;
+
+    Ok(Success::new(tags))
+}
+
+#[utoipa::path(tag = "Tag", responses((status = OK, body = Tag),(status = CONFLICT)))]
+#[post("/tags")]
+async fn create(
+    pool: web::Data<DbPool>,
+    auth: Auth,
+    tag: web::Json<SaveTag>,
+) -> actix_web::Result<impl Responder> {
+    let tag = web::block(move || {
+        let mut conn = pool.get()?;
+        Tag::create(&mut conn, tag.into_inner(), auth.user_id)
+    })
+    .await??;
+
+    Ok(Success::new(tag))
+}
+
+#[utoipa::path(tag = "Tag", responses((status = OK, body = Tag),(status = CONFLICT)))]
+#[put("/tags/{tag_id}")]
+async fn update(
+    pool: web::Data<DbPool>,
+    auth: Auth,
+    tag_id: web::Path<Uuid>,
+    tag: web::Json<SaveTag>,
+) -> actix_web::Result<impl Responder> {
+    let tag = web::block(move || {
+        let mut conn = pool.get()?;
+        Tag::update(
+            &mut conn,
+            tag_id.into_inner(),
+            tag.into_inner(),
+            &auth.user_id,
+        )
+    })
+    .await?;
+
+    let tag = match tag {
+        Ok(tag) => tag,
+        Err(AppError::DieselError(DieselError::NotFound)) => {
+            return Err(AppError::external(404, "Tag not found"))?;
+        }
+        Err(error) => return Err(error)?,
+    };
+
+    Ok(Success::new(tag))
+}
+
+#[utoipa::path(tag = "Tag", responses((status = OK, body = DeleteResponse)))]
+#[delete("/tags/{tag_id}")]
+async fn delete(
+    pool: web::Data<DbPool>,
+    auth: Auth,
+    tag_id: web::Path<Uuid>,
+) -> actix_web::Result<impl Responder> {
+    let count = web::block(move || {
+        let mut conn = pool.get()?;
+        Tag::delete(&mut conn, tag_id.into_inner(), &auth.user_id)
+    })
+    .await??;
+
+    if count == 0 {
+        return Err(AppError::external(404, "Tag not found"))?;
+    }
+
+    Ok(Success::new(DeleteResponse { count }))
+}
@@ -0,0 +1,19 @@
+use crate::utils::AppError;
+use serde::de::DeserializeOwned;
+use std::io::Read;
+use zip::read::ZipFile;
+
+/// Largest size a file of an uploaded archive is read to once decompressed, as the
+/// payload limit only applies to the compressed archive
+pub const MAX_ARCHIVE_FILE_SIZE: u64 = 32 * 1024 * 1024;
+
+/// Reads a JSON file of an uploaded archive. Files claiming to be larger than
+/// `MAX_ARCHIVE_FILE_SIZE` are refused, and reading stops there for ones that lie
+pub fn read_json<T: DeserializeOwned>(file: ZipFile, name: &str) -> Result<T, AppError> {
+    if file.size() > MAX_ARCHIVE_FILE_SIZE {
+        return Err(AppError::external(413, &format!("{name} is too large")));
+    }
+
+    serde_json::from_reader(file.take(MAX_ARCHIVE_FILE_SIZE))
+        .map_err(|_| AppError::external(400, &format!("Invalid {name}")))
+}
@@ -1,8 +1,10 @@
+pub mod archive;
 pub mod env_vars;
 pub mod error;
 pub mod invite;
 pub mod jwt;
 pub mod log;
 pub mod response_body;
+pub mod serialization;
 
 pub use error::*;
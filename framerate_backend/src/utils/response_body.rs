@@ -40,3 +40,51 @@ impl<T: Serialize> Responder for Success<T> {
 pub struct DeleteResponse {
     pub count: usize,
 }
+
+#[derive(Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PaginatedResponse<T> {
+    pub items: Vec<T>,
+    pub total: i64,
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<i64>,
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page_size: Option<i64>,
+    /// Pass as `cursor` to fetch the following page. Absent on the last page
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
+}
+
+/// The items of a list, wrapped with pagination details only when the request asked
+/// for a page, so clients that never paginate keep receiving a plain array
+#[derive(Deserialize, Serialize, ToSchema)]
+#[serde(untagged)]
+pub enum ListResponse<T> {
+    Paginated(PaginatedResponse<T>),
+    Items(Vec<T>),
+}
+
+impl<T> ListResponse<T> {
+    pub fn new(response: PaginatedResponse<T>, paginated: bool) -> Self {
+        if paginated {
+            ListResponse::Paginated(response)
+        } else {
+            ListResponse::Items(response.items)
+        }
+    }
+}
+
+impl<T> PaginatedResponse<T> {
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> PaginatedResponse<U> {
+        PaginatedResponse {
+            items: self.items.into_iter().map(f).collect(),
+            total: self.total,
+            page: self.page,
+            page_size: self.page_size,
+            next_cursor: self.next_cursor,
+        }
+    }
+}
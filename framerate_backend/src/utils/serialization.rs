@@ -0,0 +1,11 @@
+use serde::{Deserialize, Deserializer};
+
+/// Reads a field that can be left out, set to null or set to a value as
+/// `None`, `Some(None)` and `Some(Some(value))`. Use with `#[serde(default)]`
+pub fn nullable<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
+}
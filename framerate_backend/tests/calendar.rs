@@ -0,0 +1,223 @@
+pub mod common;
+
+mod find {
+    use crate::common::{data, setup};
+    use actix_web::{http::header::CONTENT_TYPE, test};
+    use chrono::{TimeDelta, Utc};
+    use framerate::calendar::find;
+    use uuid::Uuid;
+
+    #[actix_web::test]
+    async fn should_return_not_found_for_unknown_token() {
+        let (app, _) = setup::create_app(find).await;
+
+        let request = test::TestRequest::get()
+            .uri(&format!("/calendar/{}.ics", Uuid::new_v4()))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(404, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_list_upcoming_releases_once() {
+        let (app, pool) = setup::create_app(find).await;
+
+        let today = Utc::now().naive_utc().date();
+        let release_date = today + TimeDelta::days(10);
+        let air_date = today + TimeDelta::days(3);
+
+        let feed_token = {
+            let mut conn = pool.get().unwrap();
+            let user = data::create_user(&mut conn);
+            let watchlist = data::create_default_movie_watchlist(&mut conn, &user);
+            let collection = data::create_movie_collection(&mut conn, &user);
+            data::create_upcoming_movie_entry(&mut conn, &user, &watchlist, release_date);
+            data::create_upcoming_movie_entry(&mut conn, &user, &collection, release_date);
+            let show_collection = data::create_show_collection(&mut conn, &user);
+            data::create_upcoming_show_entry(&mut conn, &user, &show_collection, air_date);
+            data::create_feed_token(&mut conn, &user)
+        };
+
+        let request = test::TestRequest::get()
+            .uri(&format!("/calendar/{}.ics", feed_token.token))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+        assert_eq!(
+            "text/calendar; charset=utf-8",
+            response.headers().get(CONTENT_TYPE).unwrap()
+        );
+
+        let body = String::from_utf8(test::read_body(response).await.to_vec()).unwrap();
+        assert!(body.starts_with("BEGIN:VCALENDAR\r\n"));
+        assert_eq!(2, body.matches("BEGIN:VEVENT").count());
+
+        let show_start = body
+            .find(&format!("DTSTART;VALUE=DATE:{}", air_date.format("%Y%m%d")))
+            .unwrap();
+        let movie_start = body
+            .find(&format!(
+                "DTSTART;VALUE=DATE:{}",
+                release_date.format("%Y%m%d")
+            ))
+            .unwrap();
+        assert!(show_start < movie_start);
+        assert!(body.contains("SUMMARY:Hot Fuzz\r\n"));
+        assert!(body.contains("SUMMARY:Doctor Who (new episode)\r\n"));
+    }
+
+    #[actix_web::test]
+    async fn should_not_list_past_releases() {
+        let (app, pool) = setup::create_app(find).await;
+
+        let feed_token = {
+            let mut conn = pool.get().unwrap();
+            let user = data::create_user(&mut conn);
+            let collection = data::create_movie_collection(&mut conn, &user);
+            data::create_movie_entry(&mut conn, &user, &collection);
+            data::create_feed_token(&mut conn, &user)
+        };
+
+        let request = test::TestRequest::get()
+            .uri(&format!("/calendar/{}.ics", feed_token.token))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let body = String::from_utf8(test::read_body(response).await.to_vec()).unwrap();
+        assert!(!body.contains("BEGIN:VEVENT"));
+    }
+}
+
+mod find_upcoming {
+    use crate::common::{data, process, setup};
+    use actix_web::{http::header::AUTHORIZATION, test};
+    use chrono::{TimeDelta, Utc};
+    use framerate::calendar::{find_upcoming, CalendarEvent};
+    use framerate::collection_member::COLLECTION_VIEWER;
+
+    #[actix_web::test]
+    async fn should_require_authentication() {
+        let (app, _) = setup::create_app(find_upcoming).await;
+
+        let request = test::TestRequest::get().uri("/upcoming").to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(401, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_reject_invalid_days() {
+        let (app, pool) = setup::create_app(find_upcoming).await;
+
+        let token = {
+            let mut conn = pool.get().unwrap();
+            let (token, _) = data::create_authed_user(&mut conn);
+            token
+        };
+
+        for days in ["366", "-1", "soon"] {
+            let request = test::TestRequest::get()
+                .uri(&format!("/upcoming?days={days}"))
+                .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+                .to_request();
+
+            let response = test::call_service(&app, request).await;
+            assert_eq!(400, response.status());
+        }
+    }
+
+    #[actix_web::test]
+    async fn should_list_upcoming_releases_within_days() {
+        let (app, pool) = setup::create_app(find_upcoming).await;
+
+        let today = Utc::now().date_naive();
+        let release_date = today + TimeDelta::days(10);
+        let air_date = today + TimeDelta::days(3);
+        let later_release_date = today + TimeDelta::days(60);
+
+        let token = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let watchlist = data::create_default_movie_watchlist(&mut conn, &user);
+            let collection = data::create_movie_collection(&mut conn, &user);
+            data::create_upcoming_movie_entry(&mut conn, &user, &watchlist, release_date);
+            data::create_upcoming_movie_entry(&mut conn, &user, &collection, release_date);
+            data::create_named_movie_entry(
+                &mut conn,
+                &user,
+                &collection,
+                4639,
+                "Shaun of the Dead",
+                later_release_date,
+            );
+            let show_collection = data::create_show_collection(&mut conn, &user);
+            data::create_upcoming_show_entry(&mut conn, &user, &show_collection, air_date);
+            token
+        };
+
+        let request = test::TestRequest::get()
+            .uri("/upcoming?days=30")
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<Vec<CalendarEvent>>(response).await;
+
+        assert_eq!(2, result.data.len());
+        assert_eq!("show", result.data[0].media_type);
+        assert_eq!("Doctor Who", result.data[0].title);
+        assert_eq!(air_date, result.data[0].date);
+        assert_eq!("movie", result.data[1].media_type);
+        assert_eq!("Hot Fuzz", result.data[1].title);
+        assert_eq!(release_date, result.data[1].date);
+
+        let request = test::TestRequest::get()
+            .uri("/upcoming?days=90")
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<Vec<CalendarEvent>>(response).await;
+
+        assert_eq!(3, result.data.len());
+        assert_eq!("Shaun of the Dead", result.data[2].title);
+    }
+
+    #[actix_web::test]
+    async fn should_list_releases_in_shared_collections() {
+        let (app, pool) = setup::create_app(find_upcoming).await;
+
+        let release_date = Utc::now().date_naive() + TimeDelta::days(10);
+
+        let token = {
+            let mut conn = pool.get().unwrap();
+            let owner = data::create_user(&mut conn);
+            let collection = data::create_movie_collection(&mut conn, &owner);
+            data::create_upcoming_movie_entry(&mut conn, &owner, &collection, release_date);
+            let (token, user) = data::create_authed_user(&mut conn);
+            data::create_collection_member(&mut conn, &collection, &user, COLLECTION_VIEWER);
+            token
+        };
+
+        let request = test::TestRequest::get()
+            .uri("/upcoming")
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<Vec<CalendarEvent>>(response).await;
+
+        assert_eq!(1, result.data.len());
+        assert_eq!(release_date, result.data[0].date);
+    }
+}
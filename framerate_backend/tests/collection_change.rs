@@ -0,0 +1,97 @@
+pub mod common;
+
+mod find_all {
+    use crate::common::{data, process, setup};
+    use actix_web::{http::header::AUTHORIZATION, test};
+    use framerate::{
+        collection_change::{find_all, CollectionChange, ENTRY_ADDED},
+        collection_member::COLLECTION_VIEWER,
+        movie::MOVIE_MEDIA_TYPE,
+    };
+
+    #[actix_web::test]
+    async fn should_require_authentication() {
+        let (app, pool) = setup::create_app(find_all).await;
+
+        let collection = {
+            let mut conn = pool.get().unwrap();
+            let user = data::create_user(&mut conn);
+            data::create_movie_collection(&mut conn, &user)
+        };
+
+        let request = test::TestRequest::get()
+            .uri(&format!(
+                "/collections/{}/changes",
+                collection.collection_id
+            ))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(401, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_not_return_changes_to_other_users() {
+        let (app, pool) = setup::create_app(find_all).await;
+
+        let (token, collection) = {
+            let mut conn = pool.get().unwrap();
+            let owner = data::create_user(&mut conn);
+            let collection = data::create_movie_collection(&mut conn, &owner);
+            let (token, _) = data::create_authed_user(&mut conn);
+            (token, collection)
+        };
+
+        let request = test::TestRequest::get()
+            .uri(&format!(
+                "/collections/{}/changes",
+                collection.collection_id
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(404, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_return_changes_to_members() {
+        let (app, pool) = setup::create_app(find_all).await;
+
+        let (token, collection, owner) = {
+            let mut conn = pool.get().unwrap();
+            let owner = data::create_user(&mut conn);
+            let collection = data::create_movie_collection(&mut conn, &owner);
+            let (token, user) = data::create_authed_user(&mut conn);
+            data::create_collection_member(&mut conn, &collection, &user, COLLECTION_VIEWER);
+            CollectionChange::create(
+                &mut conn,
+                collection.collection_id,
+                owner.user_id,
+                MOVIE_MEDIA_TYPE,
+                4638,
+                ENTRY_ADDED,
+            )
+            .unwrap();
+            (token, collection, owner)
+        };
+
+        let request = test::TestRequest::get()
+            .uri(&format!(
+                "/collections/{}/changes",
+                collection.collection_id
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<Vec<CollectionChange>>(response).await;
+
+        assert_eq!(1, result.data.len());
+        assert_eq!(owner.user_id, result.data[0].user_id);
+        assert_eq!(4638, result.data[0].media_id);
+        assert_eq!(ENTRY_ADDED, result.data[0].action);
+    }
+}
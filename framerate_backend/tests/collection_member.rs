@@ -0,0 +1,502 @@
+pub mod common;
+
+mod find_all {
+    use crate::common::{data, process, setup};
+    use actix_web::{http::header::AUTHORIZATION, test};
+    use framerate::collection_member::{find_all, CollectionMemberResponse, COLLECTION_VIEWER};
+
+    #[actix_web::test]
+    async fn should_require_authentication() {
+        let (app, pool) = setup::create_app(find_all).await;
+
+        let collection = {
+            let mut conn = pool.get().unwrap();
+            let user = data::create_user(&mut conn);
+            data::create_movie_collection(&mut conn, &user)
+        };
+
+        let request = test::TestRequest::get()
+            .uri(&format!(
+                "/collections/{}/members",
+                collection.collection_id
+            ))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(401, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_not_return_members_to_invited_user() {
+        let (app, pool) = setup::create_app(find_all).await;
+
+        let (token, collection) = {
+            let mut conn = pool.get().unwrap();
+            let owner = data::create_user(&mut conn);
+            let collection = data::create_movie_collection(&mut conn, &owner);
+            let (token, user) = data::create_authed_user(&mut conn);
+            data::create_collection_invite(&mut conn, &collection, &user, COLLECTION_VIEWER);
+            (token, collection)
+        };
+
+        let request = test::TestRequest::get()
+            .uri(&format!(
+                "/collections/{}/members",
+                collection.collection_id
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(404, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_return_members() {
+        let (app, pool) = setup::create_app(find_all).await;
+
+        let (token, collection, user) = {
+            let mut conn = pool.get().unwrap();
+            let owner = data::create_user(&mut conn);
+            let collection = data::create_movie_collection(&mut conn, &owner);
+            let (token, user) = data::create_authed_user(&mut conn);
+            data::create_collection_member(&mut conn, &collection, &user, COLLECTION_VIEWER);
+            (token, collection, user)
+        };
+
+        let request = test::TestRequest::get()
+            .uri(&format!(
+                "/collections/{}/members",
+                collection.collection_id
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<Vec<CollectionMemberResponse>>(response).await;
+
+        assert_eq!(1, result.data.len());
+        assert_eq!(user.user_id, result.data[0].user_id);
+        assert_eq!(COLLECTION_VIEWER, result.data[0].role);
+        assert!(result.data[0].accepted);
+    }
+}
+
+mod create {
+    use crate::common::{data, process, setup};
+    use actix_web::{http::header::AUTHORIZATION, test};
+    use framerate::collection_member::{
+        create, CollectionMember, NewCollectionMember, COLLECTION_EDITOR,
+    };
+
+    #[actix_web::test]
+    async fn should_require_authentication() {
+        let (app, pool) = setup::create_app(create).await;
+
+        let (collection, user) = {
+            let mut conn = pool.get().unwrap();
+            let owner = data::create_user(&mut conn);
+            let collection = data::create_movie_collection(&mut conn, &owner);
+            (collection, data::create_user(&mut conn))
+        };
+
+        let request = test::TestRequest::post()
+            .uri(&format!(
+                "/collections/{}/members",
+                collection.collection_id
+            ))
+            .set_json(NewCollectionMember {
+                email: user.email,
+                role: COLLECTION_EDITOR.to_string(),
+            })
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(401, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_not_invite_to_other_users_collection() {
+        let (app, pool) = setup::create_app(create).await;
+
+        let (token, collection, user) = {
+            let mut conn = pool.get().unwrap();
+            let owner = data::create_user(&mut conn);
+            let collection = data::create_movie_collection(&mut conn, &owner);
+            let (token, editor) = data::create_authed_user(&mut conn);
+            data::create_collection_member(&mut conn, &collection, &editor, COLLECTION_EDITOR);
+            (token, collection, data::create_user(&mut conn))
+        };
+
+        let request = test::TestRequest::post()
+            .uri(&format!(
+                "/collections/{}/members",
+                collection.collection_id
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .set_json(NewCollectionMember {
+                email: user.email,
+                role: COLLECTION_EDITOR.to_string(),
+            })
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(404, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_not_invite_with_invalid_role() {
+        let (app, pool) = setup::create_app(create).await;
+
+        let (token, collection, user) = {
+            let mut conn = pool.get().unwrap();
+            let (token, owner) = data::create_authed_user(&mut conn);
+            let collection = data::create_movie_collection(&mut conn, &owner);
+            (token, collection, data::create_user(&mut conn))
+        };
+
+        let request = test::TestRequest::post()
+            .uri(&format!(
+                "/collections/{}/members",
+                collection.collection_id
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .set_json(NewCollectionMember {
+                email: user.email,
+                role: "owner".to_string(),
+            })
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(400, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_not_share_default_collection() {
+        let (app, pool) = setup::create_app(create).await;
+
+        let (token, collection, user) = {
+            let mut conn = pool.get().unwrap();
+            let (token, owner) = data::create_authed_user(&mut conn);
+            let collection = data::create_default_show_watchlist(&mut conn, &owner);
+            (token, collection, data::create_user(&mut conn))
+        };
+
+        let request = test::TestRequest::post()
+            .uri(&format!(
+                "/collections/{}/members",
+                collection.collection_id
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .set_json(NewCollectionMember {
+                email: user.email,
+                role: COLLECTION_EDITOR.to_string(),
+            })
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(400, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_invite_user() {
+        let (app, pool) = setup::create_app(create).await;
+
+        let (token, collection, user) = {
+            let mut conn = pool.get().unwrap();
+            let (token, owner) = data::create_authed_user(&mut conn);
+            let collection = data::create_movie_collection(&mut conn, &owner);
+            (token, collection, data::create_user(&mut conn))
+        };
+
+        let request = test::TestRequest::post()
+            .uri(&format!(
+                "/collections/{}/members",
+                collection.collection_id
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .set_json(NewCollectionMember {
+                email: user.email,
+                role: COLLECTION_EDITOR.to_string(),
+            })
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<CollectionMember>(response).await;
+
+        assert_eq!(collection.collection_id, result.data.collection_id);
+        assert_eq!(user.user_id, result.data.user_id);
+        assert_eq!(COLLECTION_EDITOR, result.data.role);
+        assert!(!result.data.accepted);
+    }
+}
+
+mod update {
+    use crate::common::{data, process, setup};
+    use actix_web::{http::header::AUTHORIZATION, test};
+    use framerate::collection_member::{
+        update, CollectionMember, UpdatedCollectionMember, COLLECTION_EDITOR, COLLECTION_VIEWER,
+    };
+
+    #[actix_web::test]
+    async fn should_not_update_as_member() {
+        let (app, pool) = setup::create_app(update).await;
+
+        let (token, collection, user) = {
+            let mut conn = pool.get().unwrap();
+            let owner = data::create_user(&mut conn);
+            let collection = data::create_movie_collection(&mut conn, &owner);
+            let (token, user) = data::create_authed_user(&mut conn);
+            data::create_collection_member(&mut conn, &collection, &user, COLLECTION_VIEWER);
+            (token, collection, user)
+        };
+
+        let request = test::TestRequest::put()
+            .uri(&format!(
+                "/collections/{}/members/{}",
+                collection.collection_id, user.user_id
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .set_json(UpdatedCollectionMember {
+                role: COLLECTION_EDITOR.to_string(),
+            })
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(404, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_update_role() {
+        let (app, pool) = setup::create_app(update).await;
+
+        let (token, collection, user) = {
+            let mut conn = pool.get().unwrap();
+            let (token, owner) = data::create_authed_user(&mut conn);
+            let collection = data::create_movie_collection(&mut conn, &owner);
+            let user = data::create_user(&mut conn);
+            data::create_collection_member(&mut conn, &collection, &user, COLLECTION_VIEWER);
+            (token, collection, user)
+        };
+
+        let request = test::TestRequest::put()
+            .uri(&format!(
+                "/collections/{}/members/{}",
+                collection.collection_id, user.user_id
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .set_json(UpdatedCollectionMember {
+                role: COLLECTION_EDITOR.to_string(),
+            })
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<CollectionMember>(response).await;
+
+        assert_eq!(COLLECTION_EDITOR, result.data.role);
+        assert!(result.data.accepted);
+    }
+}
+
+mod delete {
+    use crate::common::{data, process, setup};
+    use actix_web::{http::header::AUTHORIZATION, test};
+    use framerate::{
+        collection_member::{delete, COLLECTION_EDITOR},
+        utils::response_body::DeleteResponse,
+    };
+
+    #[actix_web::test]
+    async fn should_not_remove_other_members_as_member() {
+        let (app, pool) = setup::create_app(delete).await;
+
+        let (token, collection, other_user) = {
+            let mut conn = pool.get().unwrap();
+            let owner = data::create_user(&mut conn);
+            let collection = data::create_movie_collection(&mut conn, &owner);
+            let (token, user) = data::create_authed_user(&mut conn);
+            let other_user = data::create_user(&mut conn);
+            data::create_collection_member(&mut conn, &collection, &user, COLLECTION_EDITOR);
+            data::create_collection_member(&mut conn, &collection, &other_user, COLLECTION_EDITOR);
+            (token, collection, other_user)
+        };
+
+        let request = test::TestRequest::delete()
+            .uri(&format!(
+                "/collections/{}/members/{}",
+                collection.collection_id, other_user.user_id
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(404, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_let_member_leave() {
+        let (app, pool) = setup::create_app(delete).await;
+
+        let (token, collection, user) = {
+            let mut conn = pool.get().unwrap();
+            let owner = data::create_user(&mut conn);
+            let collection = data::create_movie_collection(&mut conn, &owner);
+            let (token, user) = data::create_authed_user(&mut conn);
+            data::create_collection_invite(&mut conn, &collection, &user, COLLECTION_EDITOR);
+            (token, collection, user)
+        };
+
+        let request = test::TestRequest::delete()
+            .uri(&format!(
+                "/collections/{}/members/{}",
+                collection.collection_id, user.user_id
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<DeleteResponse>(response).await;
+        assert_eq!(1, result.data.count);
+    }
+
+    #[actix_web::test]
+    async fn should_remove_member() {
+        let (app, pool) = setup::create_app(delete).await;
+
+        let (token, collection, user) = {
+            let mut conn = pool.get().unwrap();
+            let (token, owner) = data::create_authed_user(&mut conn);
+            let collection = data::create_movie_collection(&mut conn, &owner);
+            let user = data::create_user(&mut conn);
+            data::create_collection_member(&mut conn, &collection, &user, COLLECTION_EDITOR);
+            (token, collection, user)
+        };
+
+        let request = test::TestRequest::delete()
+            .uri(&format!(
+                "/collections/{}/members/{}",
+                collection.collection_id, user.user_id
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<DeleteResponse>(response).await;
+        assert_eq!(1, result.data.count);
+    }
+}
+
+mod find_invites {
+    use crate::common::{data, process, setup};
+    use actix_web::{http::header::AUTHORIZATION, test};
+    use framerate::collection_member::{
+        find_invites, CollectionInvite, COLLECTION_EDITOR, COLLECTION_VIEWER,
+    };
+
+    #[actix_web::test]
+    async fn should_return_pending_invites() {
+        let (app, pool) = setup::create_app(find_invites).await;
+
+        let (token, collection, owner) = {
+            let mut conn = pool.get().unwrap();
+            let owner = data::create_user(&mut conn);
+            let collection = data::create_movie_collection(&mut conn, &owner);
+            let accepted_collection = data::create_show_collection(&mut conn, &owner);
+            let (token, user) = data::create_authed_user(&mut conn);
+            data::create_collection_invite(&mut conn, &collection, &user, COLLECTION_VIEWER);
+            data::create_collection_member(
+                &mut conn,
+                &accepted_collection,
+                &user,
+                COLLECTION_EDITOR,
+            );
+            (token, collection, owner)
+        };
+
+        let request = test::TestRequest::get()
+            .uri("/collections/invites")
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<Vec<CollectionInvite>>(response).await;
+
+        assert_eq!(1, result.data.len());
+        assert_eq!(collection.collection_id, result.data[0].collection_id);
+        assert_eq!(collection.name, result.data[0].name);
+        assert_eq!(owner.user_id, result.data[0].owner_id);
+        assert_eq!(COLLECTION_VIEWER, result.data[0].role);
+    }
+}
+
+mod accept_invite {
+    use crate::common::{data, process, setup};
+    use actix_web::{http::header::AUTHORIZATION, test};
+    use framerate::collection_member::{accept_invite, CollectionMember, COLLECTION_VIEWER};
+
+    #[actix_web::test]
+    async fn should_not_accept_without_invite() {
+        let (app, pool) = setup::create_app(accept_invite).await;
+
+        let (token, collection) = {
+            let mut conn = pool.get().unwrap();
+            let owner = data::create_user(&mut conn);
+            let collection = data::create_movie_collection(&mut conn, &owner);
+            let (token, _) = data::create_authed_user(&mut conn);
+            (token, collection)
+        };
+
+        let request = test::TestRequest::put()
+            .uri(&format!(
+                "/collections/invites/{}",
+                collection.collection_id
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(404, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_accept_invite() {
+        let (app, pool) = setup::create_app(accept_invite).await;
+
+        let (token, collection) = {
+            let mut conn = pool.get().unwrap();
+            let owner = data::create_user(&mut conn);
+            let collection = data::create_movie_collection(&mut conn, &owner);
+            let (token, user) = data::create_authed_user(&mut conn);
+            data::create_collection_invite(&mut conn, &collection, &user, COLLECTION_VIEWER);
+            (token, collection)
+        };
+
+        let request = test::TestRequest::put()
+            .uri(&format!(
+                "/collections/invites/{}",
+                collection.collection_id
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<CollectionMember>(response).await;
+
+        assert_eq!(collection.collection_id, result.data.collection_id);
+        assert!(result.data.accepted);
+    }
+}
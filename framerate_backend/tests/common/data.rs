@@ -1,12 +1,15 @@
 use chrono::{NaiveDate, Utc};
-use diesel::{r2d2::ConnectionManager, PgConnection};
+use diesel::{prelude::*, r2d2::ConnectionManager, PgConnection};
 use r2d2::PooledConnection;
 use rand::Rng;
 use uuid::Uuid;
 
 use framerate::{
-    collection::{Collection, UpdatedCollection},
+    collection::{Collection, CollectionRules, UpdatedCollection, MIXED_MEDIA_TYPE},
+    collection_member::{CollectionMember, NewCollectionMember},
     company::{Company, SaveCompany},
+    episode_review::{EpisodeReview, SaveEpisodeReviewRequest},
+    feed_token::FeedToken,
     meta::ClientConfig,
     movie::{Movie, MOVIE_MEDIA_TYPE},
     movie_collection::{NewMovieCollection, SaveMovieCollectionEntryRequest},
@@ -14,13 +17,15 @@ use framerate::{
     movie_review::{MovieReview, SaveMovieReviewRequest},
     movie_watchlist::SaveMovieWatchlistEntryRequest,
     review::Review,
-    season::Season,
+    schema::users,
+    season::{Episode, Season},
     season_review::{SaveSeasonReviewRequest, SeasonReview},
     show::{ExternalIds, Show, SHOW_MEDIA_TYPE},
     show_collection::{NewShowCollection, SaveShowCollectionEntryRequest},
     show_entry::ShowEntry,
     show_review::{SaveShowReviewRequest, ShowReview},
     show_watchlist::SaveShowWatchlistEntryRequest,
+    tag::{SaveTag, Tag},
     user::{NewUser, PermissionLevel, RegisteringUser, User},
     utils::jwt::create_token,
 };
@@ -59,6 +64,31 @@ pub fn create_user(conn: &mut PooledConnection<ConnectionManager<PgConnection>>)
     User::create(conn, generate_save_new_user()).unwrap()
 }
 
+pub fn create_public_user(conn: &mut PooledConnection<ConnectionManager<PgConnection>>) -> User {
+    let user = create_user(conn);
+
+    diesel::update(users::table.filter(users::user_id.eq(user.user_id)))
+        .set(users::public.eq(true))
+        .get_result(conn)
+        .unwrap()
+}
+
+/// Sets what happens to watchlist entries when the user reviews them
+pub fn set_watchlist_on_review(
+    conn: &mut PooledConnection<ConnectionManager<PgConnection>>,
+    user: &User,
+    on_review: &str,
+) {
+    diesel::update(users::table.filter(users::user_id.eq(user.user_id)))
+        .set(users::configuration.eq(serde_json::json!({
+            "people": [],
+            "venues": [],
+            "watchlistOnReview": on_review,
+        })))
+        .execute(conn)
+        .unwrap();
+}
+
 pub fn create_default_show_watchlist(
     conn: &mut PooledConnection<ConnectionManager<PgConnection>>,
     user: &User,
@@ -85,6 +115,38 @@ pub fn create_movie_entry(
     .unwrap()
 }
 
+pub fn create_named_movie_entry(
+    conn: &mut PooledConnection<ConnectionManager<PgConnection>>,
+    user: &User,
+    collection: &Collection,
+    movie_id: i32,
+    title: &str,
+    release_date: NaiveDate,
+) -> MovieEntry {
+    let movie_entry = MovieEntry {
+        movie_id,
+        title: title.to_string(),
+        release_date: Some(release_date),
+        ..generate_movie_entry(user.user_id, collection.collection_id)
+    };
+
+    MovieEntry::create(conn, movie_entry).unwrap()
+}
+
+pub fn create_upcoming_movie_entry(
+    conn: &mut PooledConnection<ConnectionManager<PgConnection>>,
+    user: &User,
+    collection: &Collection,
+    release_date: NaiveDate,
+) -> MovieEntry {
+    let movie_entry = MovieEntry {
+        release_date: Some(release_date),
+        ..generate_movie_entry(user.user_id, collection.collection_id)
+    };
+
+    MovieEntry::create(conn, movie_entry).unwrap()
+}
+
 pub fn create_movie_collection(
     conn: &mut PooledConnection<ConnectionManager<PgConnection>>,
     user: &User,
@@ -98,6 +160,44 @@ pub fn create_show_collection(
     Collection::create(conn, generate_show_collection(user.user_id)).unwrap()
 }
 
+pub fn create_mixed_collection(
+    conn: &mut PooledConnection<ConnectionManager<PgConnection>>,
+    user: &User,
+) -> Collection {
+    let collection = Collection {
+        media_type: MIXED_MEDIA_TYPE.to_string(),
+        ..generate_movie_collection(user.user_id)
+    };
+
+    Collection::create(conn, collection).unwrap()
+}
+
+pub fn create_smart_movie_collection(
+    conn: &mut PooledConnection<ConnectionManager<PgConnection>>,
+    user: &User,
+    rules: CollectionRules,
+) -> Collection {
+    let collection = Collection {
+        rules: Some(serde_json::json!(rules)),
+        ..generate_movie_collection(user.user_id)
+    };
+
+    Collection::create(conn, collection).unwrap()
+}
+
+pub fn create_smart_show_collection(
+    conn: &mut PooledConnection<ConnectionManager<PgConnection>>,
+    user: &User,
+    rules: CollectionRules,
+) -> Collection {
+    let collection = Collection {
+        rules: Some(serde_json::json!(rules)),
+        ..generate_show_collection(user.user_id)
+    };
+
+    Collection::create(conn, collection).unwrap()
+}
+
 pub fn create_show_entry(
     conn: &mut PooledConnection<ConnectionManager<PgConnection>>,
     user: &User,
@@ -110,6 +210,38 @@ pub fn create_show_entry(
     .unwrap()
 }
 
+pub fn create_named_show_entry(
+    conn: &mut PooledConnection<ConnectionManager<PgConnection>>,
+    user: &User,
+    collection: &Collection,
+    show_id: i32,
+    name: &str,
+    first_air_date: NaiveDate,
+) -> ShowEntry {
+    let show_entry = ShowEntry {
+        show_id,
+        name: name.to_string(),
+        first_air_date: Some(first_air_date),
+        ..generate_show_entry(user.user_id, collection.collection_id)
+    };
+
+    ShowEntry::create(conn, show_entry).unwrap()
+}
+
+pub fn create_upcoming_show_entry(
+    conn: &mut PooledConnection<ConnectionManager<PgConnection>>,
+    user: &User,
+    collection: &Collection,
+    next_air_date: NaiveDate,
+) -> ShowEntry {
+    let show_entry = ShowEntry {
+        next_air_date: Some(next_air_date),
+        ..generate_show_entry(user.user_id, collection.collection_id)
+    };
+
+    ShowEntry::create(conn, show_entry).unwrap()
+}
+
 pub fn create_review(
     conn: &mut PooledConnection<ConnectionManager<PgConnection>>,
     user: &User,
@@ -117,6 +249,36 @@ pub fn create_review(
     Review::create(conn, generate_review(user.user_id)).unwrap()
 }
 
+pub fn create_described_review(
+    conn: &mut PooledConnection<ConnectionManager<PgConnection>>,
+    user: &User,
+    title: &str,
+    description: &str,
+) -> Review {
+    let review = Review {
+        title: Some(title.to_string()),
+        description: Some(description.to_string()),
+        ..generate_review(user.user_id)
+    };
+
+    Review::create(conn, review).unwrap()
+}
+
+pub fn create_dated_review(
+    conn: &mut PooledConnection<ConnectionManager<PgConnection>>,
+    user: &User,
+    date: NaiveDate,
+    rating: Option<i16>,
+) -> Review {
+    let review = Review {
+        date: Some(date),
+        rating,
+        ..generate_review(user.user_id)
+    };
+
+    Review::create(conn, review).unwrap()
+}
+
 pub fn create_movie_review(
     conn: &mut PooledConnection<ConnectionManager<PgConnection>>,
     user: &User,
@@ -125,6 +287,20 @@ pub fn create_movie_review(
     MovieReview::create(conn, generate_movie_review(user.user_id, review.review_id)).unwrap()
 }
 
+pub fn create_released_movie_review(
+    conn: &mut PooledConnection<ConnectionManager<PgConnection>>,
+    user: &User,
+    review: &Review,
+    release_date: NaiveDate,
+) -> MovieReview {
+    let movie_review = MovieReview {
+        release_date: Some(release_date),
+        ..generate_movie_review(user.user_id, review.review_id)
+    };
+
+    MovieReview::create(conn, movie_review).unwrap()
+}
+
 pub fn create_show_review(
     conn: &mut PooledConnection<ConnectionManager<PgConnection>>,
     user: &User,
@@ -133,6 +309,18 @@ pub fn create_show_review(
     ShowReview::create(conn, generate_show_review(user.user_id, review.review_id)).unwrap()
 }
 
+pub fn create_episode_review(
+    conn: &mut PooledConnection<ConnectionManager<PgConnection>>,
+    user: &User,
+    review: &Review,
+) -> EpisodeReview {
+    EpisodeReview::create(
+        conn,
+        generate_episode_review(user.user_id, review.review_id),
+    )
+    .unwrap()
+}
+
 pub fn create_season_review(
     conn: &mut PooledConnection<ConnectionManager<PgConnection>>,
     user: &User,
@@ -141,6 +329,20 @@ pub fn create_season_review(
     SeasonReview::create(conn, generate_season_review(user.user_id, review.review_id)).unwrap()
 }
 
+pub fn create_released_season_review(
+    conn: &mut PooledConnection<ConnectionManager<PgConnection>>,
+    user: &User,
+    review: &Review,
+    air_date: NaiveDate,
+) -> SeasonReview {
+    let season_review = SeasonReview {
+        air_date: Some(air_date),
+        ..generate_season_review(user.user_id, review.review_id)
+    };
+
+    SeasonReview::create(conn, season_review).unwrap()
+}
+
 pub fn create_company(
     conn: &mut PooledConnection<ConnectionManager<PgConnection>>,
     user: &User,
@@ -148,6 +350,13 @@ pub fn create_company(
     Company::create(conn, generate_save_company(), user.user_id).unwrap()
 }
 
+pub fn create_tag(
+    conn: &mut PooledConnection<ConnectionManager<PgConnection>>,
+    user: &User,
+) -> Tag {
+    Tag::create(conn, generate_save_tag(), user.user_id).unwrap()
+}
+
 pub fn create_linked_company(
     conn: &mut PooledConnection<ConnectionManager<PgConnection>>,
     user: &User,
@@ -161,6 +370,40 @@ pub fn create_linked_company(
     .unwrap()
 }
 
+pub fn create_feed_token(
+    conn: &mut PooledConnection<ConnectionManager<PgConnection>>,
+    user: &User,
+) -> FeedToken {
+    FeedToken::create(conn, user.user_id).unwrap()
+}
+
+pub fn create_collection_invite(
+    conn: &mut PooledConnection<ConnectionManager<PgConnection>>,
+    collection: &Collection,
+    user: &User,
+    role: &str,
+) -> CollectionMember {
+    CollectionMember::create(
+        conn,
+        collection,
+        NewCollectionMember {
+            email: user.email.clone(),
+            role: role.to_string(),
+        },
+    )
+    .unwrap()
+}
+
+pub fn create_collection_member(
+    conn: &mut PooledConnection<ConnectionManager<PgConnection>>,
+    collection: &Collection,
+    user: &User,
+    role: &str,
+) -> CollectionMember {
+    create_collection_invite(conn, collection, user, role);
+    CollectionMember::accept(conn, collection.collection_id, user.user_id).unwrap()
+}
+
 pub fn create_client_config(
     conn: &mut PooledConnection<ConnectionManager<PgConnection>>,
 ) -> ClientConfig {
@@ -179,6 +422,7 @@ pub fn generate_save_movie_review() -> SaveMovieReviewRequest {
         description: Some(Uuid::new_v4().to_string()),
         venue: Some(Uuid::new_v4().to_string()),
         company: None,
+        tags: None,
     }
 }
 
@@ -214,6 +458,7 @@ pub fn generate_save_show_review() -> SaveShowReviewRequest {
         description: Some(Uuid::new_v4().to_string()),
         venue: Some(Uuid::new_v4().to_string()),
         company: None,
+        tags: None,
     }
 }
 
@@ -227,30 +472,49 @@ pub fn generate_save_season_review() -> SaveSeasonReviewRequest {
         description: Some(Uuid::new_v4().to_string()),
         venue: Some(Uuid::new_v4().to_string()),
         company: None,
+        tags: None,
+    }
+}
+
+pub fn generate_save_episode_review() -> SaveEpisodeReviewRequest {
+    let mut rng = rand::thread_rng();
+
+    SaveEpisodeReviewRequest {
+        title: Some(Uuid::new_v4().to_string()),
+        date: Some(Utc::now().naive_utc().date()),
+        rating: Some(rng.gen_range(0..101)),
+        description: Some(Uuid::new_v4().to_string()),
+        venue: Some(Uuid::new_v4().to_string()),
+        company: None,
+        tags: None,
     }
 }
 
 pub fn generate_save_movie_collection() -> NewMovieCollection {
     NewMovieCollection {
         name: Uuid::new_v4().to_string(),
+        rules: None,
     }
 }
 
 pub fn generate_save_show_collection() -> NewShowCollection {
     NewShowCollection {
         name: Uuid::new_v4().to_string(),
+        rules: None,
     }
 }
 
 pub fn generate_update_movie_collection() -> UpdatedCollection {
     UpdatedCollection {
         name: Uuid::new_v4().to_string(),
+        rules: None,
     }
 }
 
 pub fn generate_update_show_collection() -> UpdatedCollection {
     UpdatedCollection {
         name: Uuid::new_v4().to_string(),
+        rules: None,
     }
 }
 
@@ -277,6 +541,12 @@ pub fn generate_save_company() -> SaveCompany {
     }
 }
 
+pub fn generate_save_tag() -> SaveTag {
+    SaveTag {
+        name: Uuid::new_v4().to_string(),
+    }
+}
+
 pub fn generate_save_linked_company(user: &User) -> SaveCompany {
     SaveCompany {
         first_name: Uuid::new_v4().to_string(),
@@ -305,7 +575,8 @@ pub fn generate_sample_movie() -> Movie {
         popularity: Some(26.13),
         runtime: Some(121),
         status: Some("Released".to_string()),
-        credits: None
+        credits: None,
+        franchise: None,
     }
 }
 
@@ -350,6 +621,16 @@ pub fn generate_sample_season() -> Season {
     }
 }
 
+pub fn generate_sample_episode() -> Episode {
+    Episode {
+        episode_number: 1,
+        name: Some("Rose".to_string()),
+        still_path: Some("/xXQ4ZrnmlNspOo6dTPHq0qBlmIb.jpg".to_string()),
+        overview: None,
+        air_date: NaiveDate::from_ymd_opt(2005, 3, 26),
+    }
+}
+
 pub fn generate_sample_media_type() -> String {
     let mut rng = rand::thread_rng();
     let media_type = if rng.gen() {
@@ -398,6 +679,7 @@ fn generate_movie_collection(user_id: Uuid) -> Collection {
         name: Uuid::new_v4().to_string(),
         media_type: MOVIE_MEDIA_TYPE.to_string(),
         default_for: None,
+        rules: None,
     }
 }
 
@@ -408,6 +690,7 @@ fn generate_show_collection(user_id: Uuid) -> Collection {
         name: Uuid::new_v4().to_string(),
         media_type: SHOW_MEDIA_TYPE.to_string(),
         default_for: None,
+        rules: None,
     }
 }
 
@@ -418,6 +701,7 @@ fn generate_default_show_watchlist(user_id: Uuid) -> Collection {
         name: Uuid::new_v4().to_string(),
         media_type: SHOW_MEDIA_TYPE.to_string(),
         default_for: Some("watchlist".to_string()),
+        rules: None,
     }
 }
 
@@ -428,6 +712,7 @@ fn generate_default_movie_watchlist(user_id: Uuid) -> Collection {
         name: Uuid::new_v4().to_string(),
         media_type: MOVIE_MEDIA_TYPE.to_string(),
         default_for: Some("watchlist".to_string()),
+        rules: None,
     }
 }
 
@@ -444,6 +729,10 @@ fn generate_movie_entry(user_id: Uuid, collection_id: Uuid) -> MovieEntry {
         release_date: movie.release_date,
         status: movie.status,
         updated_at: Utc::now().naive_utc().date(),
+        position: None,
+        added_at: Utc::now().naive_utc(),
+        note: None,
+        priority: None,
     }
 }
 
@@ -462,6 +751,10 @@ fn generate_show_entry(user_id: Uuid, collection_id: Uuid) -> ShowEntry {
         next_air_date: show.next_air_date,
         status: show.status,
         updated_at: Utc::now().naive_utc().date(),
+        position: None,
+        added_at: Utc::now().naive_utc(),
+        note: None,
+        priority: None,
     }
 }
 
@@ -479,6 +772,22 @@ fn generate_show_review(user_id: Uuid, review_id: Uuid) -> ShowReview {
     }
 }
 
+fn generate_episode_review(user_id: Uuid, review_id: Uuid) -> EpisodeReview {
+    let season = generate_sample_season();
+    let episode = generate_sample_episode();
+
+    EpisodeReview {
+        review_id,
+        user_id,
+        show_id: season.show_id,
+        season_number: season.season_number,
+        episode_number: episode.episode_number,
+        name: episode.name,
+        still_path: episode.still_path,
+        air_date: episode.air_date,
+    }
+}
+
 fn generate_season_review(user_id: Uuid, review_id: Uuid) -> SeasonReview {
     let season = generate_sample_season();
 
@@ -0,0 +1,849 @@
+pub mod common;
+
+mod find_all {
+    use crate::common::{data, process, setup};
+    use actix_web::{http::header::AUTHORIZATION, test};
+    use framerate::{episode_review::find_all, episode_review::EpisodeReviewResponse};
+
+    #[actix_web::test]
+    async fn should_require_authentication() {
+        let (app, _) = setup::create_app(find_all).await;
+
+        let request = test::TestRequest::get()
+            .uri("/shows/seasons/episodes/reviews")
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(401, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_not_return_other_users_reviews() {
+        let (app, pool) = setup::create_app(find_all).await;
+
+        let token = {
+            let mut conn = pool.get().unwrap();
+            let user = data::create_user(&mut conn);
+            let review = data::create_review(&mut conn, &user);
+            data::create_episode_review(&mut conn, &user, &review);
+            let (token, _) = data::create_authed_user(&mut conn);
+            token
+        };
+
+        let request = test::TestRequest::get()
+            .uri("/shows/seasons/episodes/reviews")
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<Vec<EpisodeReviewResponse>>(response).await;
+        assert_eq!(0, result.data.len());
+    }
+
+    #[actix_web::test]
+    async fn should_return_user_reviews() {
+        let (app, pool) = setup::create_app(find_all).await;
+
+        let (user, token, review, episode_review) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let review = data::create_review(&mut conn, &user);
+            let episode_review = data::create_episode_review(&mut conn, &user, &review);
+            (user, token, review, episode_review)
+        };
+
+        let request = test::TestRequest::get()
+            .uri("/shows/seasons/episodes/reviews")
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<Vec<EpisodeReviewResponse>>(response).await;
+        assert_eq!(1, result.data.len());
+
+        let returned_review = result.data.first().unwrap();
+        assert_eq!(review.review_id, returned_review.review_id);
+        assert_eq!(user.user_id, returned_review.user_id);
+        assert_eq!(review.rating, returned_review.rating);
+        assert_eq!(episode_review.show_id, returned_review.show_id);
+        assert_eq!(episode_review.season_number, returned_review.season_number);
+        assert_eq!(
+            episode_review.episode_number,
+            returned_review.episode.episode_number
+        );
+        assert_eq!(episode_review.name, returned_review.episode.name);
+    }
+}
+
+mod find_by_review_id {
+    use crate::common::{data, process, setup};
+    use actix_web::{http::header::AUTHORIZATION, test};
+    use framerate::episode_review::{find_by_review_id, EpisodeReviewResponse};
+
+    #[actix_web::test]
+    async fn should_require_authentication() {
+        let (app, pool) = setup::create_app(find_by_review_id).await;
+
+        let episode_review = {
+            let mut conn = pool.get().unwrap();
+            let user = data::create_user(&mut conn);
+            let review = data::create_review(&mut conn, &user);
+            data::create_episode_review(&mut conn, &user, &review)
+        };
+
+        let request = test::TestRequest::get()
+            .uri(&format!(
+                "/shows/seasons/episodes/reviews/{}",
+                episode_review.review_id
+            ))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(401, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_not_return_other_users_review() {
+        let (app, pool) = setup::create_app(find_by_review_id).await;
+
+        let (token, episode_review) = {
+            let mut conn = pool.get().unwrap();
+            let user = data::create_user(&mut conn);
+            let review = data::create_review(&mut conn, &user);
+            let episode_review = data::create_episode_review(&mut conn, &user, &review);
+            let (token, _) = data::create_authed_user(&mut conn);
+            (token, episode_review)
+        };
+
+        let request = test::TestRequest::get()
+            .uri(&format!(
+                "/shows/seasons/episodes/reviews/{}",
+                episode_review.review_id
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(404, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_return_review_details() {
+        let (app, pool) = setup::create_app(find_by_review_id).await;
+
+        let (user, token, review, episode_review) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let review = data::create_review(&mut conn, &user);
+            let episode_review = data::create_episode_review(&mut conn, &user, &review);
+            (user, token, review, episode_review)
+        };
+
+        let request = test::TestRequest::get()
+            .uri(&format!(
+                "/shows/seasons/episodes/reviews/{}",
+                review.review_id
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<EpisodeReviewResponse>(response).await;
+
+        assert_eq!(review.review_id, result.data.review_id);
+        assert_eq!(user.user_id, result.data.user_id);
+        assert_eq!(review.date, result.data.date);
+        assert_eq!(review.description, result.data.description);
+        assert_eq!(review.rating, result.data.rating);
+        assert_eq!(review.title, result.data.title);
+        assert_eq!(review.venue, result.data.venue);
+        assert_eq!(episode_review.show_id, result.data.show_id);
+        assert_eq!(episode_review.season_number, result.data.season_number);
+        assert_eq!(
+            episode_review.episode_number,
+            result.data.episode.episode_number
+        );
+        assert_eq!(episode_review.name, result.data.episode.name);
+        assert_eq!(episode_review.still_path, result.data.episode.still_path);
+        assert_eq!(episode_review.air_date, result.data.episode.air_date);
+    }
+}
+
+mod find_by_show_season_episode {
+    use crate::common::{data, process, setup};
+    use actix_web::{http::header::AUTHORIZATION, test};
+    use framerate::episode_review::{find_by_show_season_episode, EpisodeReviewResponse};
+
+    #[actix_web::test]
+    async fn should_require_authentication() {
+        let (app, pool) = setup::create_app(find_by_show_season_episode).await;
+
+        let episode_review = {
+            let mut conn = pool.get().unwrap();
+            let user = data::create_user(&mut conn);
+            let review = data::create_review(&mut conn, &user);
+            data::create_episode_review(&mut conn, &user, &review)
+        };
+
+        let request = test::TestRequest::get()
+            .uri(&format!(
+                "/shows/{}/seasons/{}/episodes/{}/reviews",
+                episode_review.show_id, episode_review.season_number, episode_review.episode_number
+            ))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(401, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_not_return_other_users_reviews() {
+        let (app, pool) = setup::create_app(find_by_show_season_episode).await;
+
+        let (token, episode_review) = {
+            let mut conn = pool.get().unwrap();
+            let user = data::create_user(&mut conn);
+            let review = data::create_review(&mut conn, &user);
+            let episode_review = data::create_episode_review(&mut conn, &user, &review);
+            let (token, _) = data::create_authed_user(&mut conn);
+            (token, episode_review)
+        };
+
+        let request = test::TestRequest::get()
+            .uri(&format!(
+                "/shows/{}/seasons/{}/episodes/{}/reviews",
+                episode_review.show_id, episode_review.season_number, episode_review.episode_number
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<Vec<EpisodeReviewResponse>>(response).await;
+        assert_eq!(0, result.data.len());
+    }
+
+    #[actix_web::test]
+    async fn should_return_review_details() {
+        let (app, pool) = setup::create_app(find_by_show_season_episode).await;
+
+        let (user, token, review, episode_review) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let review = data::create_review(&mut conn, &user);
+            let episode_review = data::create_episode_review(&mut conn, &user, &review);
+            (user, token, review, episode_review)
+        };
+
+        let request = test::TestRequest::get()
+            .uri(&format!(
+                "/shows/{}/seasons/{}/episodes/{}/reviews",
+                episode_review.show_id, episode_review.season_number, episode_review.episode_number
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<Vec<EpisodeReviewResponse>>(response).await;
+        assert_eq!(1, result.data.len());
+
+        let returned_review = result.data.first().unwrap();
+
+        assert_eq!(review.review_id, returned_review.review_id);
+        assert_eq!(user.user_id, returned_review.user_id);
+        assert_eq!(review.date, returned_review.date);
+        assert_eq!(review.description, returned_review.description);
+        assert_eq!(review.rating, returned_review.rating);
+        assert_eq!(review.title, returned_review.title);
+        assert_eq!(review.venue, returned_review.venue);
+        assert_eq!(episode_review.show_id, returned_review.show_id);
+        assert_eq!(episode_review.season_number, returned_review.season_number);
+        assert_eq!(
+            episode_review.episode_number,
+            returned_review.episode.episode_number
+        );
+        assert_eq!(episode_review.name, returned_review.episode.name);
+        assert_eq!(
+            episode_review.still_path,
+            returned_review.episode.still_path
+        );
+        assert_eq!(episode_review.air_date, returned_review.episode.air_date);
+    }
+}
+
+mod create {
+    use crate::common::{data, process, setup};
+    use actix_web::{http::header::AUTHORIZATION, test};
+    use framerate::{
+        episode_review::{create, EpisodeReview, EpisodeReviewResponse},
+        review_company::ReviewCompanySummary,
+    };
+    use uuid::Uuid;
+
+    #[actix_web::test]
+    async fn should_require_authentication() {
+        let (app, _) = setup::create_app(create).await;
+
+        let season = data::generate_sample_season();
+        let episode = data::generate_sample_episode();
+
+        let request = test::TestRequest::post()
+            .uri(&format!(
+                "/shows/{}/seasons/{}/episodes/{}/reviews",
+                season.show_id, season.season_number, episode.episode_number
+            ))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(401, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_discard_review_on_company_save_error() {
+        let (app, pool) = setup::create_app(create).await;
+
+        let (token, user) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            (token, user)
+        };
+
+        let season = data::generate_sample_season();
+        let episode = data::generate_sample_episode();
+        let review = data::generate_save_episode_review().company(vec![ReviewCompanySummary {
+            company_id: Uuid::new_v4(),
+        }]);
+
+        let request = test::TestRequest::post()
+            .uri(&format!(
+                "/shows/{}/seasons/{}/episodes/{}/reviews",
+                season.show_id, season.season_number, episode.episode_number
+            ))
+            .set_json(&review)
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_server_error());
+
+        let reviews = {
+            let mut conn = pool.get().unwrap();
+            EpisodeReview::find_by_show_season_episode(
+                &mut conn,
+                user.user_id,
+                season.show_id,
+                season.season_number,
+                episode.episode_number,
+            )
+            .unwrap()
+        };
+
+        assert_eq!(0, reviews.len());
+    }
+
+    #[actix_web::test]
+    async fn should_create_review() {
+        let (app, pool) = setup::create_app(create).await;
+
+        let (token, user) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            (token, user)
+        };
+
+        let season = data::generate_sample_season();
+        let episode = data::generate_sample_episode();
+        let review = data::generate_save_episode_review();
+
+        let request = test::TestRequest::post()
+            .uri(&format!(
+                "/shows/{}/seasons/{}/episodes/{}/reviews",
+                season.show_id, season.season_number, episode.episode_number
+            ))
+            .set_json(&review)
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<EpisodeReviewResponse>(response).await;
+
+        assert!(!result.data.review_id.is_nil());
+        assert_eq!(user.user_id, result.data.user_id);
+        assert_eq!(review.date, result.data.date);
+        assert_eq!(review.description, result.data.description);
+        assert_eq!(review.rating, result.data.rating);
+        assert_eq!(review.title, result.data.title);
+        assert_eq!(review.venue, result.data.venue);
+        assert_eq!(season.show_id, result.data.show_id);
+        assert_eq!(season.season_number, result.data.season_number);
+        assert_eq!(episode.episode_number, result.data.episode.episode_number);
+        assert_eq!(episode.name, result.data.episode.name);
+        assert_eq!(episode.air_date, result.data.episode.air_date);
+    }
+
+    #[actix_web::test]
+    async fn should_create_review_with_company() {
+        let (app, pool) = setup::create_app(create).await;
+
+        let (token, company_details) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let company_details = data::create_company(&mut conn, &user);
+            (token, company_details)
+        };
+
+        let season = data::generate_sample_season();
+        let episode = data::generate_sample_episode();
+
+        let review = data::generate_save_episode_review().company(vec![ReviewCompanySummary {
+            company_id: company_details.company_id,
+        }]);
+
+        let request = test::TestRequest::post()
+            .uri(&format!(
+                "/shows/{}/seasons/{}/episodes/{}/reviews",
+                season.show_id, season.season_number, episode.episode_number
+            ))
+            .set_json(&review)
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<EpisodeReviewResponse>(response).await;
+
+        assert!(!result.data.review_id.is_nil());
+
+        let review_company = review.company.unwrap();
+        let result_company = result.data.company.unwrap();
+
+        assert_eq!(review_company.len(), result_company.len());
+
+        let review_company = review_company.first().unwrap();
+        let result_company = result_company.first().unwrap();
+
+        assert_eq!(review_company.company_id, result_company.company_id);
+        assert_eq!(company_details.first_name, result_company.first_name);
+        assert_eq!(company_details.last_name, result_company.last_name);
+    }
+}
+
+mod update {
+    use crate::common::{data, process, setup};
+    use actix_web::{http::header::AUTHORIZATION, test};
+    use framerate::{
+        episode_review::{update, EpisodeReview, EpisodeReviewResponse},
+        review_company::{ReviewCompany, ReviewCompanySummary},
+    };
+    use uuid::Uuid;
+
+    #[actix_web::test]
+    async fn should_require_authentication() {
+        let (app, pool) = setup::create_app(update).await;
+
+        let episode_review = {
+            let mut conn = pool.get().unwrap();
+            let user = data::create_user(&mut conn);
+            let review = data::create_review(&mut conn, &user);
+            data::create_episode_review(&mut conn, &user, &review)
+        };
+
+        let updated_review = data::generate_save_episode_review();
+
+        let request = test::TestRequest::put()
+            .uri(&format!(
+                "/shows/{}/seasons/{}/episodes/{}/reviews/{}",
+                episode_review.show_id,
+                episode_review.season_number,
+                episode_review.episode_number,
+                episode_review.review_id
+            ))
+            .set_json(&updated_review)
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(401, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_not_update_other_users_review() {
+        let (app, pool) = setup::create_app(update).await;
+
+        let (token, episode_review) = {
+            let mut conn = pool.get().unwrap();
+            let user = data::create_user(&mut conn);
+            let review = data::create_review(&mut conn, &user);
+            let episode_review = data::create_episode_review(&mut conn, &user, &review);
+            let (token, _) = data::create_authed_user(&mut conn);
+            (token, episode_review)
+        };
+
+        let updated_review = data::generate_save_episode_review();
+
+        let request = test::TestRequest::put()
+            .uri(&format!(
+                "/shows/{}/seasons/{}/episodes/{}/reviews/{}",
+                episode_review.show_id,
+                episode_review.season_number,
+                episode_review.episode_number,
+                episode_review.review_id
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .set_json(&updated_review)
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(404, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_discard_review_changes_on_company_save_error() {
+        let (app, pool) = setup::create_app(update).await;
+
+        let (token, user, review, episode_review) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let review = data::create_review(&mut conn, &user);
+            let episode_review = data::create_episode_review(&mut conn, &user, &review);
+            (token, user, review, episode_review)
+        };
+
+        let updated_review =
+            data::generate_save_episode_review().company(vec![ReviewCompanySummary {
+                company_id: Uuid::new_v4(),
+            }]);
+
+        let request = test::TestRequest::put()
+            .uri(&format!(
+                "/shows/{}/seasons/{}/episodes/{}/reviews/{}",
+                episode_review.show_id,
+                episode_review.season_number,
+                episode_review.episode_number,
+                episode_review.review_id
+            ))
+            .set_json(&updated_review)
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_server_error());
+
+        let (review_response, company_response) = {
+            let mut conn = pool.get().unwrap();
+            let review =
+                EpisodeReview::find_by_review_id(&mut conn, user.user_id, episode_review.review_id)
+                    .unwrap();
+            let company =
+                ReviewCompany::find_by_review(&mut conn, episode_review.review_id).unwrap();
+            (review, company)
+        };
+
+        assert_eq!(review.date, review_response.date);
+        assert_eq!(review.description, review_response.description);
+        assert_eq!(review.rating, review_response.rating);
+        assert_eq!(review.review_id, review_response.review_id);
+        assert_eq!(review.title, review_response.title);
+        assert_eq!(review.user_id, review_response.user_id);
+        assert_eq!(review.venue, review_response.venue);
+        assert_eq!(0, company_response.len());
+    }
+
+    #[actix_web::test]
+    async fn should_update_review() {
+        let (app, pool) = setup::create_app(update).await;
+
+        let (token, review, episode_review) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let review = data::create_review(&mut conn, &user);
+            let episode_review = data::create_episode_review(&mut conn, &user, &review);
+            (token, review, episode_review)
+        };
+
+        let updated_review = data::generate_save_episode_review();
+
+        let request = test::TestRequest::put()
+            .uri(&format!(
+                "/shows/{}/seasons/{}/episodes/{}/reviews/{}",
+                episode_review.show_id,
+                episode_review.season_number,
+                episode_review.episode_number,
+                episode_review.review_id
+            ))
+            .set_json(&updated_review)
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let review_response = process::parse_body::<EpisodeReviewResponse>(response)
+            .await
+            .data;
+
+        assert_eq!(review.review_id, review_response.review_id);
+        assert_eq!(review.user_id, review_response.user_id);
+        assert_eq!(updated_review.date, review_response.date);
+        assert_eq!(updated_review.description, review_response.description);
+        assert_eq!(updated_review.rating, review_response.rating);
+        assert_eq!(updated_review.title, review_response.title);
+        assert_eq!(updated_review.venue, review_response.venue);
+    }
+
+    #[actix_web::test]
+    async fn should_update_review_company() {
+        let (app, pool) = setup::create_app(update).await;
+
+        let (token, episode_review, company_user1, company_user2) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let review = data::create_review(&mut conn, &user);
+            let episode_review = data::create_episode_review(&mut conn, &user, &review);
+            let company_user1 = data::create_company(&mut conn, &user);
+            let company_user2 = data::create_company(&mut conn, &user);
+
+            ReviewCompany::replace(
+                &mut conn,
+                episode_review.review_id,
+                Some(&vec![ReviewCompanySummary {
+                    company_id: company_user1.company_id,
+                }]),
+            )
+            .unwrap();
+
+            (token, episode_review, company_user1, company_user2)
+        };
+
+        // Add a user to review company
+        let updated_review = data::generate_save_episode_review().company(vec![
+            ReviewCompanySummary {
+                company_id: company_user1.company_id,
+            },
+            ReviewCompanySummary {
+                company_id: company_user2.company_id,
+            },
+        ]);
+
+        let request = test::TestRequest::put()
+            .uri(&format!(
+                "/shows/{}/seasons/{}/episodes/{}/reviews/{}",
+                episode_review.show_id,
+                episode_review.season_number,
+                episode_review.episode_number,
+                episode_review.review_id
+            ))
+            .set_json(&updated_review)
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+        let review_response = process::parse_body::<EpisodeReviewResponse>(response)
+            .await
+            .data;
+
+        let company = review_response.company.unwrap();
+
+        assert_eq!(&2, &company.len());
+        assert!(&company
+            .iter()
+            .find(|company| company.company_id == company_user1.company_id)
+            .is_some());
+        assert!(&company
+            .iter()
+            .find(|company| company.company_id == company_user2.company_id)
+            .is_some());
+
+        // Remove a user from review company
+        let updated_review =
+            data::generate_save_episode_review().company(vec![ReviewCompanySummary {
+                company_id: company_user2.company_id,
+            }]);
+
+        let request = test::TestRequest::put()
+            .uri(&format!(
+                "/shows/{}/seasons/{}/episodes/{}/reviews/{}",
+                episode_review.show_id,
+                episode_review.season_number,
+                episode_review.episode_number,
+                episode_review.review_id
+            ))
+            .set_json(&updated_review)
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+        let review_response = process::parse_body::<EpisodeReviewResponse>(response)
+            .await
+            .data;
+
+        let company = review_response.company.unwrap();
+
+        assert_eq!(&1, &company.len());
+        assert!(&company
+            .iter()
+            .find(|company| company.company_id == company_user2.company_id)
+            .is_some());
+
+        // Clear review company
+        let updated_review = data::generate_save_episode_review();
+
+        let request = test::TestRequest::put()
+            .uri(&format!(
+                "/shows/{}/seasons/{}/episodes/{}/reviews/{}",
+                episode_review.show_id,
+                episode_review.season_number,
+                episode_review.episode_number,
+                episode_review.review_id
+            ))
+            .set_json(&updated_review)
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+        let review_response = process::parse_body::<EpisodeReviewResponse>(response)
+            .await
+            .data;
+
+        let company = review_response.company.unwrap();
+
+        assert_eq!(&0, &company.len());
+    }
+}
+
+mod delete {
+    use crate::common::{data, process, setup};
+    use actix_web::{http::header::AUTHORIZATION, test};
+    use framerate::{
+        episode_review::{delete, EpisodeReview},
+        review_company::{ReviewCompany, ReviewCompanySummary},
+        utils::response_body::DeleteResponse,
+    };
+
+    #[actix_web::test]
+    async fn should_require_authentication() {
+        let (app, pool) = setup::create_app(delete).await;
+
+        let episode_review = {
+            let mut conn = pool.get().unwrap();
+            let user = data::create_user(&mut conn);
+            let review = data::create_review(&mut conn, &user);
+            data::create_episode_review(&mut conn, &user, &review)
+        };
+
+        let request = test::TestRequest::delete()
+            .uri(&format!(
+                "/shows/{}/seasons/{}/episodes/{}/reviews/{}",
+                episode_review.show_id,
+                episode_review.season_number,
+                episode_review.episode_number,
+                episode_review.review_id
+            ))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(401, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_not_delete_other_users_review() {
+        let (app, pool) = setup::create_app(delete).await;
+
+        let (token, user, episode_review) = {
+            let mut conn = pool.get().unwrap();
+            let user = data::create_user(&mut conn);
+            let review = data::create_review(&mut conn, &user);
+            let episode_review = data::create_episode_review(&mut conn, &user, &review);
+            let (token, _) = data::create_authed_user(&mut conn);
+            (token, user, episode_review)
+        };
+
+        let request = test::TestRequest::delete()
+            .uri(&format!(
+                "/shows/{}/seasons/{}/episodes/{}/reviews/{}",
+                episode_review.show_id,
+                episode_review.season_number,
+                episode_review.episode_number,
+                episode_review.review_id
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(404, response.status());
+
+        let existing_review = {
+            let mut conn = pool.get().unwrap();
+            EpisodeReview::find_by_review_id(&mut conn, user.user_id, episode_review.review_id)
+        };
+
+        assert!(existing_review.is_ok());
+    }
+
+    #[actix_web::test]
+    async fn should_delete_review() {
+        let (app, pool) = setup::create_app(delete).await;
+
+        let (token, user, episode_review) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let review = data::create_review(&mut conn, &user);
+            let episode_review = data::create_episode_review(&mut conn, &user, &review);
+            let company = data::create_company(&mut conn, &user);
+
+            ReviewCompany::replace(
+                &mut conn,
+                episode_review.review_id,
+                Some(&vec![ReviewCompanySummary {
+                    company_id: company.company_id,
+                }]),
+            )
+            .unwrap();
+
+            (token, user, episode_review)
+        };
+
+        let request = test::TestRequest::delete()
+            .uri(&format!(
+                "/shows/{}/seasons/{}/episodes/{}/reviews/{}",
+                episode_review.show_id,
+                episode_review.season_number,
+                episode_review.episode_number,
+                episode_review.review_id
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<DeleteResponse>(response).await;
+        assert_eq!(1, result.data.count);
+
+        let (existing_review, company) = {
+            let mut conn = pool.get().unwrap();
+            let review =
+                EpisodeReview::find_by_review_id(&mut conn, user.user_id, episode_review.review_id);
+            let company =
+                ReviewCompany::find_by_review(&mut conn, episode_review.review_id).unwrap();
+            (review, company)
+        };
+
+        assert!(existing_review.is_err());
+        assert_eq!(0, company.len());
+    }
+}
@@ -0,0 +1,354 @@
+pub mod common;
+
+mod export_data {
+    use crate::common::{data, setup};
+    use actix_web::{
+        http::header::{AUTHORIZATION, CONTENT_TYPE},
+        test,
+    };
+    use framerate::export::export_data;
+    use std::io::{Cursor, Read};
+    use zip::ZipArchive;
+
+    #[actix_web::test]
+    async fn should_require_authentication() {
+        let (app, pool) = setup::create_app(export_data).await;
+
+        let user = {
+            let mut conn = pool.get().unwrap();
+            data::create_user(&mut conn)
+        };
+
+        let request = test::TestRequest::get()
+            .uri(&format!("/users/{}/export", user.user_id))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(401, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_not_export_other_users() {
+        let (app, pool) = setup::create_app(export_data).await;
+
+        let (token, other_user) = {
+            let mut conn = pool.get().unwrap();
+            let (token, _) = data::create_authed_user(&mut conn);
+            (token, data::create_user(&mut conn))
+        };
+
+        let request = test::TestRequest::get()
+            .uri(&format!("/users/{}/export", other_user.user_id))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(404, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_export_archive() {
+        let (app, pool) = setup::create_app(export_data).await;
+
+        let (token, user) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let review = data::create_review(&mut conn, &user);
+            data::create_movie_review(&mut conn, &user, &review);
+            (token, user)
+        };
+
+        let request = test::TestRequest::get()
+            .uri(&format!("/users/{}/export", user.user_id))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+        assert_eq!(
+            "application/zip",
+            response.headers().get(CONTENT_TYPE).unwrap()
+        );
+
+        let body = test::read_body(response).await;
+        let mut archive = ZipArchive::new(Cursor::new(body.to_vec())).unwrap();
+        assert!(archive.by_name("profile.json").is_ok());
+        assert!(archive.by_name("watchlists.json").is_ok());
+
+        let mut reviews = String::new();
+        archive
+            .by_name("movie_reviews.json")
+            .unwrap()
+            .read_to_string(&mut reviews)
+            .unwrap();
+        let reviews: Vec<serde_json::Value> = serde_json::from_str(&reviews).unwrap();
+        assert_eq!(1, reviews.len());
+    }
+}
+
+mod export_letterboxd {
+    use crate::common::{data, setup};
+    use actix_web::{http::header::AUTHORIZATION, test};
+    use chrono::NaiveDate;
+    use framerate::export::export_letterboxd;
+
+    #[actix_web::test]
+    async fn should_not_export_other_users() {
+        let (app, pool) = setup::create_app(export_letterboxd).await;
+
+        let (token, other_user) = {
+            let mut conn = pool.get().unwrap();
+            let (token, _) = data::create_authed_user(&mut conn);
+            (token, data::create_user(&mut conn))
+        };
+
+        let request = test::TestRequest::get()
+            .uri(&format!("/users/{}/export/letterboxd", other_user.user_id))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(404, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_mark_rewatches() {
+        let (app, pool) = setup::create_app(export_letterboxd).await;
+
+        let (token, user) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let rewatch = data::create_dated_review(
+                &mut conn,
+                &user,
+                NaiveDate::from_ymd_opt(2024, 6, 1).unwrap(),
+                Some(85),
+            );
+            data::create_movie_review(&mut conn, &user, &rewatch);
+            let first_watch = data::create_dated_review(
+                &mut conn,
+                &user,
+                NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+                None,
+            );
+            data::create_movie_review(&mut conn, &user, &first_watch);
+            (token, user)
+        };
+
+        let request = test::TestRequest::get()
+            .uri(&format!("/users/{}/export/letterboxd", user.user_id))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let body = test::read_body(response).await;
+        let mut reader = csv::Reader::from_reader(body.as_ref());
+        assert_eq!(
+            vec![
+                "Title",
+                "Year",
+                "imdbID",
+                "tmdbID",
+                "WatchedDate",
+                "Rating10",
+                "Rewatch",
+                "Review"
+            ],
+            reader.headers().unwrap().iter().collect::<Vec<_>>()
+        );
+
+        let rows: Vec<csv::StringRecord> = reader.records().map(Result::unwrap).collect();
+        assert_eq!(2, rows.len());
+        assert_eq!("2024-01-01", &rows[0][4]);
+        assert_eq!("", &rows[0][5]);
+        assert_eq!("false", &rows[0][6]);
+        assert_eq!("2024-06-01", &rows[1][4]);
+        assert_eq!("9", &rows[1][5]);
+        assert_eq!("true", &rows[1][6]);
+    }
+}
+
+mod import_data {
+    use crate::common::{data, process, setup};
+    use actix_web::{http::header::AUTHORIZATION, test};
+    use framerate::{
+        collection::{CollectionRules, RuleSource},
+        export::{import_data, UserExport, UserImportReport},
+        review_company::{ReviewCompany, ReviewCompanySummary},
+        review_tag::{ReviewTag, ReviewTagSummary},
+        utils::archive::MAX_ARCHIVE_FILE_SIZE,
+    };
+    use std::io::{Cursor, Write};
+    use zip::{write::SimpleFileOptions, ZipWriter};
+
+    #[actix_web::test]
+    async fn should_reject_other_files() {
+        let (app, pool) = setup::create_app(import_data).await;
+
+        let (token, user) = {
+            let mut conn = pool.get().unwrap();
+            data::create_authed_user(&mut conn)
+        };
+
+        let request = test::TestRequest::post()
+            .uri(&format!("/users/{}/import", user.user_id))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .set_payload("Date,Name,Year,Letterboxd URI,Rating\n")
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(400, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_reject_files_too_large_once_decompressed() {
+        let (app, pool) = setup::create_app(import_data).await;
+
+        let (token, user) = {
+            let mut conn = pool.get().unwrap();
+            data::create_authed_user(&mut conn)
+        };
+
+        let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+        zip.start_file("manifest.json", SimpleFileOptions::default())
+            .unwrap();
+        zip.write_all(&vec![b' '; MAX_ARCHIVE_FILE_SIZE as usize + 1])
+            .unwrap();
+        let archive = zip.finish().unwrap().into_inner();
+
+        let request = test::TestRequest::post()
+            .uri(&format!("/users/{}/import", user.user_id))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .set_payload(archive)
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(413, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_import_export_from_another_user() {
+        let (app, pool) = setup::create_app(import_data).await;
+
+        let (token, user, archive) = {
+            let mut conn = pool.get().unwrap();
+            let exporting_user = data::create_user(&mut conn);
+
+            let review = data::create_review(&mut conn, &exporting_user);
+            data::create_movie_review(&mut conn, &exporting_user, &review);
+            let company = data::create_company(&mut conn, &exporting_user);
+            ReviewCompany::replace(
+                &mut conn,
+                review.review_id,
+                Some(&vec![ReviewCompanySummary {
+                    company_id: company.company_id,
+                }]),
+            )
+            .unwrap();
+            let tag = data::create_tag(&mut conn, &exporting_user);
+            ReviewTag::replace(
+                &mut conn,
+                exporting_user.user_id,
+                review.review_id,
+                Some(&vec![ReviewTagSummary { tag_id: tag.tag_id }]),
+            )
+            .unwrap();
+
+            let collection = data::create_movie_collection(&mut conn, &exporting_user);
+            data::create_movie_entry(&mut conn, &exporting_user, &collection);
+            let watchlist = data::create_default_show_watchlist(&mut conn, &exporting_user);
+            data::create_show_entry(&mut conn, &exporting_user, &watchlist);
+
+            let archive = UserExport::find(&mut conn, exporting_user.user_id)
+                .unwrap()
+                .to_zip()
+                .unwrap();
+
+            let (token, user) = data::create_authed_user(&mut conn);
+            (token, user, archive)
+        };
+
+        let request = test::TestRequest::post()
+            .uri(&format!("/users/{}/import", user.user_id))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .set_payload(archive)
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<UserImportReport>(response).await;
+        assert_eq!(1, result.data.reviews);
+        assert_eq!(1, result.data.companies);
+        assert_eq!(1, result.data.tags);
+        assert_eq!(1, result.data.collections);
+        assert_eq!(2, result.data.entries);
+
+        let mut conn = pool.get().unwrap();
+        let imported = UserExport::find(&mut conn, user.user_id).unwrap();
+        assert_eq!(1, imported.movie_reviews.len());
+        assert_eq!(1, imported.review_companies.len());
+        assert_eq!(1, imported.review_tags.len());
+        assert_eq!(1, imported.collections[0].movie_entries.len());
+        assert_eq!(1, imported.watchlists[0].show_entries.len());
+    }
+
+    #[actix_web::test]
+    async fn should_remap_smart_collection_rules() {
+        let (app, pool) = setup::create_app(import_data).await;
+
+        let (token, user, archive) = {
+            let mut conn = pool.get().unwrap();
+            let exporting_user = data::create_user(&mut conn);
+
+            let tag = data::create_tag(&mut conn, &exporting_user);
+            let collection = data::create_movie_collection(&mut conn, &exporting_user);
+            let rules = CollectionRules {
+                source: RuleSource::Entries,
+                min_rating: None,
+                company_id: None,
+                tag_id: Some(tag.tag_id),
+                collection_id: Some(collection.collection_id),
+                watchlist: false,
+                statuses: None,
+                upcoming_days: None,
+            };
+            data::create_smart_movie_collection(&mut conn, &exporting_user, rules);
+
+            let archive = UserExport::find(&mut conn, exporting_user.user_id)
+                .unwrap()
+                .to_zip()
+                .unwrap();
+
+            let (token, user) = data::create_authed_user(&mut conn);
+            (token, user, archive)
+        };
+
+        let request = test::TestRequest::post()
+            .uri(&format!("/users/{}/import", user.user_id))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .set_payload(archive)
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let mut conn = pool.get().unwrap();
+        let imported = UserExport::find(&mut conn, user.user_id).unwrap();
+        let (smart, static_collections): (Vec<_>, Vec<_>) = imported
+            .collections
+            .iter()
+            .map(|exported| &exported.collection)
+            .partition(|collection| collection.rules.is_some());
+
+        let rules: CollectionRules =
+            serde_json::from_value(smart[0].rules.clone().unwrap()).unwrap();
+        assert_eq!(Some(imported.tags[0].tag_id), rules.tag_id);
+        assert_eq!(
+            Some(static_collections[0].collection_id),
+            rules.collection_id
+        );
+    }
+}
@@ -0,0 +1,128 @@
+pub mod common;
+
+mod find {
+    use crate::common::{data, process, setup};
+    use actix_web::{http::header::AUTHORIZATION, test};
+    use framerate::feed_token::{find, FeedToken};
+
+    #[actix_web::test]
+    async fn should_return_not_found_without_token() {
+        let (app, pool) = setup::create_app(find).await;
+
+        let token = {
+            let mut conn = pool.get().unwrap();
+            let (token, _) = data::create_authed_user(&mut conn);
+            token
+        };
+
+        let request = test::TestRequest::get()
+            .uri("/feed-token")
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(404, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_find_feed_token() {
+        let (app, pool) = setup::create_app(find).await;
+
+        let (token, feed_token) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            (token, data::create_feed_token(&mut conn, &user))
+        };
+
+        let request = test::TestRequest::get()
+            .uri("/feed-token")
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<FeedToken>(response).await;
+        assert_eq!(feed_token.token, result.data.token);
+    }
+}
+
+mod create {
+    use crate::common::{data, process, setup};
+    use actix_web::{http::header::AUTHORIZATION, test};
+    use framerate::feed_token::{create, FeedToken};
+
+    #[actix_web::test]
+    async fn should_require_authentication() {
+        let (app, _) = setup::create_app(create).await;
+
+        let request = test::TestRequest::post().uri("/feed-token").to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(401, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_replace_existing_token() {
+        let (app, pool) = setup::create_app(create).await;
+
+        let (token, user, feed_token) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let feed_token = data::create_feed_token(&mut conn, &user);
+            (token, user, feed_token)
+        };
+
+        let request = test::TestRequest::post()
+            .uri("/feed-token")
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<FeedToken>(response).await;
+        assert_ne!(feed_token.token, result.data.token);
+
+        let mut conn = pool.get().unwrap();
+        assert!(FeedToken::find_user_id(&mut conn, feed_token.token).is_err());
+        assert_eq!(
+            user.user_id,
+            FeedToken::find_user_id(&mut conn, result.data.token).unwrap()
+        );
+    }
+}
+
+mod delete {
+    use crate::common::{data, process, setup};
+    use actix_web::{http::header::AUTHORIZATION, test};
+    use framerate::{
+        feed_token::{delete, FeedToken},
+        utils::response_body::DeleteResponse,
+    };
+
+    #[actix_web::test]
+    async fn should_revoke_token() {
+        let (app, pool) = setup::create_app(delete).await;
+
+        let (token, feed_token) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            (token, data::create_feed_token(&mut conn, &user))
+        };
+
+        let request = test::TestRequest::delete()
+            .uri("/feed-token")
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<DeleteResponse>(response).await;
+        assert_eq!(1, result.data.count);
+
+        let mut conn = pool.get().unwrap();
+        assert!(FeedToken::find_user_id(&mut conn, feed_token.token).is_err());
+    }
+}
@@ -0,0 +1,470 @@
+pub mod common;
+
+mod import_letterboxd {
+    use crate::common::{data, process, setup};
+    use actix_web::{http::header::AUTHORIZATION, test};
+    use chrono::NaiveDate;
+    use framerate::{
+        import::{import_letterboxd, ImportReport},
+        movie_review::MovieReview,
+    };
+
+    #[actix_web::test]
+    async fn should_require_authentication() {
+        let (app, _) = setup::create_app(import_letterboxd).await;
+
+        let request = test::TestRequest::post()
+            .uri("/reviews/import/letterboxd")
+            .set_payload("Date,Name,Year,Letterboxd URI,Rating\n")
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(401, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_reject_other_files() {
+        let (app, pool) = setup::create_app(import_letterboxd).await;
+
+        let token = {
+            let mut conn = pool.get().unwrap();
+            let (token, _) = data::create_authed_user(&mut conn);
+            token
+        };
+
+        let request = test::TestRequest::post()
+            .uri("/reviews/import/letterboxd")
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .set_payload("Const,Your Rating,Date Rated\ntt0425112,9,2024-01-01\n")
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(400, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_report_invalid_rows() {
+        let (app, pool) = setup::create_app(import_letterboxd).await;
+
+        let token = {
+            let mut conn = pool.get().unwrap();
+            let (token, _) = data::create_authed_user(&mut conn);
+            token
+        };
+
+        let file = "Date,Name,Year,Letterboxd URI,Rating\n\
+            2024-01-01,Hot Fuzz,2007,https://boxd.it/29Ok,4.25\n\
+            not a date,Hot Fuzz,2007,https://boxd.it/29Ok,4\n";
+
+        let request = test::TestRequest::post()
+            .uri("/reviews/import/letterboxd")
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .set_payload(file)
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<ImportReport>(response).await;
+        assert_eq!(0, result.data.imported);
+        assert_eq!(2, result.data.invalid.len());
+        assert_eq!(2, result.data.invalid[0].line);
+        assert_eq!(Some("Hot Fuzz".to_string()), result.data.invalid[0].title);
+        assert_eq!(3, result.data.invalid[1].line);
+    }
+
+    #[actix_web::test]
+    async fn should_import_diary() {
+        let (app, pool) = setup::create_app(import_letterboxd).await;
+
+        let (token, user) = {
+            let mut conn = pool.get().unwrap();
+            data::create_authed_user(&mut conn)
+        };
+
+        let file = "Date,Name,Year,Letterboxd URI,Rating,Rewatch,Tags,Watched Date\n\
+            2024-02-03,Hot Fuzz,2007,https://boxd.it/29Ok,4.5,,,2024-02-01\n\
+            2024-02-04,Not A Real Movie Title At All,1901,https://boxd.it/0,3,,,2024-02-04\n";
+
+        let request = test::TestRequest::post()
+            .uri("/reviews/import/letterboxd")
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .set_payload(file)
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<ImportReport>(response).await;
+        assert_eq!(1, result.data.imported);
+        assert_eq!(1, result.data.unmatched.len());
+        assert_eq!(3, result.data.unmatched[0].line);
+
+        let mut conn = pool.get().unwrap();
+        let reviews = MovieReview::find_by_movie_id(&mut conn, user.user_id, 4638).unwrap();
+        assert_eq!(1, reviews.len());
+        assert_eq!(Some(90), reviews[0].rating);
+        assert_eq!(NaiveDate::from_ymd_opt(2024, 2, 1), reviews[0].date);
+    }
+}
+
+mod import_imdb {
+    use crate::common::{data, process, setup};
+    use actix_web::{http::header::AUTHORIZATION, test};
+    use chrono::NaiveDate;
+    use framerate::{
+        import::{import_imdb, ImportReport},
+        movie_review::MovieReview,
+        show_review::ShowReview,
+    };
+
+    const RATINGS: &str = "Const,Your Rating,Date Rated,Title,URL,Title Type,IMDb Rating,Runtime (mins),Year,Genres,Num Votes,Release Date,Directors\n\
+        tt0425112,9,2024-03-01,Hot Fuzz,https://www.imdb.com/title/tt0425112,movie,7.8,121,2007,\"Action, Comedy\",560000,2007-02-14,Edgar Wright\n\
+        tt0436992,8,2024-03-02,Doctor Who,https://www.imdb.com/title/tt0436992,tvSeries,8.6,45,2005,\"Adventure, Drama\",260000,2005-03-26,\n";
+
+    #[actix_web::test]
+    async fn should_require_authentication() {
+        let (app, _) = setup::create_app(import_imdb).await;
+
+        let request = test::TestRequest::post()
+            .uri("/reviews/import/imdb")
+            .set_payload(RATINGS)
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(401, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_reject_other_files() {
+        let (app, pool) = setup::create_app(import_imdb).await;
+
+        let token = {
+            let mut conn = pool.get().unwrap();
+            let (token, _) = data::create_authed_user(&mut conn);
+            token
+        };
+
+        let request = test::TestRequest::post()
+            .uri("/reviews/import/imdb")
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .set_payload("Date,Name,Year,Letterboxd URI,Rating\n")
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(400, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_report_invalid_rows() {
+        let (app, pool) = setup::create_app(import_imdb).await;
+
+        let token = {
+            let mut conn = pool.get().unwrap();
+            let (token, _) = data::create_authed_user(&mut conn);
+            token
+        };
+
+        let file = "Const,Your Rating,Date Rated,Title\n\
+            tt0425112,11,2024-03-01,Hot Fuzz\n";
+
+        let request = test::TestRequest::post()
+            .uri("/reviews/import/imdb")
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .set_payload(file)
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<ImportReport>(response).await;
+        assert_eq!(0, result.data.imported);
+        assert_eq!(1, result.data.invalid.len());
+        assert_eq!(Some("Hot Fuzz".to_string()), result.data.invalid[0].title);
+    }
+
+    #[actix_web::test]
+    async fn should_import_movies_and_shows() {
+        let (app, pool) = setup::create_app(import_imdb).await;
+
+        let (token, user) = {
+            let mut conn = pool.get().unwrap();
+            data::create_authed_user(&mut conn)
+        };
+
+        let request = test::TestRequest::post()
+            .uri("/reviews/import/imdb")
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .set_payload(RATINGS)
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<ImportReport>(response).await;
+        assert_eq!(2, result.data.imported);
+
+        let mut conn = pool.get().unwrap();
+        let movie_reviews = MovieReview::find_by_movie_id(&mut conn, user.user_id, 4638).unwrap();
+        assert_eq!(1, movie_reviews.len());
+        assert_eq!(Some(90), movie_reviews[0].rating);
+        assert_eq!(NaiveDate::from_ymd_opt(2024, 3, 1), movie_reviews[0].date);
+
+        let show_reviews = ShowReview::find_by_show_id(&mut conn, user.user_id, 57243).unwrap();
+        assert_eq!(1, show_reviews.len());
+        assert_eq!(Some(80), show_reviews[0].rating);
+    }
+
+    #[actix_web::test]
+    async fn should_not_duplicate_reviews() {
+        let (app, pool) = setup::create_app(import_imdb).await;
+
+        let token = {
+            let mut conn = pool.get().unwrap();
+            let (token, _) = data::create_authed_user(&mut conn);
+            token
+        };
+
+        let request = test::TestRequest::post()
+            .uri("/reviews/import/imdb")
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .set_payload(RATINGS)
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let request = test::TestRequest::post()
+            .uri("/reviews/import/imdb")
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .set_payload(RATINGS)
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<ImportReport>(response).await;
+        assert_eq!(0, result.data.imported);
+        assert_eq!(2, result.data.skipped);
+    }
+}
+
+mod import_trakt {
+    use crate::common::{data, process, setup};
+    use actix_web::{http::header::AUTHORIZATION, test};
+    use chrono::NaiveDate;
+    use framerate::{
+        collection::{Collection, DEFAULT_WATCHLIST},
+        episode_review::EpisodeReview,
+        import::{import_trakt, ImportReport},
+        movie::MOVIE_MEDIA_TYPE,
+        movie_entry::MovieEntry,
+        movie_review::MovieReview,
+        season_review::SeasonReview,
+        utils::archive::MAX_ARCHIVE_FILE_SIZE,
+    };
+    use std::io::{Cursor, Write};
+    use zip::{write::SimpleFileOptions, ZipWriter};
+
+    const EXPORT: [(&str, &str); 6] = [
+        (
+            "watched-history.json",
+            include_str!("fixtures/trakt/watched-history.json"),
+        ),
+        (
+            "ratings-movies.json",
+            include_str!("fixtures/trakt/ratings-movies.json"),
+        ),
+        (
+            "ratings-seasons.json",
+            include_str!("fixtures/trakt/ratings-seasons.json"),
+        ),
+        (
+            "watchlist-movies.json",
+            include_str!("fixtures/trakt/watchlist-movies.json"),
+        ),
+        (
+            "watchlist-shows.json",
+            include_str!("fixtures/trakt/watchlist-shows.json"),
+        ),
+        (
+            "lists-lists.json",
+            include_str!("fixtures/trakt/lists-lists.json"),
+        ),
+    ];
+
+    fn to_zip(files: &[(&str, &str)]) -> Vec<u8> {
+        let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+        for (name, contents) in files {
+            zip.start_file(*name, SimpleFileOptions::default()).unwrap();
+            zip.write_all(contents.as_bytes()).unwrap();
+        }
+        zip.finish().unwrap().into_inner()
+    }
+
+    #[actix_web::test]
+    async fn should_require_authentication() {
+        let (app, _) = setup::create_app(import_trakt).await;
+
+        let request = test::TestRequest::post()
+            .uri("/reviews/import/trakt")
+            .set_payload(to_zip(&EXPORT))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(401, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_reject_other_files() {
+        let (app, pool) = setup::create_app(import_trakt).await;
+
+        let token = {
+            let mut conn = pool.get().unwrap();
+            let (token, _) = data::create_authed_user(&mut conn);
+            token
+        };
+
+        let request = test::TestRequest::post()
+            .uri("/reviews/import/trakt")
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .set_payload("Date,Name,Year,Letterboxd URI,Rating\n")
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(400, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_reject_files_too_large_once_decompressed() {
+        let (app, pool) = setup::create_app(import_trakt).await;
+
+        let token = {
+            let mut conn = pool.get().unwrap();
+            let (token, _) = data::create_authed_user(&mut conn);
+            token
+        };
+
+        let padding = " ".repeat(MAX_ARCHIVE_FILE_SIZE as usize + 1);
+        let history = format!("[{padding}]");
+
+        let request = test::TestRequest::post()
+            .uri("/reviews/import/trakt")
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .set_payload(to_zip(&[("watched-history.json", &history)]))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(413, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_reject_archives_without_trakt_lists() {
+        let (app, pool) = setup::create_app(import_trakt).await;
+
+        let token = {
+            let mut conn = pool.get().unwrap();
+            let (token, _) = data::create_authed_user(&mut conn);
+            token
+        };
+
+        let request = test::TestRequest::post()
+            .uri("/reviews/import/trakt")
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .set_payload(to_zip(&[("manifest.json", "{}")]))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(400, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_report_unmatched_and_invalid_items() {
+        let (app, pool) = setup::create_app(import_trakt).await;
+
+        let token = {
+            let mut conn = pool.get().unwrap();
+            let (token, _) = data::create_authed_user(&mut conn);
+            token
+        };
+
+        let file = to_zip(&[
+            (
+                "watched-history.json",
+                r#"[{"id": 9604812731, "watched_at": "2024-03-01T21:04:00.000Z", "action": "watch", "type": "movie", "movie": {"title": "Hot Fuzz", "year": 2007, "ids": {"trakt": 414, "slug": "hot-fuzz-2007", "imdb": null, "tmdb": null}}}]"#,
+            ),
+            (
+                "ratings-movies.json",
+                r#"[{"rated_at": "2024-03-03T10:00:00.000Z", "rating": 11, "type": "movie", "movie": {"title": "Hot Fuzz", "year": 2007, "ids": {"trakt": 414, "tmdb": 4638}}}]"#,
+            ),
+            (
+                "watchlist-episodes.json",
+                r#"[{"rank": 1, "id": 1154093852, "listed_at": "2024-03-05T10:00:00.000Z", "notes": null, "type": "episode", "episode": {"season": 1, "number": 1, "title": "Rose", "ids": {"trakt": 73482}}, "show": {"title": "Doctor Who", "year": 2005, "ids": {"trakt": 56, "tmdb": 57243}}}]"#,
+            ),
+        ]);
+
+        let request = test::TestRequest::post()
+            .uri("/reviews/import/trakt")
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .set_payload(file)
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<ImportReport>(response).await;
+        assert_eq!(0, result.data.imported);
+        assert_eq!(1, result.data.unmatched.len());
+        assert_eq!(1, result.data.unmatched[0].line);
+        assert_eq!(Some("Hot Fuzz".to_string()), result.data.unmatched[0].title);
+        assert_eq!(2, result.data.invalid.len());
+        assert_eq!(2, result.data.invalid[0].line);
+        assert_eq!(3, result.data.invalid[1].line);
+    }
+
+    #[actix_web::test]
+    async fn should_import_history_ratings_and_watchlist() {
+        let (app, pool) = setup::create_app(import_trakt).await;
+
+        let (token, user) = {
+            let mut conn = pool.get().unwrap();
+            data::create_authed_user(&mut conn)
+        };
+
+        let request = test::TestRequest::post()
+            .uri("/reviews/import/trakt")
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .set_payload(to_zip(&EXPORT))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<ImportReport>(response).await;
+        assert_eq!(3, result.data.imported);
+        assert_eq!(1, result.data.watchlisted);
+
+        let mut conn = pool.get().unwrap();
+        let movie_reviews = MovieReview::find_by_movie_id(&mut conn, user.user_id, 4638).unwrap();
+        assert_eq!(1, movie_reviews.len());
+        assert_eq!(Some(90), movie_reviews[0].rating);
+        assert_eq!(NaiveDate::from_ymd_opt(2024, 3, 1), movie_reviews[0].date);
+
+        let episode_reviews =
+            EpisodeReview::find_by_show_season_episode(&mut conn, user.user_id, 57243, 1, 1)
+                .unwrap();
+        assert_eq!(1, episode_reviews.len());
+        assert_eq!(NaiveDate::from_ymd_opt(2024, 3, 2), episode_reviews[0].date);
+
+        let season_reviews =
+            SeasonReview::find_by_show_season(&mut conn, user.user_id, 57243, 1).unwrap();
+        assert_eq!(1, season_reviews.len());
+        assert_eq!(Some(80), season_reviews[0].rating);
+
+        let watchlist =
+            Collection::find_default(&mut conn, user.user_id, MOVIE_MEDIA_TYPE, DEFAULT_WATCHLIST)
+                .unwrap();
+        let entry = MovieEntry::find(&mut conn, user.user_id, watchlist.collection_id, 107985);
+        assert!(entry.is_ok());
+    }
+}
@@ -0,0 +1,908 @@
+pub mod common;
+
+mod find_all {
+    use crate::common::{data, process, setup};
+    use actix_web::{http::header::AUTHORIZATION, test};
+    use framerate::mixed_collection::{find_all, MixedCollection};
+
+    #[actix_web::test]
+    async fn should_require_authentication() {
+        let (app, _) = setup::create_app(find_all).await;
+
+        let request = test::TestRequest::get()
+            .uri("/mixed/collections")
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(401, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_return_user_mixed_collections() {
+        let (app, pool) = setup::create_app(find_all).await;
+
+        let (token, collection) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let collection = data::create_mixed_collection(&mut conn, &user);
+            data::create_movie_collection(&mut conn, &user);
+            data::create_show_collection(&mut conn, &user);
+            (token, collection)
+        };
+
+        let request = test::TestRequest::get()
+            .uri("/mixed/collections")
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<Vec<MixedCollection>>(response).await;
+
+        assert_eq!(1, result.data.len());
+        assert_eq!(collection.collection_id, result.data[0].collection_id);
+    }
+}
+
+mod find {
+    use crate::common::{data, process, setup};
+    use actix_web::{http::header::AUTHORIZATION, test};
+    use chrono::NaiveDate;
+    use framerate::mixed_collection::{find, MixedCollection, MixedEntry};
+    use framerate::movie_entry::MovieEntry;
+    use framerate::show_entry::ShowEntry;
+
+    #[actix_web::test]
+    async fn should_require_authentication() {
+        let (app, pool) = setup::create_app(find).await;
+
+        let collection = {
+            let mut conn = pool.get().unwrap();
+            let user = data::create_user(&mut conn);
+            data::create_mixed_collection(&mut conn, &user)
+        };
+
+        let request = test::TestRequest::get()
+            .uri(&format!("/mixed/collections/{}", collection.collection_id))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(401, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_not_return_other_users_mixed_collection() {
+        let (app, pool) = setup::create_app(find).await;
+
+        let (token, collection) = {
+            let mut conn = pool.get().unwrap();
+            let user = data::create_user(&mut conn);
+            let collection = data::create_mixed_collection(&mut conn, &user);
+            let (token, _) = data::create_authed_user(&mut conn);
+            (token, collection)
+        };
+
+        let request = test::TestRequest::get()
+            .uri(&format!("/mixed/collections/{}", collection.collection_id))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(404, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_not_return_movie_collection() {
+        let (app, pool) = setup::create_app(find).await;
+
+        let (token, collection) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let collection = data::create_movie_collection(&mut conn, &user);
+            (token, collection)
+        };
+
+        let request = test::TestRequest::get()
+            .uri(&format!("/mixed/collections/{}", collection.collection_id))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(404, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_return_movie_and_show_entries() {
+        let (app, pool) = setup::create_app(find).await;
+
+        let (token, collection, movie_entry, show_entry) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let collection = data::create_mixed_collection(&mut conn, &user);
+            let movie_entry = data::create_named_movie_entry(
+                &mut conn,
+                &user,
+                &collection,
+                1893,
+                "Star Wars: Episode I - The Phantom Menace",
+                NaiveDate::from_ymd_opt(1999, 5, 19).unwrap(),
+            );
+            let show_entry = data::create_named_show_entry(
+                &mut conn,
+                &user,
+                &collection,
+                4194,
+                "Star Wars: The Clone Wars",
+                NaiveDate::from_ymd_opt(2008, 10, 3).unwrap(),
+            );
+            (token, collection, movie_entry, show_entry)
+        };
+
+        let request = test::TestRequest::get()
+            .uri(&format!("/mixed/collections/{}", collection.collection_id))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<MixedCollection>(response).await;
+
+        assert_eq!(
+            Some(vec![
+                MixedEntry::Show(show_entry),
+                MixedEntry::Movie(movie_entry)
+            ]),
+            result.data.entries
+        );
+    }
+
+    #[actix_web::test]
+    async fn should_sort_by_position() {
+        let (app, pool) = setup::create_app(find).await;
+
+        let (token, collection) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let collection = data::create_mixed_collection(&mut conn, &user);
+            data::create_movie_entry(&mut conn, &user, &collection);
+            let show_entry = data::create_show_entry(&mut conn, &user, &collection);
+            ShowEntry::reorder(&mut conn, collection.collection_id, &[show_entry.show_id]).unwrap();
+            (token, collection)
+        };
+
+        let request = test::TestRequest::get()
+            .uri(&format!(
+                "/mixed/collections/{}?sort=position",
+                collection.collection_id
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<serde_json::Value>(response).await;
+
+        assert_eq!("show", result.data["entries"][0]["mediaType"]);
+        assert_eq!(0, result.data["entries"][0]["position"]);
+        assert_eq!("movie", result.data["entries"][1]["mediaType"]);
+    }
+
+    #[actix_web::test]
+    async fn should_sort_like_single_media_collections() {
+        let (app, pool) = setup::create_app(find).await;
+
+        let (token, collection) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let collection = data::create_mixed_collection(&mut conn, &user);
+            let movie_entry = data::create_movie_entry(&mut conn, &user, &collection);
+            MovieEntry::create(
+                &mut conn,
+                MovieEntry {
+                    movie_id: 10074,
+                    title: "hot Rod".to_string(),
+                    release_date: None,
+                    ..movie_entry
+                },
+            )
+            .unwrap();
+            data::create_named_show_entry(
+                &mut conn,
+                &user,
+                &collection,
+                57243,
+                "doctor Who",
+                NaiveDate::from_ymd_opt(2005, 3, 26).unwrap(),
+            );
+            (token, collection)
+        };
+
+        for (sort, expected) in [
+            ("releaseDate", ["Hot Fuzz", "doctor Who", "hot Rod"]),
+            ("title", ["Hot Fuzz", "doctor Who", "hot Rod"]),
+        ] {
+            let request = test::TestRequest::get()
+                .uri(&format!(
+                    "/mixed/collections/{}?sort={sort}",
+                    collection.collection_id
+                ))
+                .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+                .to_request();
+
+            let response = test::call_service(&app, request).await;
+            assert!(response.status().is_success());
+
+            let result = process::parse_body::<MixedCollection>(response).await;
+            let titles: Vec<String> = result
+                .data
+                .entries
+                .unwrap()
+                .into_iter()
+                .map(|entry| match entry {
+                    MixedEntry::Movie(movie_entry) => movie_entry.title,
+                    MixedEntry::Show(show_entry) => show_entry.name,
+                })
+                .collect();
+
+            assert_eq!(expected.to_vec(), titles, "{sort}");
+        }
+    }
+
+    #[actix_web::test]
+    async fn should_return_media_type_of_entries() {
+        let (app, pool) = setup::create_app(find).await;
+
+        let (token, collection) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let collection = data::create_mixed_collection(&mut conn, &user);
+            data::create_movie_entry(&mut conn, &user, &collection);
+            data::create_show_entry(&mut conn, &user, &collection);
+            (token, collection)
+        };
+
+        let request = test::TestRequest::get()
+            .uri(&format!(
+                "/mixed/collections/{}?sort=title",
+                collection.collection_id
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<serde_json::Value>(response).await;
+
+        assert_eq!("show", result.data["entries"][0]["mediaType"]);
+        assert_eq!("Doctor Who", result.data["entries"][0]["name"]);
+        assert_eq!("movie", result.data["entries"][1]["mediaType"]);
+        assert_eq!("Hot Fuzz", result.data["entries"][1]["title"]);
+    }
+}
+
+mod create {
+    use crate::common::{data, process, setup};
+    use actix_web::{http::header::AUTHORIZATION, test};
+    use framerate::mixed_collection::{create, MixedCollection, NewMixedCollection};
+
+    #[actix_web::test]
+    async fn should_require_authentication() {
+        let (app, _) = setup::create_app(create).await;
+
+        let request = test::TestRequest::post()
+            .uri("/mixed/collections")
+            .set_json(NewMixedCollection {
+                name: "Star Wars".to_string(),
+            })
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(401, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_create_mixed_collection() {
+        let (app, pool) = setup::create_app(create).await;
+
+        let (token, user) = {
+            let mut conn = pool.get().unwrap();
+            data::create_authed_user(&mut conn)
+        };
+
+        let request = test::TestRequest::post()
+            .uri("/mixed/collections")
+            .set_json(NewMixedCollection {
+                name: "Star Wars".to_string(),
+            })
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<MixedCollection>(response).await;
+
+        assert_eq!("Star Wars", result.data.name);
+        assert_eq!(user.user_id, result.data.user_id);
+    }
+}
+
+mod update {
+    use crate::common::{data, process, setup};
+    use actix_web::{http::header::AUTHORIZATION, test};
+    use framerate::collection::UpdatedCollection;
+    use framerate::mixed_collection::{update, MixedCollection};
+
+    #[actix_web::test]
+    async fn should_update_mixed_collection() {
+        let (app, pool) = setup::create_app(update).await;
+
+        let (token, collection) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let collection = data::create_mixed_collection(&mut conn, &user);
+            (token, collection)
+        };
+
+        let request = test::TestRequest::put()
+            .uri(&format!("/mixed/collections/{}", collection.collection_id))
+            .set_json(UpdatedCollection {
+                name: "Star Wars".to_string(),
+                rules: None,
+            })
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<MixedCollection>(response).await;
+
+        assert_eq!(collection.collection_id, result.data.collection_id);
+        assert_eq!("Star Wars", result.data.name);
+    }
+}
+
+mod delete {
+    use crate::common::{data, process, setup};
+    use actix_web::{http::header::AUTHORIZATION, test};
+    use framerate::{mixed_collection::delete, utils::response_body::DeleteResponse};
+
+    #[actix_web::test]
+    async fn should_delete_mixed_collection() {
+        let (app, pool) = setup::create_app(delete).await;
+
+        let (token, collection) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let collection = data::create_mixed_collection(&mut conn, &user);
+            (token, collection)
+        };
+
+        let request = test::TestRequest::delete()
+            .uri(&format!("/mixed/collections/{}", collection.collection_id))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<DeleteResponse>(response).await;
+
+        assert_eq!(1, result.data.count);
+    }
+}
+
+mod create_entry {
+    use crate::common::{data, process, setup};
+    use actix_web::{http::header::AUTHORIZATION, test};
+    use framerate::mixed_collection::{create_entry, MixedEntry, SaveMixedCollectionEntryRequest};
+
+    #[actix_web::test]
+    async fn should_require_authentication() {
+        let (app, pool) = setup::create_app(create_entry).await;
+
+        let collection = {
+            let mut conn = pool.get().unwrap();
+            let user = data::create_user(&mut conn);
+            data::create_mixed_collection(&mut conn, &user)
+        };
+
+        let request = test::TestRequest::post()
+            .uri(&format!("/mixed/collections/{}", collection.collection_id))
+            .set_json(SaveMixedCollectionEntryRequest {
+                media_type: "movie".to_string(),
+                media_id: data::generate_sample_movie().id,
+            })
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(401, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_not_create_entry_with_invalid_media_type() {
+        let (app, pool) = setup::create_app(create_entry).await;
+
+        let (token, collection) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let collection = data::create_mixed_collection(&mut conn, &user);
+            (token, collection)
+        };
+
+        let request = test::TestRequest::post()
+            .uri(&format!("/mixed/collections/{}", collection.collection_id))
+            .set_json(SaveMixedCollectionEntryRequest {
+                media_type: "season".to_string(),
+                media_id: 1,
+            })
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(400, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_create_show_entry() {
+        let (app, pool) = setup::create_app(create_entry).await;
+
+        let (token, collection) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let collection = data::create_mixed_collection(&mut conn, &user);
+            (token, collection)
+        };
+
+        let show = data::generate_sample_show();
+
+        let request = test::TestRequest::post()
+            .uri(&format!("/mixed/collections/{}", collection.collection_id))
+            .set_json(SaveMixedCollectionEntryRequest {
+                media_type: "show".to_string(),
+                media_id: show.id,
+            })
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<MixedEntry>(response).await;
+
+        let MixedEntry::Show(show_entry) = result.data else {
+            panic!("Expected a show entry");
+        };
+        assert_eq!(collection.collection_id, show_entry.collection_id);
+        assert_eq!(show.id, show_entry.show_id);
+        assert_eq!(show.name, show_entry.name);
+    }
+}
+
+mod delete_entry {
+    use crate::common::{data, process, setup};
+    use actix_web::{http::header::AUTHORIZATION, test};
+    use chrono::NaiveDate;
+    use framerate::{
+        collection::EntrySort, mixed_collection::delete_entry, movie_entry::MovieEntry,
+        utils::response_body::DeleteResponse,
+    };
+
+    #[actix_web::test]
+    async fn should_not_delete_other_users_entry() {
+        let (app, pool) = setup::create_app(delete_entry).await;
+
+        let (token, show_entry) = {
+            let mut conn = pool.get().unwrap();
+            let user = data::create_user(&mut conn);
+            let collection = data::create_mixed_collection(&mut conn, &user);
+            let show_entry = data::create_show_entry(&mut conn, &user, &collection);
+            let (token, _) = data::create_authed_user(&mut conn);
+            (token, show_entry)
+        };
+
+        let request = test::TestRequest::delete()
+            .uri(&format!(
+                "/mixed/collections/{}/show/{}",
+                show_entry.collection_id, show_entry.show_id
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(404, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_not_delete_entry_with_invalid_media_type() {
+        let (app, pool) = setup::create_app(delete_entry).await;
+
+        let (token, show_entry) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let collection = data::create_mixed_collection(&mut conn, &user);
+            let show_entry = data::create_show_entry(&mut conn, &user, &collection);
+            (token, show_entry)
+        };
+
+        let request = test::TestRequest::delete()
+            .uri(&format!(
+                "/mixed/collections/{}/season/{}",
+                show_entry.collection_id, show_entry.show_id
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(400, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_delete_only_entry_of_media_type() {
+        let (app, pool) = setup::create_app(delete_entry).await;
+
+        let (token, user, collection, show_entry) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let collection = data::create_mixed_collection(&mut conn, &user);
+            let show_entry = data::create_show_entry(&mut conn, &user, &collection);
+            data::create_named_movie_entry(
+                &mut conn,
+                &user,
+                &collection,
+                show_entry.show_id,
+                "Doctor Who",
+                NaiveDate::from_ymd_opt(1996, 5, 12).unwrap(),
+            );
+            (token, user, collection, show_entry)
+        };
+
+        let request = test::TestRequest::delete()
+            .uri(&format!(
+                "/mixed/collections/{}/show/{}",
+                show_entry.collection_id, show_entry.show_id
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<DeleteResponse>(response).await;
+        assert_eq!(1, result.data.count);
+
+        let movie_entries = {
+            let mut conn = pool.get().unwrap();
+            MovieEntry::find_all(
+                &mut conn,
+                user.user_id,
+                collection.collection_id,
+                EntrySort::Title,
+            )
+            .unwrap()
+        };
+        assert_eq!(1, movie_entries.len());
+    }
+}
+
+mod update_entry {
+    use crate::common::{data, process, setup};
+    use actix_web::{http::header::AUTHORIZATION, test};
+    use framerate::{
+        mixed_collection::{update_entry, MixedEntry},
+        movie_entry::UpdatedMovieEntry,
+    };
+
+    #[actix_web::test]
+    async fn should_not_update_entry_with_invalid_media_type() {
+        let (app, pool) = setup::create_app(update_entry).await;
+
+        let (token, show_entry) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let collection = data::create_mixed_collection(&mut conn, &user);
+            let show_entry = data::create_show_entry(&mut conn, &user, &collection);
+            (token, show_entry)
+        };
+
+        let request = test::TestRequest::put()
+            .uri(&format!(
+                "/mixed/collections/{}/season/{}",
+                show_entry.collection_id, show_entry.show_id
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .set_json(UpdatedMovieEntry {
+                priority: Some(Some(3)),
+                ..Default::default()
+            })
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(400, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_update_show_entry() {
+        let (app, pool) = setup::create_app(update_entry).await;
+
+        let (token, show_entry) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let collection = data::create_mixed_collection(&mut conn, &user);
+            data::create_movie_entry(&mut conn, &user, &collection);
+            let show_entry = data::create_show_entry(&mut conn, &user, &collection);
+            (token, show_entry)
+        };
+
+        let request = test::TestRequest::put()
+            .uri(&format!(
+                "/mixed/collections/{}/show/{}",
+                show_entry.collection_id, show_entry.show_id
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .set_json(UpdatedMovieEntry {
+                note: Some(Some("Start with Rose".to_string())),
+                ..Default::default()
+            })
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<MixedEntry>(response).await;
+        let MixedEntry::Show(updated_entry) = result.data else {
+            panic!("Expected a show entry");
+        };
+        assert_eq!(Some("Start with Rose".to_string()), updated_entry.note);
+    }
+}
+
+mod reorder {
+    use crate::common::{data, process, setup};
+    use actix_web::{http::header::AUTHORIZATION, test};
+    use framerate::mixed_collection::{
+        reorder, MixedCollection, MixedEntry, MixedEntryKey, ReorderMixedCollectionRequest,
+    };
+
+    #[actix_web::test]
+    async fn should_not_reorder_unknown_entries() {
+        let (app, pool) = setup::create_app(reorder).await;
+
+        let (token, collection, show_entry) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let collection = data::create_mixed_collection(&mut conn, &user);
+            let show_entry = data::create_show_entry(&mut conn, &user, &collection);
+            (token, collection, show_entry)
+        };
+
+        let request = test::TestRequest::put()
+            .uri(&format!(
+                "/mixed/collections/{}/order",
+                collection.collection_id
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .set_json(ReorderMixedCollectionRequest {
+                entries: vec![MixedEntryKey {
+                    media_type: "movie".to_string(),
+                    media_id: show_entry.show_id,
+                }],
+            })
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(400, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_reorder_movie_and_show_entries_together() {
+        let (app, pool) = setup::create_app(reorder).await;
+
+        let (token, collection, movie_entry, show_entry) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let collection = data::create_mixed_collection(&mut conn, &user);
+            let movie_entry = data::create_movie_entry(&mut conn, &user, &collection);
+            let show_entry = data::create_show_entry(&mut conn, &user, &collection);
+            (token, collection, movie_entry, show_entry)
+        };
+
+        let request = test::TestRequest::put()
+            .uri(&format!(
+                "/mixed/collections/{}/order",
+                collection.collection_id
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .set_json(ReorderMixedCollectionRequest {
+                entries: vec![
+                    MixedEntryKey {
+                        media_type: "show".to_string(),
+                        media_id: show_entry.show_id,
+                    },
+                    MixedEntryKey {
+                        media_type: "movie".to_string(),
+                        media_id: movie_entry.movie_id,
+                    },
+                ],
+            })
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<MixedCollection>(response).await;
+        let positions: Vec<(&str, Option<i32>)> = result
+            .data
+            .entries
+            .as_ref()
+            .unwrap()
+            .iter()
+            .map(|entry| match entry {
+                MixedEntry::Movie(movie_entry) => ("movie", movie_entry.position),
+                MixedEntry::Show(show_entry) => ("show", show_entry.position),
+            })
+            .collect();
+
+        assert_eq!(vec![("show", Some(0)), ("movie", Some(1))], positions);
+    }
+}
+
+mod copy_entries {
+    use crate::common::{data, process, setup};
+    use actix_web::{http::header::AUTHORIZATION, test};
+    use framerate::mixed_collection::{
+        copy_entries, MixedEntry, MixedEntryKey, TransferMixedCollectionRequest,
+    };
+
+    #[actix_web::test]
+    async fn should_not_copy_shows_to_movie_collection() {
+        let (app, pool) = setup::create_app(copy_entries).await;
+
+        let (token, show_entry, target) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let collection = data::create_mixed_collection(&mut conn, &user);
+            let show_entry = data::create_show_entry(&mut conn, &user, &collection);
+            let target = data::create_movie_collection(&mut conn, &user);
+            (token, show_entry, target)
+        };
+
+        let request = test::TestRequest::post()
+            .uri(&format!(
+                "/mixed/collections/{}/copy",
+                show_entry.collection_id
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .set_json(TransferMixedCollectionRequest {
+                target_collection_id: target.collection_id,
+                entries: vec![MixedEntryKey {
+                    media_type: "show".to_string(),
+                    media_id: show_entry.show_id,
+                }],
+            })
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(400, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_copy_shows_to_show_collection() {
+        let (app, pool) = setup::create_app(copy_entries).await;
+
+        let (token, show_entry, target) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let collection = data::create_mixed_collection(&mut conn, &user);
+            data::create_movie_entry(&mut conn, &user, &collection);
+            let show_entry = data::create_show_entry(&mut conn, &user, &collection);
+            let target = data::create_show_collection(&mut conn, &user);
+            (token, show_entry, target)
+        };
+
+        let request = test::TestRequest::post()
+            .uri(&format!(
+                "/mixed/collections/{}/copy",
+                show_entry.collection_id
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .set_json(TransferMixedCollectionRequest {
+                target_collection_id: target.collection_id,
+                entries: vec![MixedEntryKey {
+                    media_type: "show".to_string(),
+                    media_id: show_entry.show_id,
+                }],
+            })
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<Vec<MixedEntry>>(response).await;
+        let [MixedEntry::Show(copied_entry)] = result.data.as_slice() else {
+            panic!("Expected a single show entry");
+        };
+        assert_eq!(target.collection_id, copied_entry.collection_id);
+        assert_eq!(show_entry.show_id, copied_entry.show_id);
+    }
+}
+
+mod move_entries {
+    use crate::common::{data, process, setup};
+    use actix_web::{http::header::AUTHORIZATION, test};
+    use framerate::{
+        collection::EntrySort,
+        mixed_collection::{
+            move_entries, MixedEntry, MixedEntryKey, TransferMixedCollectionRequest,
+        },
+    };
+
+    #[actix_web::test]
+    async fn should_move_movie_and_show_entries() {
+        let (app, pool) = setup::create_app(move_entries).await;
+
+        let (token, user, collection, movie_entry, show_entry, target) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let collection = data::create_mixed_collection(&mut conn, &user);
+            let movie_entry = data::create_movie_entry(&mut conn, &user, &collection);
+            let show_entry = data::create_show_entry(&mut conn, &user, &collection);
+            let target = data::create_mixed_collection(&mut conn, &user);
+            (token, user, collection, movie_entry, show_entry, target)
+        };
+
+        let request = test::TestRequest::post()
+            .uri(&format!(
+                "/mixed/collections/{}/move",
+                collection.collection_id
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .set_json(TransferMixedCollectionRequest {
+                target_collection_id: target.collection_id,
+                entries: vec![
+                    MixedEntryKey {
+                        media_type: "movie".to_string(),
+                        media_id: movie_entry.movie_id,
+                    },
+                    MixedEntryKey {
+                        media_type: "show".to_string(),
+                        media_id: show_entry.show_id,
+                    },
+                ],
+            })
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<Vec<MixedEntry>>(response).await;
+        assert_eq!(2, result.data.len());
+
+        let (source_entries, target_entries) = {
+            let mut conn = pool.get().unwrap();
+            (
+                MixedEntry::find_all(
+                    &mut conn,
+                    user.user_id,
+                    collection.collection_id,
+                    EntrySort::Title,
+                )
+                .unwrap(),
+                MixedEntry::find_all(
+                    &mut conn,
+                    user.user_id,
+                    target.collection_id,
+                    EntrySort::Title,
+                )
+                .unwrap(),
+            )
+        };
+        assert!(source_entries.is_empty());
+        assert_eq!(2, target_entries.len());
+    }
+}
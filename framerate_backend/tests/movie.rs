@@ -111,3 +111,50 @@ mod details {
         assert_eq!(result.data.id, 4638);
     }
 }
+
+mod franchise {
+    use crate::common::{data, process, setup};
+    use actix_web::{http::header::AUTHORIZATION, test};
+    use framerate::movie::{franchise, MovieFranchise};
+
+    #[actix_web::test]
+    async fn should_require_authentication() {
+        let (app, _) = setup::create_app(franchise).await;
+
+        let request = test::TestRequest::get()
+            .uri("/movies/franchises/9335")
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(401, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_return_parts_in_release_order() {
+        let (app, pool) = setup::create_app(franchise).await;
+        let (token, _) = {
+            let mut conn = pool.get().unwrap();
+            data::create_authed_user(&mut conn)
+        };
+
+        let request = test::TestRequest::get()
+            .uri("/movies/franchises/9335")
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<MovieFranchise>(response).await;
+        assert_eq!(result.data.id, 9335);
+        assert_eq!(
+            result
+                .data
+                .parts
+                .iter()
+                .map(|part| part.id)
+                .collect::<Vec<_>>(),
+            vec![747, 4638, 107985]
+        );
+    }
+}
@@ -3,6 +3,9 @@ pub mod common;
 mod find {
     use crate::common::{data, process, setup};
     use actix_web::{http::header::AUTHORIZATION, test};
+    use chrono::NaiveDate;
+    use framerate::collection::{CollectionRules, RuleSource};
+    use framerate::collection_member::COLLECTION_VIEWER;
     use framerate::movie_collection::{find, MovieCollection};
 
     #[actix_web::test]
@@ -74,11 +77,183 @@ mod find {
         assert_eq!(collection.name, result.data.name);
         assert_eq!(&movie_entry, returned_entry.first().unwrap());
     }
+
+    #[actix_web::test]
+    async fn should_sort_movie_collection() {
+        let (app, pool) = setup::create_app(find).await;
+
+        let (token, collection) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let collection = data::create_movie_collection(&mut conn, &user);
+            data::create_movie_entry(&mut conn, &user, &collection);
+            data::create_named_movie_entry(
+                &mut conn,
+                &user,
+                &collection,
+                107985,
+                "The World's End",
+                NaiveDate::from_ymd_opt(2013, 7, 19).unwrap(),
+            );
+            (token, collection)
+        };
+
+        for (sort, expected) in [
+            ("", ["The World's End", "Hot Fuzz"]),
+            ("?sort=releaseDate", ["The World's End", "Hot Fuzz"]),
+            ("?sort=title", ["Hot Fuzz", "The World's End"]),
+        ] {
+            let request = test::TestRequest::get()
+                .uri(&format!(
+                    "/movies/collections/{}{sort}",
+                    collection.collection_id
+                ))
+                .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+                .to_request();
+
+            let response = test::call_service(&app, request).await;
+            assert!(response.status().is_success());
+
+            let result = process::parse_body::<MovieCollection>(response).await;
+            let titles: Vec<String> = result
+                .data
+                .entries
+                .unwrap()
+                .into_iter()
+                .map(|entry| entry.title)
+                .collect();
+
+            assert_eq!(expected.to_vec(), titles, "{sort}");
+        }
+    }
+
+    #[actix_web::test]
+    async fn should_reject_unknown_sort() {
+        let (app, pool) = setup::create_app(find).await;
+
+        let (token, collection) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let collection = data::create_movie_collection(&mut conn, &user);
+            (token, collection)
+        };
+
+        let request = test::TestRequest::get()
+            .uri(&format!(
+                "/movies/collections/{}?sort=rating",
+                collection.collection_id
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(400, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_return_shared_movie_collection() {
+        let (app, pool) = setup::create_app(find).await;
+
+        let (token, collection, movie_entry) = {
+            let mut conn = pool.get().unwrap();
+            let owner = data::create_user(&mut conn);
+            let collection = data::create_movie_collection(&mut conn, &owner);
+            let movie_entry = data::create_movie_entry(&mut conn, &owner, &collection);
+            let (token, user) = data::create_authed_user(&mut conn);
+            data::create_collection_member(&mut conn, &collection, &user, COLLECTION_VIEWER);
+            (token, collection, movie_entry)
+        };
+
+        let request = test::TestRequest::get()
+            .uri(&format!("/movies/collections/{}", collection.collection_id))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<MovieCollection>(response).await;
+
+        assert_eq!(vec![movie_entry], result.data.entries.unwrap());
+    }
+
+    #[actix_web::test]
+    async fn should_return_smart_movie_collection_from_reviews() {
+        let (app, pool) = setup::create_app(find).await;
+
+        let (token, collection, strict_collection) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let review = data::create_dated_review(
+                &mut conn,
+                &user,
+                NaiveDate::from_ymd_opt(2024, 5, 1).unwrap(),
+                Some(50),
+            );
+            data::create_movie_review(&mut conn, &user, &review);
+            let review = data::create_dated_review(
+                &mut conn,
+                &user,
+                NaiveDate::from_ymd_opt(2024, 6, 1).unwrap(),
+                Some(95),
+            );
+            data::create_movie_review(&mut conn, &user, &review);
+
+            let rules = |min_rating| CollectionRules {
+                source: RuleSource::Reviews,
+                min_rating: Some(min_rating),
+                company_id: None,
+                tag_id: None,
+                collection_id: None,
+                watchlist: false,
+                statuses: None,
+                upcoming_days: None,
+            };
+            let collection = data::create_smart_movie_collection(&mut conn, &user, rules(90));
+            let strict_collection =
+                data::create_smart_movie_collection(&mut conn, &user, rules(99));
+            (token, collection, strict_collection)
+        };
+
+        let request = test::TestRequest::get()
+            .uri(&format!("/movies/collections/{}", collection.collection_id))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<MovieCollection>(response).await;
+        let entries = result.data.entries.unwrap();
+
+        assert_eq!(1, entries.len());
+        assert_eq!(collection.collection_id, entries[0].collection_id);
+        assert_eq!(data::generate_sample_movie().id, entries[0].movie_id);
+        assert_eq!(
+            NaiveDate::from_ymd_opt(2024, 6, 1).unwrap(),
+            entries[0].added_at.date()
+        );
+
+        let request = test::TestRequest::get()
+            .uri(&format!(
+                "/movies/collections/{}",
+                strict_collection.collection_id
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<MovieCollection>(response).await;
+        assert!(result.data.entries.unwrap().is_empty());
+    }
 }
 
 mod find_all {
     use crate::common::{data, process, setup};
     use actix_web::{http::header::AUTHORIZATION, test};
+    use framerate::collection_member::COLLECTION_VIEWER;
     use framerate::movie_collection::{find_all, MovieCollection};
 
     #[actix_web::test]
@@ -151,12 +326,50 @@ mod find_all {
         assert_eq!(collection.name, returned_collection.name);
         assert_eq!(None, returned_collection.entries);
     }
+
+    #[actix_web::test]
+    async fn should_return_shared_movie_collections() {
+        let (app, pool) = setup::create_app(find_all).await;
+
+        let (token, collection) = {
+            let mut conn = pool.get().unwrap();
+            let owner = data::create_user(&mut conn);
+            let collection = data::create_movie_collection(&mut conn, &owner);
+            let invited_collection = data::create_movie_collection(&mut conn, &owner);
+            let (token, user) = data::create_authed_user(&mut conn);
+            data::create_collection_member(&mut conn, &collection, &user, COLLECTION_VIEWER);
+            data::create_collection_invite(
+                &mut conn,
+                &invited_collection,
+                &user,
+                COLLECTION_VIEWER,
+            );
+            (token, collection)
+        };
+
+        let request = test::TestRequest::get()
+            .uri("/movies/collections")
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<Vec<MovieCollection>>(response).await;
+        assert_eq!(1, result.data.len());
+
+        let returned_collection = result.data.first().unwrap();
+        assert_eq!(collection.collection_id, returned_collection.collection_id);
+        assert_eq!(collection.user_id, returned_collection.user_id);
+    }
 }
 
 mod create {
     use crate::common::{data, process, setup};
     use actix_web::{http::header::AUTHORIZATION, test};
+    use framerate::collection::RuleSource;
     use framerate::movie_collection::{create, MovieCollection};
+    use serde_json::json;
 
     #[actix_web::test]
     async fn should_require_authentication() {
@@ -205,12 +418,97 @@ mod create {
         assert_eq!(movie_collection.name, returned_movie_collection.name);
         assert_eq!(user.user_id, returned_movie_collection.user_id);
     }
+
+    #[actix_web::test]
+    async fn should_create_smart_movie_collection() {
+        let (app, pool) = setup::create_app(create).await;
+
+        let token = {
+            let mut conn = pool.get().unwrap();
+            let (token, _) = data::create_authed_user(&mut conn);
+            token
+        };
+
+        let request = test::TestRequest::post()
+            .uri("/movies/collections")
+            .set_json(json!({
+                "name": "Favourites",
+                "rules": { "source": "reviews", "minRating": 90 }
+            }))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<MovieCollection>(response).await;
+        let rules = result.data.rules.unwrap();
+
+        assert_eq!(RuleSource::Reviews, rules.source);
+        assert_eq!(Some(90), rules.min_rating);
+    }
+
+    #[actix_web::test]
+    async fn should_not_create_smart_movie_collection_with_invalid_rules() {
+        let (app, pool) = setup::create_app(create).await;
+
+        let token = {
+            let mut conn = pool.get().unwrap();
+            let (token, _) = data::create_authed_user(&mut conn);
+            token
+        };
+
+        for rules in [
+            json!({ "source": "entries", "minRating": 90 }),
+            json!({ "source": "reviews", "maxRating": 90 }),
+            json!({ "source": "reviews", "minRating": 150 }),
+        ] {
+            let request = test::TestRequest::post()
+                .uri("/movies/collections")
+                .set_json(json!({ "name": "Favourites", "rules": rules }))
+                .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+                .to_request();
+
+            let response = test::call_service(&app, request).await;
+            assert_eq!(400, response.status());
+        }
+    }
+
+    #[actix_web::test]
+    async fn should_not_create_smart_movie_collection_from_other_users_data() {
+        let (app, pool) = setup::create_app(create).await;
+
+        let (token, tag, collection) = {
+            let mut conn = pool.get().unwrap();
+            let user = data::create_user(&mut conn);
+            let tag = data::create_tag(&mut conn, &user);
+            let collection = data::create_movie_collection(&mut conn, &user);
+            let (token, _) = data::create_authed_user(&mut conn);
+            (token, tag, collection)
+        };
+
+        for rules in [
+            json!({ "source": "reviews", "tagId": tag.tag_id }),
+            json!({ "source": "entries", "collectionId": collection.collection_id }),
+        ] {
+            let request = test::TestRequest::post()
+                .uri("/movies/collections")
+                .set_json(json!({ "name": "Favourites", "rules": rules }))
+                .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+                .to_request();
+
+            let response = test::call_service(&app, request).await;
+            assert_eq!(400, response.status());
+        }
+    }
 }
 
 mod update {
     use crate::common::{data, process, setup};
     use actix_web::{http::header::AUTHORIZATION, test};
+    use framerate::collection::{CollectionRules, RuleSource};
     use framerate::movie_collection::{update, MovieCollection};
+    use serde_json::json;
 
     #[actix_web::test]
     async fn should_require_authentication() {
@@ -285,6 +583,68 @@ mod update {
         assert_eq!(collection.user_id, result.data.user_id);
         assert_eq!(updated_collection.name, result.data.name);
     }
+
+    #[actix_web::test]
+    async fn should_update_smart_movie_collection_rules() {
+        let (app, pool) = setup::create_app(update).await;
+
+        let (token, collection) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let rules = CollectionRules {
+                source: RuleSource::Reviews,
+                min_rating: Some(90),
+                company_id: None,
+                tag_id: None,
+                collection_id: None,
+                watchlist: false,
+                statuses: None,
+                upcoming_days: None,
+            };
+            let collection = data::create_smart_movie_collection(&mut conn, &user, rules);
+            (token, collection)
+        };
+
+        let request = test::TestRequest::put()
+            .uri(&format!("/movies/collections/{}", collection.collection_id))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .set_json(json!({
+                "name": "Good",
+                "rules": { "source": "reviews", "minRating": 70 }
+            }))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<MovieCollection>(response).await;
+        assert_eq!("Good", result.data.name);
+        assert_eq!(Some(70), result.data.rules.unwrap().min_rating);
+    }
+
+    #[actix_web::test]
+    async fn should_not_set_rules_on_static_movie_collection() {
+        let (app, pool) = setup::create_app(update).await;
+
+        let (token, collection) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let collection = data::create_movie_collection(&mut conn, &user);
+            (token, collection)
+        };
+
+        let request = test::TestRequest::put()
+            .uri(&format!("/movies/collections/{}", collection.collection_id))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .set_json(json!({
+                "name": "Good",
+                "rules": { "source": "reviews", "minRating": 70 }
+            }))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(400, response.status());
+    }
 }
 
 mod delete {
@@ -438,27 +798,33 @@ mod create_entry {
     }
 }
 
-mod delete_entry {
+mod create_franchise_entries {
     use crate::common::{data, process, setup};
     use actix_web::{http::header::AUTHORIZATION, test};
-    use framerate::{movie_collection::delete_entry, utils::response_body::DeleteResponse};
+    use framerate::{
+        collection::{CollectionRules, RuleSource},
+        movie_collection::{
+            create_franchise_entries, SaveMovieCollectionFranchiseRequest,
+            SaveMovieCollectionFranchiseResponse,
+        },
+    };
 
     #[actix_web::test]
     async fn should_require_authentication() {
-        let (app, pool) = setup::create_app(delete_entry).await;
+        let (app, pool) = setup::create_app(create_franchise_entries).await;
 
-        let movie_entry = {
+        let collection = {
             let mut conn = pool.get().unwrap();
             let user = data::create_user(&mut conn);
-            let collection = data::create_movie_collection(&mut conn, &user);
-            data::create_movie_entry(&mut conn, &user, &collection)
+            data::create_movie_collection(&mut conn, &user)
         };
 
-        let request = test::TestRequest::delete()
+        let request = test::TestRequest::post()
             .uri(&format!(
-                "/movies/collections/{}/{}",
-                movie_entry.collection_id, movie_entry.movie_id
+                "/movies/collections/{}/franchise",
+                collection.collection_id
             ))
+            .set_json(SaveMovieCollectionFranchiseRequest { franchise_id: 9335 })
             .to_request();
 
         let response = test::call_service(&app, request).await;
@@ -466,23 +832,23 @@ mod delete_entry {
     }
 
     #[actix_web::test]
-    async fn should_not_delete_other_users_movie_entry() {
-        let (app, pool) = setup::create_app(delete_entry).await;
+    async fn should_not_create_franchise_entries_on_other_users_collection() {
+        let (app, pool) = setup::create_app(create_franchise_entries).await;
 
-        let (token, movie_entry) = {
+        let (token, collection) = {
             let mut conn = pool.get().unwrap();
             let user = data::create_user(&mut conn);
             let collection = data::create_movie_collection(&mut conn, &user);
-            let movie_entry = data::create_movie_entry(&mut conn, &user, &collection);
             let (token, _) = data::create_authed_user(&mut conn);
-            (token, movie_entry)
+            (token, collection)
         };
 
-        let request = test::TestRequest::delete()
+        let request = test::TestRequest::post()
             .uri(&format!(
-                "/movies/collections/{}/{}",
-                movie_entry.collection_id, movie_entry.movie_id
+                "/movies/collections/{}/franchise",
+                collection.collection_id
             ))
+            .set_json(SaveMovieCollectionFranchiseRequest { franchise_id: 9335 })
             .insert_header((AUTHORIZATION, format!("Bearer {token}")))
             .to_request();
 
@@ -491,43 +857,104 @@ mod delete_entry {
     }
 
     #[actix_web::test]
-    async fn should_delete_movie_entry() {
-        let (app, pool) = setup::create_app(delete_entry).await;
+    async fn should_create_franchise_entries() {
+        let (app, pool) = setup::create_app(create_franchise_entries).await;
 
-        let (token, movie_entry) = {
+        let (token, user, collection) = {
             let mut conn = pool.get().unwrap();
             let (token, user) = data::create_authed_user(&mut conn);
             let collection = data::create_movie_collection(&mut conn, &user);
-            let movie_entry = data::create_movie_entry(&mut conn, &user, &collection);
-            (token, movie_entry)
+            (token, user, collection)
         };
 
-        let request = test::TestRequest::delete()
+        let request = test::TestRequest::post()
             .uri(&format!(
-                "/movies/collections/{}/{}",
-                movie_entry.collection_id, movie_entry.movie_id
+                "/movies/collections/{}/franchise",
+                collection.collection_id
             ))
+            .set_json(SaveMovieCollectionFranchiseRequest { franchise_id: 9335 })
             .insert_header((AUTHORIZATION, format!("Bearer {token}")))
             .to_request();
 
         let response = test::call_service(&app, request).await;
         assert!(response.status().is_success());
 
-        let result = process::parse_body::<DeleteResponse>(response).await;
+        let result = process::parse_body::<SaveMovieCollectionFranchiseResponse>(response).await;
+        let mut movie_ids = result
+            .data
+            .entries
+            .iter()
+            .map(|entry| entry.movie_id)
+            .collect::<Vec<_>>();
+        movie_ids.sort();
+
+        assert_eq!(movie_ids, vec![747, 4638, 107985]);
+        assert!(result.data.failed_movie_ids.is_empty());
+        assert!(result
+            .data
+            .entries
+            .iter()
+            .all(|entry| entry.collection_id == collection.collection_id
+                && entry.user_id == user.user_id));
+    }
 
-        assert_eq!(1, result.data.count);
+    #[actix_web::test]
+    async fn should_not_create_franchise_entries_on_smart_collection() {
+        let (app, pool) = setup::create_app(create_franchise_entries).await;
+
+        let (token, collection) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let rules = CollectionRules {
+                source: RuleSource::Reviews,
+                min_rating: None,
+                company_id: None,
+                tag_id: None,
+                collection_id: None,
+                watchlist: false,
+                statuses: None,
+                upcoming_days: None,
+            };
+            let collection = data::create_smart_movie_collection(&mut conn, &user, rules);
+            (token, collection)
+        };
+
+        let request = test::TestRequest::post()
+            .uri(&format!(
+                "/movies/collections/{}/franchise",
+                collection.collection_id
+            ))
+            .set_json(SaveMovieCollectionFranchiseRequest { franchise_id: 9335 })
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(400, response.status());
     }
 }
 
-mod find_by_movie {
+mod update_entry {
     use crate::common::{data, process, setup};
     use actix_web::{http::header::AUTHORIZATION, test};
-    use framerate::movie_collection::find_by_movie;
-    use uuid::Uuid;
+    use chrono::NaiveDate;
+    use framerate::{
+        movie_collection::update_entry,
+        movie_entry::{MovieEntry, UpdatedMovieEntry},
+    };
+
+    fn generate_updated_movie_entry() -> UpdatedMovieEntry {
+        UpdatedMovieEntry {
+            added_at: NaiveDate::from_ymd_opt(2024, 12, 24)
+                .unwrap()
+                .and_hms_opt(20, 0, 0),
+            note: Some(Some("Recommended by Sam".to_string())),
+            priority: Some(Some(5)),
+        }
+    }
 
     #[actix_web::test]
     async fn should_require_authentication() {
-        let (app, pool) = setup::create_app(find_by_movie).await;
+        let (app, pool) = setup::create_app(update_entry).await;
 
         let movie_entry = {
             let mut conn = pool.get().unwrap();
@@ -536,11 +963,12 @@ mod find_by_movie {
             data::create_movie_entry(&mut conn, &user, &collection)
         };
 
-        let request = test::TestRequest::get()
+        let request = test::TestRequest::put()
             .uri(&format!(
-                "/movies/collections/movie/{}",
-                movie_entry.movie_id
+                "/movies/collections/{}/{}",
+                movie_entry.collection_id, movie_entry.movie_id
             ))
+            .set_json(generate_updated_movie_entry())
             .to_request();
 
         let response = test::call_service(&app, request).await;
@@ -548,8 +976,8 @@ mod find_by_movie {
     }
 
     #[actix_web::test]
-    async fn should_not_return_other_users_movie_collections() {
-        let (app, pool) = setup::create_app(find_by_movie).await;
+    async fn should_not_update_other_users_movie_entry() {
+        let (app, pool) = setup::create_app(update_entry).await;
 
         let (token, movie_entry) = {
             let mut conn = pool.get().unwrap();
@@ -560,9 +988,457 @@ mod find_by_movie {
             (token, movie_entry)
         };
 
-        let request = test::TestRequest::get()
+        let request = test::TestRequest::put()
             .uri(&format!(
-                "/movies/collections/movie/{}",
+                "/movies/collections/{}/{}",
+                movie_entry.collection_id, movie_entry.movie_id
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .set_json(generate_updated_movie_entry())
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(404, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_not_update_with_invalid_priority() {
+        let (app, pool) = setup::create_app(update_entry).await;
+
+        let (token, movie_entry) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let collection = data::create_movie_collection(&mut conn, &user);
+            let movie_entry = data::create_movie_entry(&mut conn, &user, &collection);
+            (token, movie_entry)
+        };
+
+        let request = test::TestRequest::put()
+            .uri(&format!(
+                "/movies/collections/{}/{}",
+                movie_entry.collection_id, movie_entry.movie_id
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .set_json(UpdatedMovieEntry {
+                priority: Some(Some(6)),
+                ..generate_updated_movie_entry()
+            })
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(400, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_update_movie_entry() {
+        let (app, pool) = setup::create_app(update_entry).await;
+
+        let (token, movie_entry) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let collection = data::create_movie_collection(&mut conn, &user);
+            let movie_entry = data::create_movie_entry(&mut conn, &user, &collection);
+            (token, movie_entry)
+        };
+
+        let updated_entry = generate_updated_movie_entry();
+
+        let request = test::TestRequest::put()
+            .uri(&format!(
+                "/movies/collections/{}/{}",
+                movie_entry.collection_id, movie_entry.movie_id
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .set_json(&updated_entry)
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<MovieEntry>(response).await;
+
+        assert_eq!(
+            MovieEntry {
+                added_at: updated_entry.added_at.unwrap(),
+                note: updated_entry.note.flatten(),
+                priority: updated_entry.priority.flatten(),
+                ..movie_entry
+            },
+            result.data
+        );
+    }
+
+    #[actix_web::test]
+    async fn should_only_update_given_fields() {
+        let (app, pool) = setup::create_app(update_entry).await;
+
+        let (token, movie_entry) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let collection = data::create_movie_collection(&mut conn, &user);
+            let movie_entry = data::create_movie_entry(&mut conn, &user, &collection);
+            let movie_entry = MovieEntry::update(
+                &mut conn,
+                collection.collection_id,
+                movie_entry.movie_id,
+                UpdatedMovieEntry {
+                    priority: Some(Some(3)),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+            (token, movie_entry)
+        };
+
+        let request = test::TestRequest::put()
+            .uri(&format!(
+                "/movies/collections/{}/{}",
+                movie_entry.collection_id, movie_entry.movie_id
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .set_json(serde_json::json!({ "note": "Recommended by Sam" }))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<MovieEntry>(response).await;
+
+        assert_eq!(
+            MovieEntry {
+                note: Some("Recommended by Sam".to_string()),
+                ..movie_entry
+            },
+            result.data
+        );
+    }
+}
+
+mod delete_entry {
+    use crate::common::{data, process, setup};
+    use actix_web::{http::header::AUTHORIZATION, test};
+    use framerate::{
+        collection_change::{CollectionChange, ENTRY_REMOVED},
+        collection_member::{COLLECTION_EDITOR, COLLECTION_VIEWER},
+        movie::MOVIE_MEDIA_TYPE,
+        movie_collection::delete_entry,
+        utils::response_body::DeleteResponse,
+    };
+
+    #[actix_web::test]
+    async fn should_require_authentication() {
+        let (app, pool) = setup::create_app(delete_entry).await;
+
+        let movie_entry = {
+            let mut conn = pool.get().unwrap();
+            let user = data::create_user(&mut conn);
+            let collection = data::create_movie_collection(&mut conn, &user);
+            data::create_movie_entry(&mut conn, &user, &collection)
+        };
+
+        let request = test::TestRequest::delete()
+            .uri(&format!(
+                "/movies/collections/{}/{}",
+                movie_entry.collection_id, movie_entry.movie_id
+            ))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(401, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_not_delete_other_users_movie_entry() {
+        let (app, pool) = setup::create_app(delete_entry).await;
+
+        let (token, movie_entry) = {
+            let mut conn = pool.get().unwrap();
+            let user = data::create_user(&mut conn);
+            let collection = data::create_movie_collection(&mut conn, &user);
+            let movie_entry = data::create_movie_entry(&mut conn, &user, &collection);
+            let (token, _) = data::create_authed_user(&mut conn);
+            (token, movie_entry)
+        };
+
+        let request = test::TestRequest::delete()
+            .uri(&format!(
+                "/movies/collections/{}/{}",
+                movie_entry.collection_id, movie_entry.movie_id
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(404, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_delete_movie_entry() {
+        let (app, pool) = setup::create_app(delete_entry).await;
+
+        let (token, movie_entry) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let collection = data::create_movie_collection(&mut conn, &user);
+            let movie_entry = data::create_movie_entry(&mut conn, &user, &collection);
+            (token, movie_entry)
+        };
+
+        let request = test::TestRequest::delete()
+            .uri(&format!(
+                "/movies/collections/{}/{}",
+                movie_entry.collection_id, movie_entry.movie_id
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<DeleteResponse>(response).await;
+
+        assert_eq!(1, result.data.count);
+    }
+
+    #[actix_web::test]
+    async fn should_not_delete_movie_entry_as_viewer() {
+        let (app, pool) = setup::create_app(delete_entry).await;
+
+        let (token, movie_entry) = {
+            let mut conn = pool.get().unwrap();
+            let owner = data::create_user(&mut conn);
+            let collection = data::create_movie_collection(&mut conn, &owner);
+            let movie_entry = data::create_movie_entry(&mut conn, &owner, &collection);
+            let (token, user) = data::create_authed_user(&mut conn);
+            data::create_collection_member(&mut conn, &collection, &user, COLLECTION_VIEWER);
+            (token, movie_entry)
+        };
+
+        let request = test::TestRequest::delete()
+            .uri(&format!(
+                "/movies/collections/{}/{}",
+                movie_entry.collection_id, movie_entry.movie_id
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(404, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_delete_movie_entry_as_editor() {
+        let (app, pool) = setup::create_app(delete_entry).await;
+
+        let (token, user, movie_entry) = {
+            let mut conn = pool.get().unwrap();
+            let owner = data::create_user(&mut conn);
+            let collection = data::create_movie_collection(&mut conn, &owner);
+            let movie_entry = data::create_movie_entry(&mut conn, &owner, &collection);
+            let (token, user) = data::create_authed_user(&mut conn);
+            data::create_collection_member(&mut conn, &collection, &user, COLLECTION_EDITOR);
+            (token, user, movie_entry)
+        };
+
+        let request = test::TestRequest::delete()
+            .uri(&format!(
+                "/movies/collections/{}/{}",
+                movie_entry.collection_id, movie_entry.movie_id
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let changes = {
+            let mut conn = pool.get().unwrap();
+            CollectionChange::find_all(&mut conn, movie_entry.collection_id).unwrap()
+        };
+
+        assert_eq!(1, changes.len());
+        assert_eq!(user.user_id, changes[0].user_id);
+        assert_eq!(MOVIE_MEDIA_TYPE, changes[0].media_type);
+        assert_eq!(movie_entry.movie_id, changes[0].media_id);
+        assert_eq!(ENTRY_REMOVED, changes[0].action);
+    }
+}
+
+mod reorder {
+    use crate::common::{data, process, setup};
+    use actix_web::{http::header::AUTHORIZATION, test};
+    use chrono::NaiveDate;
+    use framerate::movie_collection::{reorder, MovieCollection, ReorderMovieCollectionRequest};
+
+    #[actix_web::test]
+    async fn should_require_authentication() {
+        let (app, pool) = setup::create_app(reorder).await;
+
+        let collection = {
+            let mut conn = pool.get().unwrap();
+            let user = data::create_user(&mut conn);
+            data::create_movie_collection(&mut conn, &user)
+        };
+
+        let request = test::TestRequest::put()
+            .uri(&format!(
+                "/movies/collections/{}/order",
+                collection.collection_id
+            ))
+            .set_json(ReorderMovieCollectionRequest { movie_ids: vec![] })
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(401, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_not_reorder_other_users_movie_collection() {
+        let (app, pool) = setup::create_app(reorder).await;
+
+        let (token, collection, movie_entry) = {
+            let mut conn = pool.get().unwrap();
+            let user = data::create_user(&mut conn);
+            let collection = data::create_movie_collection(&mut conn, &user);
+            let movie_entry = data::create_movie_entry(&mut conn, &user, &collection);
+            let (token, _) = data::create_authed_user(&mut conn);
+            (token, collection, movie_entry)
+        };
+
+        let request = test::TestRequest::put()
+            .uri(&format!(
+                "/movies/collections/{}/order",
+                collection.collection_id
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .set_json(ReorderMovieCollectionRequest {
+                movie_ids: vec![movie_entry.movie_id],
+            })
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(404, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_not_reorder_unknown_entries() {
+        let (app, pool) = setup::create_app(reorder).await;
+
+        let (token, collection, movie_entry) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let collection = data::create_movie_collection(&mut conn, &user);
+            let movie_entry = data::create_movie_entry(&mut conn, &user, &collection);
+            (token, collection, movie_entry)
+        };
+
+        for movie_ids in [
+            vec![movie_entry.movie_id, 107985],
+            vec![movie_entry.movie_id, movie_entry.movie_id],
+        ] {
+            let request = test::TestRequest::put()
+                .uri(&format!(
+                    "/movies/collections/{}/order",
+                    collection.collection_id
+                ))
+                .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+                .set_json(ReorderMovieCollectionRequest { movie_ids })
+                .to_request();
+
+            let response = test::call_service(&app, request).await;
+            assert_eq!(400, response.status());
+        }
+    }
+
+    #[actix_web::test]
+    async fn should_reorder_movie_collection() {
+        let (app, pool) = setup::create_app(reorder).await;
+
+        let (token, collection, movie_entry, other_entry) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let collection = data::create_movie_collection(&mut conn, &user);
+            let movie_entry = data::create_movie_entry(&mut conn, &user, &collection);
+            let other_entry = data::create_named_movie_entry(
+                &mut conn,
+                &user,
+                &collection,
+                107985,
+                "The World's End",
+                NaiveDate::from_ymd_opt(2013, 7, 19).unwrap(),
+            );
+            (token, collection, movie_entry, other_entry)
+        };
+
+        let request = test::TestRequest::put()
+            .uri(&format!(
+                "/movies/collections/{}/order",
+                collection.collection_id
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .set_json(ReorderMovieCollectionRequest {
+                movie_ids: vec![other_entry.movie_id, movie_entry.movie_id],
+            })
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<MovieCollection>(response).await;
+        let entries = result.data.entries.unwrap();
+
+        assert_eq!(2, entries.len());
+        assert_eq!(other_entry.movie_id, entries[0].movie_id);
+        assert_eq!(Some(0), entries[0].position);
+        assert_eq!(movie_entry.movie_id, entries[1].movie_id);
+        assert_eq!(Some(1), entries[1].position);
+    }
+}
+
+mod find_by_movie {
+    use crate::common::{data, process, setup};
+    use actix_web::{http::header::AUTHORIZATION, test};
+    use framerate::movie_collection::find_by_movie;
+    use uuid::Uuid;
+
+    #[actix_web::test]
+    async fn should_require_authentication() {
+        let (app, pool) = setup::create_app(find_by_movie).await;
+
+        let movie_entry = {
+            let mut conn = pool.get().unwrap();
+            let user = data::create_user(&mut conn);
+            let collection = data::create_movie_collection(&mut conn, &user);
+            data::create_movie_entry(&mut conn, &user, &collection)
+        };
+
+        let request = test::TestRequest::get()
+            .uri(&format!(
+                "/movies/collections/movie/{}",
+                movie_entry.movie_id
+            ))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(401, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_not_return_other_users_movie_collections() {
+        let (app, pool) = setup::create_app(find_by_movie).await;
+
+        let (token, movie_entry) = {
+            let mut conn = pool.get().unwrap();
+            let user = data::create_user(&mut conn);
+            let collection = data::create_movie_collection(&mut conn, &user);
+            let movie_entry = data::create_movie_entry(&mut conn, &user, &collection);
+            let (token, _) = data::create_authed_user(&mut conn);
+            (token, movie_entry)
+        };
+
+        let request = test::TestRequest::get()
+            .uri(&format!(
+                "/movies/collections/movie/{}",
                 movie_entry.movie_id
             ))
             .insert_header((AUTHORIZATION, format!("Bearer {token}")))
@@ -605,3 +1481,308 @@ mod find_by_movie {
         assert_eq!(&collection.collection_id, returned_collection_id);
     }
 }
+
+mod copy_entries {
+    use crate::common::{data, process, setup};
+    use actix_web::{http::header::AUTHORIZATION, test};
+    use chrono::NaiveDate;
+    use framerate::{
+        movie_collection::{copy_entries, TransferMovieCollectionRequest},
+        movie_entry::{MovieEntry, UpdatedMovieEntry},
+    };
+
+    #[actix_web::test]
+    async fn should_require_authentication() {
+        let (app, pool) = setup::create_app(copy_entries).await;
+
+        let (collection, target) = {
+            let mut conn = pool.get().unwrap();
+            let user = data::create_user(&mut conn);
+            let collection = data::create_movie_collection(&mut conn, &user);
+            let target = data::create_movie_collection(&mut conn, &user);
+            (collection, target)
+        };
+
+        let request = test::TestRequest::post()
+            .uri(&format!(
+                "/movies/collections/{}/copy",
+                collection.collection_id
+            ))
+            .set_json(TransferMovieCollectionRequest {
+                target_collection_id: target.collection_id,
+                movie_ids: vec![],
+            })
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(401, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_not_copy_to_other_users_movie_collection() {
+        let (app, pool) = setup::create_app(copy_entries).await;
+
+        let (token, movie_entry, target) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let collection = data::create_movie_collection(&mut conn, &user);
+            let movie_entry = data::create_movie_entry(&mut conn, &user, &collection);
+            let other_user = data::create_user(&mut conn);
+            let target = data::create_movie_collection(&mut conn, &other_user);
+            (token, movie_entry, target)
+        };
+
+        let request = test::TestRequest::post()
+            .uri(&format!(
+                "/movies/collections/{}/copy",
+                movie_entry.collection_id
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .set_json(TransferMovieCollectionRequest {
+                target_collection_id: target.collection_id,
+                movie_ids: vec![movie_entry.movie_id],
+            })
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(404, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_not_copy_unknown_entries() {
+        let (app, pool) = setup::create_app(copy_entries).await;
+
+        let (token, movie_entry, target) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let collection = data::create_movie_collection(&mut conn, &user);
+            let movie_entry = data::create_movie_entry(&mut conn, &user, &collection);
+            let target = data::create_movie_collection(&mut conn, &user);
+            (token, movie_entry, target)
+        };
+
+        let request = test::TestRequest::post()
+            .uri(&format!(
+                "/movies/collections/{}/copy",
+                movie_entry.collection_id
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .set_json(TransferMovieCollectionRequest {
+                target_collection_id: target.collection_id,
+                movie_ids: vec![movie_entry.movie_id, 1],
+            })
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(400, response.status());
+
+        let entries = {
+            let mut conn = pool.get().unwrap();
+            MovieEntry::find_all(
+                &mut conn,
+                target.user_id,
+                target.collection_id,
+                Default::default(),
+            )
+            .unwrap()
+        };
+        assert!(entries.is_empty());
+    }
+
+    #[actix_web::test]
+    async fn should_copy_movie_entries() {
+        let (app, pool) = setup::create_app(copy_entries).await;
+
+        let (token, collection, target, movie_entry, other_movie_entry) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let collection = data::create_movie_collection(&mut conn, &user);
+            let movie_entry = data::create_movie_entry(&mut conn, &user, &collection);
+            let movie_entry = MovieEntry::update(
+                &mut conn,
+                collection.collection_id,
+                movie_entry.movie_id,
+                UpdatedMovieEntry {
+                    added_at: None,
+                    note: Some(Some("Watch on a big screen".to_string())),
+                    priority: Some(Some(4)),
+                },
+            )
+            .unwrap();
+            let other_movie_entry = data::create_named_movie_entry(
+                &mut conn,
+                &user,
+                &collection,
+                4639,
+                "Shaun of the Dead",
+                NaiveDate::from_ymd_opt(2004, 4, 9).unwrap(),
+            );
+            let target = data::create_movie_collection(&mut conn, &user);
+            (token, collection, target, movie_entry, other_movie_entry)
+        };
+
+        let request = test::TestRequest::post()
+            .uri(&format!(
+                "/movies/collections/{}/copy",
+                collection.collection_id
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .set_json(TransferMovieCollectionRequest {
+                target_collection_id: target.collection_id,
+                movie_ids: vec![movie_entry.movie_id, other_movie_entry.movie_id],
+            })
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<Vec<MovieEntry>>(response).await;
+        assert_eq!(2, result.data.len());
+
+        let copied_entry = result
+            .data
+            .iter()
+            .find(|entry| entry.movie_id == movie_entry.movie_id)
+            .unwrap();
+        assert_eq!(target.collection_id, copied_entry.collection_id);
+        assert_eq!(movie_entry.title, copied_entry.title);
+        assert_eq!(movie_entry.note, copied_entry.note);
+        assert_eq!(movie_entry.priority, copied_entry.priority);
+
+        let entries = {
+            let mut conn = pool.get().unwrap();
+            MovieEntry::find_all(
+                &mut conn,
+                collection.user_id,
+                collection.collection_id,
+                Default::default(),
+            )
+            .unwrap()
+        };
+        assert_eq!(2, entries.len());
+    }
+}
+
+mod move_entries {
+    use crate::common::{data, process, setup};
+    use actix_web::{http::header::AUTHORIZATION, test};
+    use framerate::{
+        collection_change::{CollectionChange, ENTRY_ADDED, ENTRY_REMOVED},
+        collection_member::COLLECTION_VIEWER,
+        movie_collection::{move_entries, TransferMovieCollectionRequest},
+        movie_entry::MovieEntry,
+    };
+
+    #[actix_web::test]
+    async fn should_require_authentication() {
+        let (app, pool) = setup::create_app(move_entries).await;
+
+        let (collection, target) = {
+            let mut conn = pool.get().unwrap();
+            let user = data::create_user(&mut conn);
+            let collection = data::create_movie_collection(&mut conn, &user);
+            let target = data::create_movie_collection(&mut conn, &user);
+            (collection, target)
+        };
+
+        let request = test::TestRequest::post()
+            .uri(&format!(
+                "/movies/collections/{}/move",
+                collection.collection_id
+            ))
+            .set_json(TransferMovieCollectionRequest {
+                target_collection_id: target.collection_id,
+                movie_ids: vec![],
+            })
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(401, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_not_move_from_movie_collection_as_viewer() {
+        let (app, pool) = setup::create_app(move_entries).await;
+
+        let (token, movie_entry, target) = {
+            let mut conn = pool.get().unwrap();
+            let owner = data::create_user(&mut conn);
+            let collection = data::create_movie_collection(&mut conn, &owner);
+            let movie_entry = data::create_movie_entry(&mut conn, &owner, &collection);
+            let (token, user) = data::create_authed_user(&mut conn);
+            data::create_collection_member(&mut conn, &collection, &user, COLLECTION_VIEWER);
+            let target = data::create_movie_collection(&mut conn, &user);
+            (token, movie_entry, target)
+        };
+
+        let request = test::TestRequest::post()
+            .uri(&format!(
+                "/movies/collections/{}/move",
+                movie_entry.collection_id
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .set_json(TransferMovieCollectionRequest {
+                target_collection_id: target.collection_id,
+                movie_ids: vec![movie_entry.movie_id],
+            })
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(404, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_move_movie_entries() {
+        let (app, pool) = setup::create_app(move_entries).await;
+
+        let (token, user, movie_entry, target) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let watchlist = data::create_default_movie_watchlist(&mut conn, &user);
+            let movie_entry = data::create_movie_entry(&mut conn, &user, &watchlist);
+            let target = data::create_movie_collection(&mut conn, &user);
+            (token, user, movie_entry, target)
+        };
+
+        let request = test::TestRequest::post()
+            .uri(&format!(
+                "/movies/collections/{}/move",
+                movie_entry.collection_id
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .set_json(TransferMovieCollectionRequest {
+                target_collection_id: target.collection_id,
+                movie_ids: vec![movie_entry.movie_id],
+            })
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<Vec<MovieEntry>>(response).await;
+        assert_eq!(1, result.data.len());
+        assert_eq!(target.collection_id, result.data[0].collection_id);
+        assert_eq!(movie_entry.movie_id, result.data[0].movie_id);
+
+        let (source_entries, source_changes, target_changes) = {
+            let mut conn = pool.get().unwrap();
+            (
+                MovieEntry::find_all(
+                    &mut conn,
+                    user.user_id,
+                    movie_entry.collection_id,
+                    Default::default(),
+                )
+                .unwrap(),
+                CollectionChange::find_all(&mut conn, movie_entry.collection_id).unwrap(),
+                CollectionChange::find_all(&mut conn, target.collection_id).unwrap(),
+            )
+        };
+
+        assert!(source_entries.is_empty());
+        assert_eq!(1, source_changes.len());
+        assert_eq!(ENTRY_REMOVED, source_changes[0].action);
+        assert_eq!(1, target_changes.len());
+        assert_eq!(ENTRY_ADDED, target_changes[0].action);
+    }
+}
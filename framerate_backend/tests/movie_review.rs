@@ -3,7 +3,12 @@ pub mod common;
 mod find_all {
     use crate::common::{data, process, setup};
     use actix_web::{http::header::AUTHORIZATION, test};
-    use framerate::{movie_review::find_all, movie_review::MovieReviewResponse};
+    use chrono::NaiveDate;
+    use framerate::{
+        movie_review::find_all,
+        movie_review::MovieReviewResponse,
+        review_tag::{ReviewTag, ReviewTagSummary},
+    };
 
     #[actix_web::test]
     async fn should_require_authentication() {
@@ -80,12 +85,210 @@ mod find_all {
             returned_review.movie.release_date
         );
     }
+
+    #[actix_web::test]
+    async fn should_filter_by_tag() {
+        let (app, pool) = setup::create_app(find_all).await;
+
+        let (token, tagged_review, tag) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let tag = data::create_tag(&mut conn, &user);
+
+            let tagged_review = data::create_review(&mut conn, &user);
+            data::create_movie_review(&mut conn, &user, &tagged_review);
+            ReviewTag::replace(
+                &mut conn,
+                user.user_id,
+                tagged_review.review_id,
+                Some(&vec![ReviewTagSummary { tag_id: tag.tag_id }]),
+            )
+            .unwrap();
+
+            let review = data::create_review(&mut conn, &user);
+            data::create_movie_review(&mut conn, &user, &review);
+
+            (token, tagged_review, tag)
+        };
+
+        let request = test::TestRequest::get()
+            .uri(&format!("/movies/reviews?withTag={}", tag.tag_id))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<Vec<MovieReviewResponse>>(response).await;
+        assert_eq!(1, result.data.len());
+        assert_eq!(tagged_review.review_id, result.data[0].review_id);
+    }
+
+    #[actix_web::test]
+    async fn should_search_review_text() {
+        let (app, pool) = setup::create_app(find_all).await;
+
+        let (token, matching_review) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+
+            let matching_review = data::create_described_review(
+                &mut conn,
+                &user,
+                "Village life",
+                "The swan chase through Sandford was the highlight",
+            );
+            data::create_movie_review(&mut conn, &user, &matching_review);
+
+            let review =
+                data::create_described_review(&mut conn, &user, "Second watch", "Still holds up");
+            data::create_movie_review(&mut conn, &user, &review);
+
+            (token, matching_review)
+        };
+
+        let request = test::TestRequest::get()
+            .uri("/movies/reviews?query=swans")
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<Vec<MovieReviewResponse>>(response).await;
+        assert_eq!(1, result.data.len());
+
+        let returned_review = result.data.first().unwrap();
+        assert_eq!(matching_review.review_id, returned_review.review_id);
+        assert!(returned_review
+            .snippet
+            .as_ref()
+            .unwrap()
+            .contains("<mark>swan</mark>"));
+    }
+
+    #[actix_web::test]
+    async fn should_rank_search_results_by_relevance() {
+        let (app, pool) = setup::create_app(find_all).await;
+
+        let (token, title_match, description_match) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+
+            let description_match = data::create_described_review(
+                &mut conn,
+                &user,
+                "Village life",
+                "Great cornetto jokes",
+            );
+            data::create_movie_review(&mut conn, &user, &description_match);
+
+            let title_match = data::create_described_review(
+                &mut conn,
+                &user,
+                "Cornetto trilogy",
+                "Rewatched with friends",
+            );
+            data::create_movie_review(&mut conn, &user, &title_match);
+
+            (token, title_match, description_match)
+        };
+
+        let request = test::TestRequest::get()
+            .uri("/movies/reviews?query=cornetto")
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<Vec<MovieReviewResponse>>(response).await;
+        assert_eq!(2, result.data.len());
+        assert_eq!(title_match.review_id, result.data[0].review_id);
+        assert_eq!(description_match.review_id, result.data[1].review_id);
+    }
+
+    #[actix_web::test]
+    async fn should_filter_by_watch_date() {
+        let (app, pool) = setup::create_app(find_all).await;
+
+        let (token, expected) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+
+            let mut reviews = vec![];
+            for (date, rating) in [
+                (NaiveDate::from_ymd_opt(2024, 12, 31).unwrap(), 100),
+                (NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(), 60),
+                (NaiveDate::from_ymd_opt(2025, 6, 15).unwrap(), 90),
+                (NaiveDate::from_ymd_opt(2025, 12, 31).unwrap(), 70),
+                (NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(), 100),
+            ] {
+                let review = data::create_dated_review(&mut conn, &user, date, Some(rating));
+                data::create_movie_review(&mut conn, &user, &review);
+                reviews.push(review.review_id);
+            }
+
+            (token, vec![reviews[2], reviews[3], reviews[1]])
+        };
+
+        let request = test::TestRequest::get()
+            .uri("/movies/reviews?dateFrom=2025-01-01&dateTo=2025-12-31&orderBy=rating")
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<Vec<MovieReviewResponse>>(response).await;
+        let returned: Vec<_> = result.data.iter().map(|review| review.review_id).collect();
+        assert_eq!(expected, returned);
+    }
+
+    #[actix_web::test]
+    async fn should_filter_by_release_date() {
+        let (app, pool) = setup::create_app(find_all).await;
+
+        let (token, expected) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+
+            let review = data::create_review(&mut conn, &user);
+            let release_date = NaiveDate::from_ymd_opt(1999, 3, 31).unwrap();
+            data::create_released_movie_review(&mut conn, &user, &review, release_date);
+
+            let expected = data::create_review(&mut conn, &user);
+            let release_date = NaiveDate::from_ymd_opt(2004, 4, 9).unwrap();
+            data::create_released_movie_review(&mut conn, &user, &expected, release_date);
+
+            let review = data::create_review(&mut conn, &user);
+            let release_date = NaiveDate::from_ymd_opt(2013, 7, 19).unwrap();
+            data::create_released_movie_review(&mut conn, &user, &review, release_date);
+
+            (token, expected)
+        };
+
+        let request = test::TestRequest::get()
+            .uri("/movies/reviews?releaseFrom=2000-01-01&releaseTo=2009-12-31")
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<Vec<MovieReviewResponse>>(response).await;
+        assert_eq!(1, result.data.len());
+        assert_eq!(expected.review_id, result.data[0].review_id);
+    }
 }
 
 mod find_by_review_id {
     use crate::common::{data, process, setup};
     use actix_web::{http::header::AUTHORIZATION, test};
-    use framerate::movie_review::{find_by_review_id, MovieReviewResponse};
+    use framerate::{
+        movie_review::{find_by_review_id, MovieReviewResponse},
+        review_tag::{ReviewTag, ReviewTagSummary},
+    };
 
     #[actix_web::test]
     async fn should_require_authentication() {
@@ -163,6 +366,42 @@ mod find_by_review_id {
         assert_eq!(movie_review.poster_path, result.data.movie.poster_path);
         assert_eq!(movie_review.release_date, result.data.movie.release_date);
     }
+
+    #[actix_web::test]
+    async fn should_return_review_tags() {
+        let (app, pool) = setup::create_app(find_by_review_id).await;
+
+        let (token, review, tag) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let review = data::create_review(&mut conn, &user);
+            data::create_movie_review(&mut conn, &user, &review);
+            let tag = data::create_tag(&mut conn, &user);
+            ReviewTag::replace(
+                &mut conn,
+                user.user_id,
+                review.review_id,
+                Some(&vec![ReviewTagSummary { tag_id: tag.tag_id }]),
+            )
+            .unwrap();
+            (token, review, tag)
+        };
+
+        let request = test::TestRequest::get()
+            .uri(&format!("/movies/reviews/{}", review.review_id))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<MovieReviewResponse>(response).await;
+
+        let tags = result.data.tags.unwrap();
+        assert_eq!(1, tags.len());
+        assert_eq!(tag.tag_id, tags[0].tag_id);
+        assert_eq!(tag.name, tags[0].name);
+    }
 }
 
 mod find_by_movie_id {
@@ -263,9 +502,15 @@ mod find_by_movie_id {
 mod create {
     use crate::common::{data, process, setup};
     use actix_web::{http::header::AUTHORIZATION, test};
+    use framerate::{
+        collection::{Collection, DEFAULT_WATCHED},
+        movie::MOVIE_MEDIA_TYPE,
+        movie_entry::MovieEntry,
+    };
     use framerate::{
         movie_review::{create, MovieReview, MovieReviewResponse},
         review_company::ReviewCompanySummary,
+        review_tag::ReviewTagSummary,
     };
     use uuid::Uuid;
 
@@ -394,6 +639,200 @@ mod create {
         assert_eq!(company_details.first_name, result_company.first_name);
         assert_eq!(company_details.last_name, result_company.last_name);
     }
+
+    #[actix_web::test]
+    async fn should_create_review_with_tags() {
+        let (app, pool) = setup::create_app(create).await;
+
+        let (token, tag) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let tag = data::create_tag(&mut conn, &user);
+            (token, tag)
+        };
+
+        let movie = data::generate_sample_movie();
+
+        let review =
+            data::generate_save_movie_review().tags(vec![ReviewTagSummary { tag_id: tag.tag_id }]);
+
+        let request = test::TestRequest::post()
+            .uri(&format!("/movies/{}/reviews", movie.id))
+            .set_json(&review)
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<MovieReviewResponse>(response).await;
+
+        let tags = result.data.tags.unwrap();
+        assert_eq!(1, tags.len());
+        assert_eq!(tag.tag_id, tags[0].tag_id);
+        assert_eq!(tag.name, tags[0].name);
+    }
+
+    #[actix_web::test]
+    async fn should_not_create_review_with_other_users_tags() {
+        let (app, pool) = setup::create_app(create).await;
+
+        let (token, user, tag) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let other_user = data::create_user(&mut conn);
+            let tag = data::create_tag(&mut conn, &other_user);
+            (token, user, tag)
+        };
+
+        let movie = data::generate_sample_movie();
+
+        let review =
+            data::generate_save_movie_review().tags(vec![ReviewTagSummary { tag_id: tag.tag_id }]);
+
+        let request = test::TestRequest::post()
+            .uri(&format!("/movies/{}/reviews", movie.id))
+            .set_json(&review)
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(400, response.status());
+
+        let reviews = {
+            let mut conn = pool.get().unwrap();
+            MovieReview::find_by_movie_id(&mut conn, user.user_id, movie.id).unwrap()
+        };
+
+        assert_eq!(0, reviews.len());
+    }
+
+    #[actix_web::test]
+    async fn should_keep_reviewed_movie_on_watchlist_by_default() {
+        let (app, pool) = setup::create_app(create).await;
+
+        let (token, movie_entry) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let watchlist = data::create_default_movie_watchlist(&mut conn, &user);
+            let movie_entry = data::create_movie_entry(&mut conn, &user, &watchlist);
+            (token, movie_entry)
+        };
+
+        let request = test::TestRequest::post()
+            .uri(&format!("/movies/{}/reviews", movie_entry.movie_id))
+            .set_json(data::generate_save_movie_review())
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<MovieReviewResponse>(response).await;
+        assert!(result.data.watchlist_removal.is_none());
+
+        let entries = {
+            let mut conn = pool.get().unwrap();
+            MovieEntry::find_all(
+                &mut conn,
+                movie_entry.user_id,
+                movie_entry.collection_id,
+                Default::default(),
+            )
+            .unwrap()
+        };
+        assert_eq!(1, entries.len());
+    }
+
+    #[actix_web::test]
+    async fn should_remove_reviewed_movie_from_watchlist() {
+        let (app, pool) = setup::create_app(create).await;
+
+        let (token, movie_entry) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            data::set_watchlist_on_review(&mut conn, &user, "remove");
+            let watchlist = data::create_default_movie_watchlist(&mut conn, &user);
+            let movie_entry = data::create_movie_entry(&mut conn, &user, &watchlist);
+            (token, movie_entry)
+        };
+
+        let request = test::TestRequest::post()
+            .uri(&format!("/movies/{}/reviews", movie_entry.movie_id))
+            .set_json(data::generate_save_movie_review())
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<MovieReviewResponse>(response).await;
+        let removal = result.data.watchlist_removal.unwrap();
+        assert_eq!(movie_entry, removal.entry);
+        assert!(removal.moved_to.is_none());
+
+        let entries = {
+            let mut conn = pool.get().unwrap();
+            MovieEntry::find_all(
+                &mut conn,
+                movie_entry.user_id,
+                movie_entry.collection_id,
+                Default::default(),
+            )
+            .unwrap()
+        };
+        assert!(entries.is_empty());
+    }
+
+    #[actix_web::test]
+    async fn should_move_reviewed_movie_to_watched_collection() {
+        let (app, pool) = setup::create_app(create).await;
+
+        let (token, movie_entry) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            data::set_watchlist_on_review(&mut conn, &user, "move");
+            let watchlist = data::create_default_movie_watchlist(&mut conn, &user);
+            let movie_entry = data::create_movie_entry(&mut conn, &user, &watchlist);
+            (token, movie_entry)
+        };
+
+        let request = test::TestRequest::post()
+            .uri(&format!("/movies/{}/reviews", movie_entry.movie_id))
+            .set_json(data::generate_save_movie_review())
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<MovieReviewResponse>(response).await;
+        let removal = result.data.watchlist_removal.unwrap();
+        assert_eq!(movie_entry.movie_id, removal.entry.movie_id);
+
+        let (watched, entries) = {
+            let mut conn = pool.get().unwrap();
+            let watched = Collection::find_default(
+                &mut conn,
+                movie_entry.user_id,
+                MOVIE_MEDIA_TYPE,
+                DEFAULT_WATCHED,
+            )
+            .unwrap();
+            let entries = MovieEntry::find_all(
+                &mut conn,
+                movie_entry.user_id,
+                watched.collection_id,
+                Default::default(),
+            )
+            .unwrap();
+            (watched, entries)
+        };
+
+        assert_eq!(Some(watched.collection_id), removal.moved_to);
+        assert_eq!(1, entries.len());
+        assert_eq!(movie_entry.movie_id, entries[0].movie_id);
+    }
 }
 
 mod update {
@@ -658,3 +1097,116 @@ mod update {
         assert_eq!(&0, &company.len());
     }
 }
+
+mod delete {
+    use crate::common::{data, process, setup};
+    use actix_web::{http::header::AUTHORIZATION, test};
+    use framerate::{
+        movie_review::{delete, MovieReview},
+        review_company::{ReviewCompany, ReviewCompanySummary},
+        utils::response_body::DeleteResponse,
+    };
+
+    #[actix_web::test]
+    async fn should_require_authentication() {
+        let (app, pool) = setup::create_app(delete).await;
+
+        let movie_review = {
+            let mut conn = pool.get().unwrap();
+            let user = data::create_user(&mut conn);
+            let review = data::create_review(&mut conn, &user);
+            data::create_movie_review(&mut conn, &user, &review)
+        };
+
+        let request = test::TestRequest::delete()
+            .uri(&format!(
+                "/movies/{}/reviews/{}",
+                movie_review.movie_id, movie_review.review_id
+            ))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(401, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_not_delete_other_users_review() {
+        let (app, pool) = setup::create_app(delete).await;
+
+        let (token, user, movie_review) = {
+            let mut conn = pool.get().unwrap();
+            let user = data::create_user(&mut conn);
+            let review = data::create_review(&mut conn, &user);
+            let movie_review = data::create_movie_review(&mut conn, &user, &review);
+            let (token, _) = data::create_authed_user(&mut conn);
+            (token, user, movie_review)
+        };
+
+        let request = test::TestRequest::delete()
+            .uri(&format!(
+                "/movies/{}/reviews/{}",
+                movie_review.movie_id, movie_review.review_id
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(404, response.status());
+
+        let existing_review = {
+            let mut conn = pool.get().unwrap();
+            MovieReview::find_by_review_id(&mut conn, user.user_id, movie_review.review_id)
+        };
+
+        assert!(existing_review.is_ok());
+    }
+
+    #[actix_web::test]
+    async fn should_delete_review() {
+        let (app, pool) = setup::create_app(delete).await;
+
+        let (token, user, movie_review) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let review = data::create_review(&mut conn, &user);
+            let movie_review = data::create_movie_review(&mut conn, &user, &review);
+            let company = data::create_company(&mut conn, &user);
+
+            ReviewCompany::replace(
+                &mut conn,
+                movie_review.review_id,
+                Some(&vec![ReviewCompanySummary {
+                    company_id: company.company_id,
+                }]),
+            )
+            .unwrap();
+
+            (token, user, movie_review)
+        };
+
+        let request = test::TestRequest::delete()
+            .uri(&format!(
+                "/movies/{}/reviews/{}",
+                movie_review.movie_id, movie_review.review_id
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<DeleteResponse>(response).await;
+        assert_eq!(1, result.data.count);
+
+        let (existing_review, company) = {
+            let mut conn = pool.get().unwrap();
+            let review =
+                MovieReview::find_by_review_id(&mut conn, user.user_id, movie_review.review_id);
+            let company = ReviewCompany::find_by_review(&mut conn, movie_review.review_id).unwrap();
+            (review, company)
+        };
+
+        assert!(existing_review.is_err());
+        assert_eq!(0, company.len());
+    }
+}
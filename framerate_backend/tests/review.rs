@@ -0,0 +1,683 @@
+pub mod common;
+
+mod find_all {
+    use crate::common::{data, process, setup};
+    use actix_web::{http::header::AUTHORIZATION, test};
+    use chrono::NaiveDate;
+    use framerate::{
+        review::{find_all, ReviewMediaType, ReviewResponse},
+        utils::response_body::PaginatedResponse,
+    };
+
+    #[actix_web::test]
+    async fn should_require_authentication() {
+        let (app, _) = setup::create_app(find_all).await;
+
+        let request = test::TestRequest::get().uri("/reviews").to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(401, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_not_return_other_users_reviews() {
+        let (app, pool) = setup::create_app(find_all).await;
+
+        let token = {
+            let mut conn = pool.get().unwrap();
+            let user = data::create_user(&mut conn);
+            let review = data::create_review(&mut conn, &user);
+            data::create_movie_review(&mut conn, &user, &review);
+            let (token, _) = data::create_authed_user(&mut conn);
+            token
+        };
+
+        let request = test::TestRequest::get()
+            .uri("/reviews")
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<Vec<ReviewResponse>>(response).await;
+        assert_eq!(0, result.data.len());
+    }
+
+    #[actix_web::test]
+    async fn should_return_all_media_types_by_date() {
+        let (app, pool) = setup::create_app(find_all).await;
+
+        let (token, movie_review, show_review, season_review, episode_review) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+
+            let date = NaiveDate::from_ymd_opt(2024, 1, 4).unwrap();
+            let review = data::create_dated_review(&mut conn, &user, date, Some(40));
+            let movie_review = data::create_movie_review(&mut conn, &user, &review);
+
+            let date = NaiveDate::from_ymd_opt(2024, 1, 2).unwrap();
+            let review = data::create_dated_review(&mut conn, &user, date, Some(60));
+            let show_review = data::create_show_review(&mut conn, &user, &review);
+
+            let date = NaiveDate::from_ymd_opt(2024, 1, 3).unwrap();
+            let review = data::create_dated_review(&mut conn, &user, date, Some(80));
+            let season_review = data::create_season_review(&mut conn, &user, &review);
+
+            let date = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+            let review = data::create_dated_review(&mut conn, &user, date, None);
+            let episode_review = data::create_episode_review(&mut conn, &user, &review);
+
+            // Reviews without any media are not part of the timeline
+            data::create_review(&mut conn, &user);
+
+            (
+                token,
+                movie_review,
+                show_review,
+                season_review,
+                episode_review,
+            )
+        };
+
+        let request = test::TestRequest::get()
+            .uri("/reviews")
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<Vec<ReviewResponse>>(response).await;
+        assert_eq!(4, result.data.len());
+
+        let movie = &result.data[0];
+        assert_eq!(movie_review.review_id, movie.review_id);
+        assert_eq!(ReviewMediaType::Movie, movie.media_type);
+        assert_eq!(movie_review.movie_id, movie.movie.as_ref().unwrap().id);
+
+        let season = &result.data[1];
+        assert_eq!(season_review.review_id, season.review_id);
+        assert_eq!(ReviewMediaType::Season, season.media_type);
+        assert_eq!(
+            season_review.season_number,
+            season.season.as_ref().unwrap().season_number
+        );
+
+        let show = &result.data[2];
+        assert_eq!(show_review.review_id, show.review_id);
+        assert_eq!(ReviewMediaType::Show, show.media_type);
+        assert_eq!(show_review.show_id, show.show.as_ref().unwrap().id);
+
+        let episode = &result.data[3];
+        assert_eq!(episode_review.review_id, episode.review_id);
+        assert_eq!(ReviewMediaType::Episode, episode.media_type);
+        assert_eq!(
+            episode_review.show_id,
+            episode.season.as_ref().unwrap().show_id
+        );
+        assert_eq!(
+            episode_review.episode_number,
+            episode.episode.as_ref().unwrap().episode_number
+        );
+    }
+
+    #[actix_web::test]
+    async fn should_filter_and_paginate() {
+        let (app, pool) = setup::create_app(find_all).await;
+
+        let (token, expected) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+
+            let mut expected = vec![];
+            for (day, rating) in [(1, 20), (2, 70), (3, 80), (4, 90)] {
+                let date = NaiveDate::from_ymd_opt(2024, 2, day).unwrap();
+                let review = data::create_dated_review(&mut conn, &user, date, Some(rating));
+                if day % 2 == 0 {
+                    data::create_movie_review(&mut conn, &user, &review);
+                } else {
+                    data::create_show_review(&mut conn, &user, &review);
+                }
+                expected.push(review.review_id);
+            }
+
+            (token, expected)
+        };
+
+        let request = test::TestRequest::get()
+            .uri("/reviews?ratingMin=50&sort=asc&page=2&pageSize=2")
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<PaginatedResponse<ReviewResponse>>(response).await;
+        assert_eq!(1, result.data.items.len());
+        assert_eq!(expected[3], result.data.items[0].review_id);
+        assert_eq!(ReviewMediaType::Movie, result.data.items[0].media_type);
+    }
+
+    #[actix_web::test]
+    async fn should_return_pagination_metadata() {
+        let (app, pool) = setup::create_app(find_all).await;
+
+        let token = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+
+            for _ in 0..3 {
+                let review = data::create_review(&mut conn, &user);
+                data::create_movie_review(&mut conn, &user, &review);
+            }
+
+            token
+        };
+
+        let request = test::TestRequest::get()
+            .uri("/reviews?page=1&pageSize=2")
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<PaginatedResponse<ReviewResponse>>(response).await;
+        assert_eq!(2, result.data.items.len());
+        assert_eq!(3, result.data.total);
+        assert_eq!(Some(1), result.data.page);
+        assert_eq!(Some(2), result.data.page_size);
+        assert!(result.data.next_cursor.is_some());
+    }
+
+    #[actix_web::test]
+    async fn should_follow_cursor_through_all_pages() {
+        let (app, pool) = setup::create_app(find_all).await;
+
+        let (token, mut expected) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+
+            let mut expected = vec![];
+            for (day, rating) in [(1, 40), (1, 60), (2, 60), (3, 80), (3, 20)] {
+                let date = NaiveDate::from_ymd_opt(2024, 3, day).unwrap();
+                let review = data::create_dated_review(&mut conn, &user, date, Some(rating));
+                if day == 2 {
+                    data::create_show_review(&mut conn, &user, &review);
+                } else {
+                    data::create_movie_review(&mut conn, &user, &review);
+                }
+                expected.push(review.review_id);
+            }
+
+            (token, expected)
+        };
+
+        let mut returned = vec![];
+        let mut uri = "/reviews?orderBy=date&sort=asc&page=1&pageSize=2".to_string();
+        let mut page = Some(1);
+        loop {
+            let request = test::TestRequest::get()
+                .uri(&uri)
+                .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+                .to_request();
+
+            let response = test::call_service(&app, request).await;
+            assert!(response.status().is_success());
+
+            let result = process::parse_body::<PaginatedResponse<ReviewResponse>>(response).await;
+            assert_eq!(5, result.data.total);
+            assert_eq!(page.take(), result.data.page);
+            returned.extend(result.data.items.iter().map(|review| review.review_id));
+
+            match result.data.next_cursor {
+                Some(cursor) => {
+                    uri = format!("/reviews?orderBy=date&sort=asc&pageSize=2&cursor={cursor}")
+                }
+                None => break,
+            }
+        }
+
+        expected[0..2].sort();
+        expected[3..5].sort();
+        assert_eq!(expected, returned);
+    }
+
+    #[actix_web::test]
+    async fn should_reject_invalid_cursor() {
+        let (app, pool) = setup::create_app(find_all).await;
+
+        let token = {
+            let mut conn = pool.get().unwrap();
+            let (token, _) = data::create_authed_user(&mut conn);
+            token
+        };
+
+        let request = test::TestRequest::get()
+            .uri("/reviews?cursor=not-a-cursor")
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(400, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_search_media_title() {
+        let (app, pool) = setup::create_app(find_all).await;
+
+        let (token, movie_review) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+
+            let review = data::create_review(&mut conn, &user);
+            let movie_review = data::create_movie_review(&mut conn, &user, &review);
+
+            let review = data::create_review(&mut conn, &user);
+            data::create_show_review(&mut conn, &user, &review);
+
+            (token, movie_review)
+        };
+
+        let request = test::TestRequest::get()
+            .uri("/reviews?query=fuzz")
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<Vec<ReviewResponse>>(response).await;
+        assert_eq!(1, result.data.len());
+        assert_eq!(movie_review.review_id, result.data[0].review_id);
+        assert!(result.data[0].snippet.is_some());
+    }
+}
+
+mod find_statistics {
+    use crate::common::{data, process, setup};
+    use actix_web::{http::header::AUTHORIZATION, test};
+    use chrono::NaiveDate;
+    use framerate::review::{find_statistics, ReviewStatistics};
+
+    #[actix_web::test]
+    async fn should_require_authentication() {
+        let (app, _) = setup::create_app(find_statistics).await;
+
+        let request = test::TestRequest::get()
+            .uri("/reviews/statistics")
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(401, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_not_include_other_users_reviews() {
+        let (app, pool) = setup::create_app(find_statistics).await;
+
+        let token = {
+            let mut conn = pool.get().unwrap();
+            let user = data::create_user(&mut conn);
+            let date = NaiveDate::from_ymd_opt(2024, 6, 10).unwrap();
+            data::create_dated_review(&mut conn, &user, date, Some(50));
+            let (token, _) = data::create_authed_user(&mut conn);
+            token
+        };
+
+        let request = test::TestRequest::get()
+            .uri("/reviews/statistics?period=month&date=2024-06-01")
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<ReviewStatistics>(response).await;
+
+        assert_eq!(0, result.data.count);
+        assert_eq!(None, result.data.average_rating);
+        assert_eq!(0, result.data.rating_histogram.len());
+    }
+
+    #[actix_web::test]
+    async fn should_return_december_month_statistics() {
+        let (app, pool) = setup::create_app(find_statistics).await;
+
+        let token = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            for (year, month, day) in [(2024, 11, 30), (2024, 12, 1), (2024, 12, 31), (2025, 1, 1)]
+            {
+                let date = NaiveDate::from_ymd_opt(year, month, day).unwrap();
+                data::create_dated_review(&mut conn, &user, date, Some(50));
+            }
+            token
+        };
+
+        let request = test::TestRequest::get()
+            .uri("/reviews/statistics?period=month&date=2024-12-15")
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<ReviewStatistics>(response).await;
+
+        assert_eq!(
+            NaiveDate::from_ymd_opt(2024, 12, 1).unwrap(),
+            result.data.from
+        );
+        assert_eq!(
+            NaiveDate::from_ymd_opt(2024, 12, 31).unwrap(),
+            result.data.to
+        );
+        assert_eq!(2, result.data.count);
+    }
+
+    #[actix_web::test]
+    async fn should_return_week_spanning_years() {
+        let (app, pool) = setup::create_app(find_statistics).await;
+
+        let token = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            for (year, month, day) in [(2024, 12, 29), (2024, 12, 30), (2025, 1, 5), (2025, 1, 6)] {
+                let date = NaiveDate::from_ymd_opt(year, month, day).unwrap();
+                data::create_dated_review(&mut conn, &user, date, Some(50));
+            }
+            token
+        };
+
+        let request = test::TestRequest::get()
+            .uri("/reviews/statistics?period=week&date=2025-01-01")
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<ReviewStatistics>(response).await;
+
+        assert_eq!(
+            NaiveDate::from_ymd_opt(2024, 12, 30).unwrap(),
+            result.data.from
+        );
+        assert_eq!(NaiveDate::from_ymd_opt(2025, 1, 5).unwrap(), result.data.to);
+        assert_eq!(2, result.data.count);
+    }
+
+    #[actix_web::test]
+    async fn should_require_custom_period_dates() {
+        let (app, pool) = setup::create_app(find_statistics).await;
+
+        let token = {
+            let mut conn = pool.get().unwrap();
+            let (token, _) = data::create_authed_user(&mut conn);
+            token
+        };
+
+        let request = test::TestRequest::get()
+            .uri("/reviews/statistics?period=custom&from=2024-01-01")
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(400, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_return_rating_summary_for_custom_period() {
+        let (app, pool) = setup::create_app(find_statistics).await;
+
+        let token = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            for (day, rating) in [(1, Some(40)), (2, Some(80)), (3, Some(80)), (4, None)] {
+                let date = NaiveDate::from_ymd_opt(2023, 3, day).unwrap();
+                data::create_dated_review(&mut conn, &user, date, rating);
+            }
+            token
+        };
+
+        let request = test::TestRequest::get()
+            .uri("/reviews/statistics?period=custom&from=2023-03-01&to=2023-03-04")
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<ReviewStatistics>(response).await;
+
+        assert_eq!(4, result.data.count);
+        assert_eq!(Some(200.0 / 3.0), result.data.average_rating);
+        assert_eq!(2, result.data.rating_histogram.len());
+
+        let lowest = result.data.rating_histogram.first().unwrap();
+        assert_eq!(40, lowest.rating);
+        assert_eq!(1, lowest.count);
+
+        let highest = result.data.rating_histogram.last().unwrap();
+        assert_eq!(80, highest.rating);
+        assert_eq!(2, highest.count);
+    }
+
+    #[actix_web::test]
+    async fn should_filter_by_media_type() {
+        let (app, pool) = setup::create_app(find_statistics).await;
+
+        let token = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let date = NaiveDate::from_ymd_opt(2022, 8, 1).unwrap();
+            let review = data::create_dated_review(&mut conn, &user, date, Some(60));
+            data::create_movie_review(&mut conn, &user, &review);
+            let review = data::create_dated_review(&mut conn, &user, date, Some(70));
+            data::create_show_review(&mut conn, &user, &review);
+            token
+        };
+
+        let request = test::TestRequest::get()
+            .uri("/reviews/statistics?period=year&date=2022-01-01&mediaType=movie")
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<ReviewStatistics>(response).await;
+
+        assert_eq!(1, result.data.count);
+        assert_eq!(Some(60.0), result.data.average_rating);
+    }
+}
+
+mod find_year_in_review {
+    use crate::common::{data, process, setup};
+    use actix_web::{http::header::AUTHORIZATION, test};
+    use chrono::NaiveDate;
+    use framerate::{
+        review::{find_year_in_review, Review, ReviewMediaType, YearInReview},
+        review_company::{ReviewCompany, ReviewCompanySummary},
+    };
+
+    #[actix_web::test]
+    async fn should_require_authentication() {
+        let (app, _) = setup::create_app(find_year_in_review).await;
+
+        let request = test::TestRequest::get()
+            .uri("/reviews/year/2024")
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(401, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_reject_invalid_year() {
+        let (app, pool) = setup::create_app(find_year_in_review).await;
+
+        let token = {
+            let mut conn = pool.get().unwrap();
+            let (token, _) = data::create_authed_user(&mut conn);
+            token
+        };
+
+        let request = test::TestRequest::get()
+            .uri("/reviews/year/999999")
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(400, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_not_include_other_users_reviews() {
+        let (app, pool) = setup::create_app(find_year_in_review).await;
+
+        let token = {
+            let mut conn = pool.get().unwrap();
+            let user = data::create_user(&mut conn);
+            let date = NaiveDate::from_ymd_opt(2024, 6, 10).unwrap();
+            let review = data::create_dated_review(&mut conn, &user, date, Some(50));
+            data::create_movie_review(&mut conn, &user, &review);
+            let (token, _) = data::create_authed_user(&mut conn);
+            token
+        };
+
+        let request = test::TestRequest::get()
+            .uri("/reviews/year/2024")
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<YearInReview>(response).await;
+        assert_eq!(2024, result.data.year);
+        assert_eq!(0, result.data.count);
+        assert!(result.data.media_types.iter().all(|media| media.count == 0));
+        assert!(result.data.highest_rated.is_none());
+        assert!(result.data.busiest_month.is_none());
+        assert!(result.data.first_watch.is_none());
+        assert!(result.data.top_companions.is_empty());
+    }
+
+    #[actix_web::test]
+    async fn should_return_year_in_review() {
+        let (app, pool) = setup::create_app(find_year_in_review).await;
+
+        let (token, reviews, friend) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let friend = data::create_company(&mut conn, &user);
+            let other_friend = data::create_company(&mut conn, &user);
+
+            let mut reviews = vec![];
+            for (month, day, rating, venue) in [
+                (1, 5, 40, "Cinema"),
+                (3, 2, 90, "Home"),
+                (3, 20, 60, "Cinema"),
+                (11, 30, 90, "Cinema"),
+            ] {
+                let date = NaiveDate::from_ymd_opt(2024, month, day).unwrap();
+                let review = data::create_dated_review(&mut conn, &user, date, Some(rating));
+                let review = Review::update(
+                    &mut conn,
+                    Review {
+                        venue: Some(venue.to_string()),
+                        ..review
+                    },
+                )
+                .unwrap();
+                reviews.push(review);
+            }
+
+            data::create_movie_review(&mut conn, &user, &reviews[0]);
+            data::create_show_review(&mut conn, &user, &reviews[1]);
+            data::create_season_review(&mut conn, &user, &reviews[2]);
+            data::create_movie_review(&mut conn, &user, &reviews[3]);
+
+            for review in &reviews[1..] {
+                ReviewCompany::replace(
+                    &mut conn,
+                    review.review_id,
+                    Some(&vec![ReviewCompanySummary {
+                        company_id: friend.company_id,
+                    }]),
+                )
+                .unwrap();
+            }
+            ReviewCompany::replace(
+                &mut conn,
+                reviews[0].review_id,
+                Some(&vec![ReviewCompanySummary {
+                    company_id: other_friend.company_id,
+                }]),
+            )
+            .unwrap();
+
+            let outside_year = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+            let review = data::create_dated_review(&mut conn, &user, outside_year, Some(100));
+            data::create_movie_review(&mut conn, &user, &review);
+
+            (token, reviews, friend)
+        };
+
+        let request = test::TestRequest::get()
+            .uri("/reviews/year/2024")
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let report = process::parse_body::<YearInReview>(response).await.data;
+
+        assert_eq!(4, report.count);
+        let media_type_count = |media_type| {
+            report
+                .media_types
+                .iter()
+                .find(|media| media.media_type == media_type)
+                .unwrap()
+                .count
+        };
+        assert_eq!(2, media_type_count(ReviewMediaType::Movie));
+        assert_eq!(1, media_type_count(ReviewMediaType::Show));
+        assert_eq!(1, media_type_count(ReviewMediaType::Season));
+        assert_eq!(0, media_type_count(ReviewMediaType::Episode));
+
+        assert_eq!(Some(70.0), report.average_rating);
+
+        let highest_rated = report.highest_rated.unwrap();
+        assert_eq!(reviews[1].review_id, highest_rated.review_id);
+        assert_eq!(ReviewMediaType::Show, highest_rated.media_type);
+        assert_eq!(Some("Doctor Who".to_string()), highest_rated.title);
+        let lowest_rated = report.lowest_rated.unwrap();
+        assert_eq!(reviews[0].review_id, lowest_rated.review_id);
+        assert_eq!(Some("Hot Fuzz".to_string()), lowest_rated.title);
+
+        let busiest_month = report.busiest_month.unwrap();
+        assert_eq!(3, busiest_month.month);
+        assert_eq!(2, busiest_month.count);
+
+        let top_venue = report.top_venue.unwrap();
+        assert_eq!("Cinema", top_venue.venue);
+        assert_eq!(3, top_venue.count);
+
+        assert_eq!(2, report.top_companions.len());
+        assert_eq!(friend.company_id, report.top_companions[0].company_id);
+        assert_eq!(3, report.top_companions[0].count);
+        assert_eq!(1, report.top_companions[1].count);
+
+        assert_eq!(reviews[0].review_id, report.first_watch.unwrap().review_id);
+        let last_watch = report.last_watch.unwrap();
+        assert_eq!(reviews[3].review_id, last_watch.review_id);
+        assert_eq!(4638, last_watch.media_id);
+    }
+}
@@ -0,0 +1,161 @@
+pub mod common;
+
+mod find {
+    use crate::common::{data, setup};
+    use actix_web::{http::header::CONTENT_TYPE, test};
+    use framerate::review::Review;
+    use framerate::review_feed::find;
+    use uuid::Uuid;
+
+    #[actix_web::test]
+    async fn should_not_find_private_feed_without_token() {
+        let (app, pool) = setup::create_app(find).await;
+
+        let user = {
+            let mut conn = pool.get().unwrap();
+            let user = data::create_user(&mut conn);
+            let review = data::create_review(&mut conn, &user);
+            data::create_movie_review(&mut conn, &user, &review);
+            user
+        };
+
+        let request = test::TestRequest::get()
+            .uri(&format!("/users/{}/reviews.atom", user.user_id))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(404, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_not_accept_other_users_token() {
+        let (app, pool) = setup::create_app(find).await;
+
+        let (user, feed_token) = {
+            let mut conn = pool.get().unwrap();
+            let user = data::create_user(&mut conn);
+            let other_user = data::create_user(&mut conn);
+            (user, data::create_feed_token(&mut conn, &other_user))
+        };
+
+        let request = test::TestRequest::get()
+            .uri(&format!(
+                "/users/{}/reviews.atom?token={}",
+                user.user_id, feed_token.token
+            ))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(404, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_find_private_feed_with_token() {
+        let (app, pool) = setup::create_app(find).await;
+
+        let (user, review, feed_token) = {
+            let mut conn = pool.get().unwrap();
+            let user = data::create_user(&mut conn);
+            let review = data::create_review(&mut conn, &user);
+            data::create_movie_review(&mut conn, &user, &review);
+            let feed_token = data::create_feed_token(&mut conn, &user);
+            (user, review, feed_token)
+        };
+
+        let request = test::TestRequest::get()
+            .uri(&format!(
+                "/users/{}/reviews.atom?token={}",
+                user.user_id, feed_token.token
+            ))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+        assert_eq!(
+            "application/atom+xml; charset=utf-8",
+            response.headers().get(CONTENT_TYPE).unwrap()
+        );
+
+        let body = String::from_utf8(test::read_body(response).await.to_vec()).unwrap();
+        assert!(body.contains(&format!("<id>urn:uuid:{}</id>", review.review_id)));
+        assert!(body.contains("<title>Hot Fuzz"));
+        assert!(!body.contains(&feed_token.token.to_string()));
+    }
+
+    #[actix_web::test]
+    async fn should_give_content_to_reviews_without_description() {
+        let (app, pool) = setup::create_app(find).await;
+
+        let user = {
+            let mut conn = pool.get().unwrap();
+            let user = data::create_public_user(&mut conn);
+            let review = Review::create(
+                &mut conn,
+                Review {
+                    review_id: Uuid::new_v4(),
+                    user_id: user.user_id,
+                    title: None,
+                    date: None,
+                    rating: None,
+                    description: None,
+                    venue: None,
+                },
+            )
+            .unwrap();
+            data::create_movie_review(&mut conn, &user, &review);
+            user
+        };
+
+        let request = test::TestRequest::get()
+            .uri(&format!("/users/{}/reviews.atom", user.user_id))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let body = String::from_utf8(test::read_body(response).await.to_vec()).unwrap();
+        assert!(body.contains("<content type=\"text\">Hot Fuzz</content>"));
+    }
+
+    #[actix_web::test]
+    async fn should_find_public_feed() {
+        let (app, pool) = setup::create_app(find).await;
+
+        let user = {
+            let mut conn = pool.get().unwrap();
+            let user = data::create_public_user(&mut conn);
+            for _ in 0..2 {
+                let review = data::create_review(&mut conn, &user);
+                data::create_movie_review(&mut conn, &user, &review);
+            }
+            let review = data::create_review(&mut conn, &user);
+            data::create_season_review(&mut conn, &user, &review);
+            user
+        };
+
+        let request = test::TestRequest::get()
+            .uri(&format!("/users/{}/reviews.atom", user.user_id))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let body = String::from_utf8(test::read_body(response).await.to_vec()).unwrap();
+        assert!(body.starts_with("<?xml"));
+        assert_eq!(3, body.matches("<entry>").count());
+        assert_eq!(2, body.matches("<category term=\"movie\"/>").count());
+        assert_eq!(1, body.matches("<category term=\"season\"/>").count());
+    }
+
+    #[actix_web::test]
+    async fn should_not_find_unknown_user() {
+        let (app, _) = setup::create_app(find).await;
+
+        let request = test::TestRequest::get()
+            .uri(&format!("/users/{}/reviews.atom", Uuid::new_v4()))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(404, response.status());
+    }
+}
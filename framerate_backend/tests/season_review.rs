@@ -1,5 +1,117 @@
 pub mod common;
 
+mod find_all {
+    use crate::common::{data, process, setup};
+    use actix_web::{http::header::AUTHORIZATION, test};
+    use chrono::NaiveDate;
+    use framerate::{season_review::find_all, season_review::SeasonReviewResponse};
+
+    #[actix_web::test]
+    async fn should_require_authentication() {
+        let (app, _) = setup::create_app(find_all).await;
+
+        let request = test::TestRequest::get()
+            .uri("/shows/seasons/reviews")
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(401, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_not_return_other_users_reviews() {
+        let (app, pool) = setup::create_app(find_all).await;
+
+        let token = {
+            let mut conn = pool.get().unwrap();
+            let user = data::create_user(&mut conn);
+            let review = data::create_review(&mut conn, &user);
+            data::create_season_review(&mut conn, &user, &review);
+            let (token, _) = data::create_authed_user(&mut conn);
+            token
+        };
+
+        let request = test::TestRequest::get()
+            .uri("/shows/seasons/reviews")
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<Vec<SeasonReviewResponse>>(response).await;
+        assert_eq!(0, result.data.len());
+    }
+
+    #[actix_web::test]
+    async fn should_return_user_reviews() {
+        let (app, pool) = setup::create_app(find_all).await;
+
+        let (user, token, review, season_review) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let review = data::create_review(&mut conn, &user);
+            let season_review = data::create_season_review(&mut conn, &user, &review);
+            (user, token, review, season_review)
+        };
+
+        let request = test::TestRequest::get()
+            .uri("/shows/seasons/reviews")
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<Vec<SeasonReviewResponse>>(response).await;
+        assert_eq!(1, result.data.len());
+
+        let returned_review = result.data.first().unwrap();
+        assert_eq!(review.review_id, returned_review.review_id);
+        assert_eq!(user.user_id, returned_review.user_id);
+        assert_eq!(review.date, returned_review.date);
+        assert_eq!(review.rating, returned_review.rating);
+        assert_eq!(season_review.show_id, returned_review.season.show_id);
+        assert_eq!(
+            season_review.season_number,
+            returned_review.season.season_number
+        );
+        assert_eq!(season_review.name, returned_review.season.name);
+    }
+
+    #[actix_web::test]
+    async fn should_filter_by_release_date() {
+        let (app, pool) = setup::create_app(find_all).await;
+
+        let (token, expected) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+
+            let review = data::create_review(&mut conn, &user);
+            let air_date = NaiveDate::from_ymd_opt(2005, 3, 26).unwrap();
+            data::create_released_season_review(&mut conn, &user, &review, air_date);
+
+            let expected = data::create_review(&mut conn, &user);
+            let air_date = NaiveDate::from_ymd_opt(2010, 4, 3).unwrap();
+            data::create_released_season_review(&mut conn, &user, &expected, air_date);
+
+            (token, expected)
+        };
+
+        let request = test::TestRequest::get()
+            .uri("/shows/seasons/reviews?releaseFrom=2010-01-01")
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<Vec<SeasonReviewResponse>>(response).await;
+        assert_eq!(1, result.data.len());
+        assert_eq!(expected.review_id, result.data[0].review_id);
+    }
+}
+
 mod find_by_review_id {
     use crate::common::{data, process, setup};
     use actix_web::{http::header::AUTHORIZATION, test};
@@ -202,6 +314,11 @@ mod find_by_show_season {
 mod create {
     use crate::common::{data, process, setup};
     use actix_web::{http::header::AUTHORIZATION, test};
+    use framerate::{
+        collection::{Collection, DEFAULT_WATCHED},
+        show::SHOW_MEDIA_TYPE,
+        show_entry::ShowEntry,
+    };
     use framerate::{
         review_company::ReviewCompanySummary,
         season_review::{create, SeasonReview, SeasonReviewResponse},
@@ -352,6 +469,104 @@ mod create {
         assert_eq!(company_details.first_name, result_company.first_name);
         assert_eq!(company_details.last_name, result_company.last_name);
     }
+
+    #[actix_web::test]
+    async fn should_keep_show_on_watchlist_before_final_season() {
+        let (app, pool) = setup::create_app(create).await;
+
+        let (token, watchlist) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            data::set_watchlist_on_review(&mut conn, &user, "remove");
+            let watchlist = data::create_default_show_watchlist(&mut conn, &user);
+            data::create_show_entry(&mut conn, &user, &watchlist);
+            (token, watchlist)
+        };
+
+        let season = data::generate_sample_season();
+
+        let request = test::TestRequest::post()
+            .uri(&format!(
+                "/shows/{}/seasons/{}/reviews",
+                season.show_id, season.season_number
+            ))
+            .set_json(data::generate_save_season_review())
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<SeasonReviewResponse>(response).await;
+        assert!(result.data.watchlist_removal.is_none());
+
+        let mut conn = pool.get().unwrap();
+        let entries = ShowEntry::find_all(
+            &mut conn,
+            watchlist.user_id,
+            watchlist.collection_id,
+            Default::default(),
+        )
+        .unwrap();
+        assert_eq!(1, entries.len());
+    }
+
+    #[actix_web::test]
+    async fn should_move_reviewed_show_to_watched_collection() {
+        let (app, pool) = setup::create_app(create).await;
+
+        let (token, show_entry) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            data::set_watchlist_on_review(&mut conn, &user, "move");
+            let watchlist = data::create_default_show_watchlist(&mut conn, &user);
+            let show_entry = data::create_show_entry(&mut conn, &user, &watchlist);
+            (token, show_entry)
+        };
+
+        // The final season of a show that has ended
+        let season = data::generate_sample_season();
+        let final_season = 13;
+
+        let request = test::TestRequest::post()
+            .uri(&format!(
+                "/shows/{}/seasons/{}/reviews",
+                season.show_id, final_season
+            ))
+            .set_json(data::generate_save_season_review())
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<SeasonReviewResponse>(response).await;
+        let removal = result.data.watchlist_removal.unwrap();
+        assert_eq!(show_entry.show_id, removal.entry.show_id);
+
+        let (watched, entries) = {
+            let mut conn = pool.get().unwrap();
+            let watched = Collection::find_default(
+                &mut conn,
+                show_entry.user_id,
+                SHOW_MEDIA_TYPE,
+                DEFAULT_WATCHED,
+            )
+            .unwrap();
+            let entries = ShowEntry::find_all(
+                &mut conn,
+                show_entry.user_id,
+                watched.collection_id,
+                Default::default(),
+            )
+            .unwrap();
+            (watched, entries)
+        };
+
+        assert_eq!(Some(watched.collection_id), removal.moved_to);
+        assert_eq!(1, entries.len());
+        assert_eq!(show_entry.show_id, entries[0].show_id);
+    }
 }
 
 mod update {
@@ -617,3 +832,117 @@ mod update {
         assert_eq!(&0, &company.len());
     }
 }
+
+mod delete {
+    use crate::common::{data, process, setup};
+    use actix_web::{http::header::AUTHORIZATION, test};
+    use framerate::{
+        review_company::{ReviewCompany, ReviewCompanySummary},
+        season_review::{delete, SeasonReview},
+        utils::response_body::DeleteResponse,
+    };
+
+    #[actix_web::test]
+    async fn should_require_authentication() {
+        let (app, pool) = setup::create_app(delete).await;
+
+        let season_review = {
+            let mut conn = pool.get().unwrap();
+            let user = data::create_user(&mut conn);
+            let review = data::create_review(&mut conn, &user);
+            data::create_season_review(&mut conn, &user, &review)
+        };
+
+        let request = test::TestRequest::delete()
+            .uri(&format!(
+                "/shows/{}/seasons/{}/reviews/{}",
+                season_review.show_id, season_review.season_number, season_review.review_id
+            ))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(401, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_not_delete_other_users_review() {
+        let (app, pool) = setup::create_app(delete).await;
+
+        let (token, user, season_review) = {
+            let mut conn = pool.get().unwrap();
+            let user = data::create_user(&mut conn);
+            let review = data::create_review(&mut conn, &user);
+            let season_review = data::create_season_review(&mut conn, &user, &review);
+            let (token, _) = data::create_authed_user(&mut conn);
+            (token, user, season_review)
+        };
+
+        let request = test::TestRequest::delete()
+            .uri(&format!(
+                "/shows/{}/seasons/{}/reviews/{}",
+                season_review.show_id, season_review.season_number, season_review.review_id
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(404, response.status());
+
+        let existing_review = {
+            let mut conn = pool.get().unwrap();
+            SeasonReview::find_by_review_id(&mut conn, user.user_id, season_review.review_id)
+        };
+
+        assert!(existing_review.is_ok());
+    }
+
+    #[actix_web::test]
+    async fn should_delete_review() {
+        let (app, pool) = setup::create_app(delete).await;
+
+        let (token, user, season_review) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let review = data::create_review(&mut conn, &user);
+            let season_review = data::create_season_review(&mut conn, &user, &review);
+            let company = data::create_company(&mut conn, &user);
+
+            ReviewCompany::replace(
+                &mut conn,
+                season_review.review_id,
+                Some(&vec![ReviewCompanySummary {
+                    company_id: company.company_id,
+                }]),
+            )
+            .unwrap();
+
+            (token, user, season_review)
+        };
+
+        let request = test::TestRequest::delete()
+            .uri(&format!(
+                "/shows/{}/seasons/{}/reviews/{}",
+                season_review.show_id, season_review.season_number, season_review.review_id
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<DeleteResponse>(response).await;
+        assert_eq!(1, result.data.count);
+
+        let (existing_review, company) = {
+            let mut conn = pool.get().unwrap();
+            let review =
+                SeasonReview::find_by_review_id(&mut conn, user.user_id, season_review.review_id);
+            let company =
+                ReviewCompany::find_by_review(&mut conn, season_review.review_id).unwrap();
+            (review, company)
+        };
+
+        assert!(existing_review.is_err());
+        assert_eq!(0, company.len());
+    }
+}
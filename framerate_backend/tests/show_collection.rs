@@ -3,6 +3,9 @@ pub mod common;
 mod find {
     use crate::common::{data, process, setup};
     use actix_web::{http::header::AUTHORIZATION, test};
+    use chrono::{NaiveDate, TimeDelta, Utc};
+    use framerate::collection::{CollectionRules, RuleSource};
+    use framerate::collection_member::COLLECTION_VIEWER;
     use framerate::show_collection::{find, ShowCollection};
 
     #[actix_web::test]
@@ -74,11 +77,175 @@ mod find {
         assert_eq!(collection.name, result.data.name);
         assert_eq!(&show_entry, returned_entry.first().unwrap());
     }
+
+    #[actix_web::test]
+    async fn should_sort_show_collection() {
+        let (app, pool) = setup::create_app(find).await;
+
+        let (token, collection) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let collection = data::create_show_collection(&mut conn, &user);
+            data::create_show_entry(&mut conn, &user, &collection);
+            data::create_named_show_entry(
+                &mut conn,
+                &user,
+                &collection,
+                1165,
+                "Torchwood",
+                NaiveDate::from_ymd_opt(2006, 10, 22).unwrap(),
+            );
+            (token, collection)
+        };
+
+        for (sort, expected) in [
+            ("", ["Torchwood", "Doctor Who"]),
+            ("?sort=releaseDate", ["Torchwood", "Doctor Who"]),
+            ("?sort=title", ["Doctor Who", "Torchwood"]),
+        ] {
+            let request = test::TestRequest::get()
+                .uri(&format!(
+                    "/shows/collections/{}{sort}",
+                    collection.collection_id
+                ))
+                .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+                .to_request();
+
+            let response = test::call_service(&app, request).await;
+            assert!(response.status().is_success());
+
+            let result = process::parse_body::<ShowCollection>(response).await;
+            let names: Vec<String> = result
+                .data
+                .entries
+                .unwrap()
+                .into_iter()
+                .map(|entry| entry.name)
+                .collect();
+
+            assert_eq!(expected.to_vec(), names, "{sort}");
+        }
+    }
+
+    #[actix_web::test]
+    async fn should_reject_unknown_sort() {
+        let (app, pool) = setup::create_app(find).await;
+
+        let (token, collection) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let collection = data::create_show_collection(&mut conn, &user);
+            (token, collection)
+        };
+
+        let request = test::TestRequest::get()
+            .uri(&format!(
+                "/shows/collections/{}?sort=rating",
+                collection.collection_id
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(400, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_return_shared_show_collection() {
+        let (app, pool) = setup::create_app(find).await;
+
+        let (token, collection, show_entry) = {
+            let mut conn = pool.get().unwrap();
+            let owner = data::create_user(&mut conn);
+            let collection = data::create_show_collection(&mut conn, &owner);
+            let show_entry = data::create_show_entry(&mut conn, &owner, &collection);
+            let (token, user) = data::create_authed_user(&mut conn);
+            data::create_collection_member(&mut conn, &collection, &user, COLLECTION_VIEWER);
+            (token, collection, show_entry)
+        };
+
+        let request = test::TestRequest::get()
+            .uri(&format!("/shows/collections/{}", collection.collection_id))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<ShowCollection>(response).await;
+
+        assert_eq!(vec![show_entry], result.data.entries.unwrap());
+    }
+
+    #[actix_web::test]
+    async fn should_return_smart_show_collection_from_entries() {
+        let (app, pool) = setup::create_app(find).await;
+
+        let (token, collection, show_entry) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let watchlist = data::create_default_show_watchlist(&mut conn, &user);
+            let show_entry = data::create_upcoming_show_entry(
+                &mut conn,
+                &user,
+                &watchlist,
+                Utc::now().date_naive() + TimeDelta::days(10),
+            );
+            let other_collection = data::create_show_collection(&mut conn, &user);
+            data::create_upcoming_show_entry(
+                &mut conn,
+                &user,
+                &other_collection,
+                Utc::now().date_naive() + TimeDelta::days(10),
+            );
+            data::create_named_show_entry(
+                &mut conn,
+                &user,
+                &watchlist,
+                1165,
+                "Torchwood",
+                NaiveDate::from_ymd_opt(2006, 10, 22).unwrap(),
+            );
+
+            let collection = data::create_smart_show_collection(
+                &mut conn,
+                &user,
+                CollectionRules {
+                    source: RuleSource::Entries,
+                    min_rating: None,
+                    company_id: None,
+                    tag_id: None,
+                    collection_id: None,
+                    watchlist: true,
+                    statuses: Some(vec![show_entry.status.clone().unwrap()]),
+                    upcoming_days: Some(30),
+                },
+            );
+            (token, collection, show_entry)
+        };
+
+        let request = test::TestRequest::get()
+            .uri(&format!("/shows/collections/{}", collection.collection_id))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<ShowCollection>(response).await;
+        let entries = result.data.entries.unwrap();
+
+        assert_eq!(1, entries.len());
+        assert_eq!(collection.collection_id, entries[0].collection_id);
+        assert_eq!(show_entry.show_id, entries[0].show_id);
+        assert_eq!(show_entry.next_air_date, entries[0].next_air_date);
+    }
 }
 
 mod find_all {
     use crate::common::{data, process, setup};
     use actix_web::{http::header::AUTHORIZATION, test};
+    use framerate::collection_member::COLLECTION_VIEWER;
     use framerate::show_collection::{find_all, ShowCollection};
 
     #[actix_web::test]
@@ -151,6 +318,42 @@ mod find_all {
         assert_eq!(collection.name, returned_collection.name);
         assert_eq!(None, returned_collection.entries);
     }
+
+    #[actix_web::test]
+    async fn should_return_shared_show_collections() {
+        let (app, pool) = setup::create_app(find_all).await;
+
+        let (token, collection) = {
+            let mut conn = pool.get().unwrap();
+            let owner = data::create_user(&mut conn);
+            let collection = data::create_show_collection(&mut conn, &owner);
+            let invited_collection = data::create_show_collection(&mut conn, &owner);
+            let (token, user) = data::create_authed_user(&mut conn);
+            data::create_collection_member(&mut conn, &collection, &user, COLLECTION_VIEWER);
+            data::create_collection_invite(
+                &mut conn,
+                &invited_collection,
+                &user,
+                COLLECTION_VIEWER,
+            );
+            (token, collection)
+        };
+
+        let request = test::TestRequest::get()
+            .uri("/shows/collections")
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<Vec<ShowCollection>>(response).await;
+        assert_eq!(1, result.data.len());
+
+        let returned_collection = result.data.first().unwrap();
+        assert_eq!(collection.collection_id, returned_collection.collection_id);
+        assert_eq!(collection.user_id, returned_collection.user_id);
+    }
 }
 
 mod create {
@@ -438,10 +641,197 @@ mod create_entry {
     }
 }
 
+mod update_entry {
+    use crate::common::{data, process, setup};
+    use actix_web::{http::header::AUTHORIZATION, test};
+    use chrono::NaiveDate;
+    use framerate::{
+        show_collection::update_entry,
+        show_entry::{ShowEntry, UpdatedShowEntry},
+    };
+
+    fn generate_updated_show_entry() -> UpdatedShowEntry {
+        UpdatedShowEntry {
+            added_at: NaiveDate::from_ymd_opt(2024, 12, 24)
+                .unwrap()
+                .and_hms_opt(20, 0, 0),
+            note: Some(Some("Recommended by Sam".to_string())),
+            priority: Some(Some(5)),
+        }
+    }
+
+    #[actix_web::test]
+    async fn should_require_authentication() {
+        let (app, pool) = setup::create_app(update_entry).await;
+
+        let show_entry = {
+            let mut conn = pool.get().unwrap();
+            let user = data::create_user(&mut conn);
+            let collection = data::create_show_collection(&mut conn, &user);
+            data::create_show_entry(&mut conn, &user, &collection)
+        };
+
+        let request = test::TestRequest::put()
+            .uri(&format!(
+                "/shows/collections/{}/{}",
+                show_entry.collection_id, show_entry.show_id
+            ))
+            .set_json(generate_updated_show_entry())
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(401, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_not_update_other_users_show_entry() {
+        let (app, pool) = setup::create_app(update_entry).await;
+
+        let (token, show_entry) = {
+            let mut conn = pool.get().unwrap();
+            let user = data::create_user(&mut conn);
+            let collection = data::create_show_collection(&mut conn, &user);
+            let show_entry = data::create_show_entry(&mut conn, &user, &collection);
+            let (token, _) = data::create_authed_user(&mut conn);
+            (token, show_entry)
+        };
+
+        let request = test::TestRequest::put()
+            .uri(&format!(
+                "/shows/collections/{}/{}",
+                show_entry.collection_id, show_entry.show_id
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .set_json(generate_updated_show_entry())
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(404, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_not_update_with_invalid_priority() {
+        let (app, pool) = setup::create_app(update_entry).await;
+
+        let (token, show_entry) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let collection = data::create_show_collection(&mut conn, &user);
+            let show_entry = data::create_show_entry(&mut conn, &user, &collection);
+            (token, show_entry)
+        };
+
+        let request = test::TestRequest::put()
+            .uri(&format!(
+                "/shows/collections/{}/{}",
+                show_entry.collection_id, show_entry.show_id
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .set_json(UpdatedShowEntry {
+                priority: Some(Some(6)),
+                ..generate_updated_show_entry()
+            })
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(400, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_update_show_entry() {
+        let (app, pool) = setup::create_app(update_entry).await;
+
+        let (token, show_entry) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let collection = data::create_show_collection(&mut conn, &user);
+            let show_entry = data::create_show_entry(&mut conn, &user, &collection);
+            (token, show_entry)
+        };
+
+        let updated_entry = generate_updated_show_entry();
+
+        let request = test::TestRequest::put()
+            .uri(&format!(
+                "/shows/collections/{}/{}",
+                show_entry.collection_id, show_entry.show_id
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .set_json(&updated_entry)
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<ShowEntry>(response).await;
+
+        assert_eq!(
+            ShowEntry {
+                added_at: updated_entry.added_at.unwrap(),
+                note: updated_entry.note.flatten(),
+                priority: updated_entry.priority.flatten(),
+                ..show_entry
+            },
+            result.data
+        );
+    }
+
+    #[actix_web::test]
+    async fn should_only_update_given_fields() {
+        let (app, pool) = setup::create_app(update_entry).await;
+
+        let (token, show_entry) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let collection = data::create_show_collection(&mut conn, &user);
+            let show_entry = data::create_show_entry(&mut conn, &user, &collection);
+            let show_entry = ShowEntry::update(
+                &mut conn,
+                collection.collection_id,
+                show_entry.show_id,
+                UpdatedShowEntry {
+                    priority: Some(Some(3)),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+            (token, show_entry)
+        };
+
+        let request = test::TestRequest::put()
+            .uri(&format!(
+                "/shows/collections/{}/{}",
+                show_entry.collection_id, show_entry.show_id
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .set_json(serde_json::json!({ "note": "Recommended by Sam" }))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<ShowEntry>(response).await;
+
+        assert_eq!(
+            ShowEntry {
+                note: Some("Recommended by Sam".to_string()),
+                ..show_entry
+            },
+            result.data
+        );
+    }
+}
+
 mod delete_entry {
     use crate::common::{data, process, setup};
     use actix_web::{http::header::AUTHORIZATION, test};
-    use framerate::{show_collection::delete_entry, utils::response_body::DeleteResponse};
+    use framerate::{
+        collection_change::{CollectionChange, ENTRY_REMOVED},
+        collection_member::{COLLECTION_EDITOR, COLLECTION_VIEWER},
+        show::SHOW_MEDIA_TYPE,
+        show_collection::delete_entry,
+        utils::response_body::DeleteResponse,
+    };
 
     #[actix_web::test]
     async fn should_require_authentication() {
@@ -517,6 +907,200 @@ mod delete_entry {
 
         assert_eq!(1, result.data.count);
     }
+
+    #[actix_web::test]
+    async fn should_not_delete_show_entry_as_viewer() {
+        let (app, pool) = setup::create_app(delete_entry).await;
+
+        let (token, show_entry) = {
+            let mut conn = pool.get().unwrap();
+            let owner = data::create_user(&mut conn);
+            let collection = data::create_show_collection(&mut conn, &owner);
+            let show_entry = data::create_show_entry(&mut conn, &owner, &collection);
+            let (token, user) = data::create_authed_user(&mut conn);
+            data::create_collection_member(&mut conn, &collection, &user, COLLECTION_VIEWER);
+            (token, show_entry)
+        };
+
+        let request = test::TestRequest::delete()
+            .uri(&format!(
+                "/shows/collections/{}/{}",
+                show_entry.collection_id, show_entry.show_id
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(404, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_delete_show_entry_as_editor() {
+        let (app, pool) = setup::create_app(delete_entry).await;
+
+        let (token, user, show_entry) = {
+            let mut conn = pool.get().unwrap();
+            let owner = data::create_user(&mut conn);
+            let collection = data::create_show_collection(&mut conn, &owner);
+            let show_entry = data::create_show_entry(&mut conn, &owner, &collection);
+            let (token, user) = data::create_authed_user(&mut conn);
+            data::create_collection_member(&mut conn, &collection, &user, COLLECTION_EDITOR);
+            (token, user, show_entry)
+        };
+
+        let request = test::TestRequest::delete()
+            .uri(&format!(
+                "/shows/collections/{}/{}",
+                show_entry.collection_id, show_entry.show_id
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let changes = {
+            let mut conn = pool.get().unwrap();
+            CollectionChange::find_all(&mut conn, show_entry.collection_id).unwrap()
+        };
+
+        assert_eq!(1, changes.len());
+        assert_eq!(user.user_id, changes[0].user_id);
+        assert_eq!(SHOW_MEDIA_TYPE, changes[0].media_type);
+        assert_eq!(show_entry.show_id, changes[0].media_id);
+        assert_eq!(ENTRY_REMOVED, changes[0].action);
+    }
+}
+
+mod reorder {
+    use crate::common::{data, process, setup};
+    use actix_web::{http::header::AUTHORIZATION, test};
+    use chrono::NaiveDate;
+    use framerate::show_collection::{reorder, ReorderShowCollectionRequest, ShowCollection};
+
+    #[actix_web::test]
+    async fn should_require_authentication() {
+        let (app, pool) = setup::create_app(reorder).await;
+
+        let collection = {
+            let mut conn = pool.get().unwrap();
+            let user = data::create_user(&mut conn);
+            data::create_show_collection(&mut conn, &user)
+        };
+
+        let request = test::TestRequest::put()
+            .uri(&format!(
+                "/shows/collections/{}/order",
+                collection.collection_id
+            ))
+            .set_json(ReorderShowCollectionRequest { show_ids: vec![] })
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(401, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_not_reorder_other_users_show_collection() {
+        let (app, pool) = setup::create_app(reorder).await;
+
+        let (token, collection, show_entry) = {
+            let mut conn = pool.get().unwrap();
+            let user = data::create_user(&mut conn);
+            let collection = data::create_show_collection(&mut conn, &user);
+            let show_entry = data::create_show_entry(&mut conn, &user, &collection);
+            let (token, _) = data::create_authed_user(&mut conn);
+            (token, collection, show_entry)
+        };
+
+        let request = test::TestRequest::put()
+            .uri(&format!(
+                "/shows/collections/{}/order",
+                collection.collection_id
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .set_json(ReorderShowCollectionRequest {
+                show_ids: vec![show_entry.show_id],
+            })
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(404, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_not_reorder_unknown_entries() {
+        let (app, pool) = setup::create_app(reorder).await;
+
+        let (token, collection, show_entry) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let collection = data::create_show_collection(&mut conn, &user);
+            let show_entry = data::create_show_entry(&mut conn, &user, &collection);
+            (token, collection, show_entry)
+        };
+
+        for show_ids in [
+            vec![show_entry.show_id, 1165],
+            vec![show_entry.show_id, show_entry.show_id],
+        ] {
+            let request = test::TestRequest::put()
+                .uri(&format!(
+                    "/shows/collections/{}/order",
+                    collection.collection_id
+                ))
+                .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+                .set_json(ReorderShowCollectionRequest { show_ids })
+                .to_request();
+
+            let response = test::call_service(&app, request).await;
+            assert_eq!(400, response.status());
+        }
+    }
+
+    #[actix_web::test]
+    async fn should_reorder_show_collection() {
+        let (app, pool) = setup::create_app(reorder).await;
+
+        let (token, collection, show_entry, other_entry) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let collection = data::create_show_collection(&mut conn, &user);
+            let show_entry = data::create_show_entry(&mut conn, &user, &collection);
+            let other_entry = data::create_named_show_entry(
+                &mut conn,
+                &user,
+                &collection,
+                1165,
+                "Torchwood",
+                NaiveDate::from_ymd_opt(2006, 10, 22).unwrap(),
+            );
+            (token, collection, show_entry, other_entry)
+        };
+
+        let request = test::TestRequest::put()
+            .uri(&format!(
+                "/shows/collections/{}/order",
+                collection.collection_id
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .set_json(ReorderShowCollectionRequest {
+                show_ids: vec![other_entry.show_id, show_entry.show_id],
+            })
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<ShowCollection>(response).await;
+        let entries = result.data.entries.unwrap();
+
+        assert_eq!(2, entries.len());
+        assert_eq!(other_entry.show_id, entries[0].show_id);
+        assert_eq!(Some(0), entries[0].position);
+        assert_eq!(show_entry.show_id, entries[1].show_id);
+        assert_eq!(Some(1), entries[1].position);
+    }
 }
 
 mod find_by_show {
@@ -596,3 +1180,308 @@ mod find_by_show {
         assert_eq!(&collection.collection_id, returned_collection_id);
     }
 }
+
+mod copy_entries {
+    use crate::common::{data, process, setup};
+    use actix_web::{http::header::AUTHORIZATION, test};
+    use chrono::NaiveDate;
+    use framerate::{
+        show_collection::{copy_entries, TransferShowCollectionRequest},
+        show_entry::{ShowEntry, UpdatedShowEntry},
+    };
+
+    #[actix_web::test]
+    async fn should_require_authentication() {
+        let (app, pool) = setup::create_app(copy_entries).await;
+
+        let (collection, target) = {
+            let mut conn = pool.get().unwrap();
+            let user = data::create_user(&mut conn);
+            let collection = data::create_show_collection(&mut conn, &user);
+            let target = data::create_show_collection(&mut conn, &user);
+            (collection, target)
+        };
+
+        let request = test::TestRequest::post()
+            .uri(&format!(
+                "/shows/collections/{}/copy",
+                collection.collection_id
+            ))
+            .set_json(TransferShowCollectionRequest {
+                target_collection_id: target.collection_id,
+                show_ids: vec![],
+            })
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(401, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_not_copy_to_other_users_show_collection() {
+        let (app, pool) = setup::create_app(copy_entries).await;
+
+        let (token, show_entry, target) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let collection = data::create_show_collection(&mut conn, &user);
+            let show_entry = data::create_show_entry(&mut conn, &user, &collection);
+            let other_user = data::create_user(&mut conn);
+            let target = data::create_show_collection(&mut conn, &other_user);
+            (token, show_entry, target)
+        };
+
+        let request = test::TestRequest::post()
+            .uri(&format!(
+                "/shows/collections/{}/copy",
+                show_entry.collection_id
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .set_json(TransferShowCollectionRequest {
+                target_collection_id: target.collection_id,
+                show_ids: vec![show_entry.show_id],
+            })
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(404, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_not_copy_unknown_entries() {
+        let (app, pool) = setup::create_app(copy_entries).await;
+
+        let (token, show_entry, target) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let collection = data::create_show_collection(&mut conn, &user);
+            let show_entry = data::create_show_entry(&mut conn, &user, &collection);
+            let target = data::create_show_collection(&mut conn, &user);
+            (token, show_entry, target)
+        };
+
+        let request = test::TestRequest::post()
+            .uri(&format!(
+                "/shows/collections/{}/copy",
+                show_entry.collection_id
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .set_json(TransferShowCollectionRequest {
+                target_collection_id: target.collection_id,
+                show_ids: vec![show_entry.show_id, 1],
+            })
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(400, response.status());
+
+        let entries = {
+            let mut conn = pool.get().unwrap();
+            ShowEntry::find_all(
+                &mut conn,
+                target.user_id,
+                target.collection_id,
+                Default::default(),
+            )
+            .unwrap()
+        };
+        assert!(entries.is_empty());
+    }
+
+    #[actix_web::test]
+    async fn should_copy_show_entries() {
+        let (app, pool) = setup::create_app(copy_entries).await;
+
+        let (token, collection, target, show_entry, other_show_entry) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let collection = data::create_show_collection(&mut conn, &user);
+            let show_entry = data::create_show_entry(&mut conn, &user, &collection);
+            let show_entry = ShowEntry::update(
+                &mut conn,
+                collection.collection_id,
+                show_entry.show_id,
+                UpdatedShowEntry {
+                    added_at: None,
+                    note: Some(Some("Watch on a big screen".to_string())),
+                    priority: Some(Some(4)),
+                },
+            )
+            .unwrap();
+            let other_show_entry = data::create_named_show_entry(
+                &mut conn,
+                &user,
+                &collection,
+                1165,
+                "Torchwood",
+                NaiveDate::from_ymd_opt(2006, 10, 22).unwrap(),
+            );
+            let target = data::create_show_collection(&mut conn, &user);
+            (token, collection, target, show_entry, other_show_entry)
+        };
+
+        let request = test::TestRequest::post()
+            .uri(&format!(
+                "/shows/collections/{}/copy",
+                collection.collection_id
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .set_json(TransferShowCollectionRequest {
+                target_collection_id: target.collection_id,
+                show_ids: vec![show_entry.show_id, other_show_entry.show_id],
+            })
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<Vec<ShowEntry>>(response).await;
+        assert_eq!(2, result.data.len());
+
+        let copied_entry = result
+            .data
+            .iter()
+            .find(|entry| entry.show_id == show_entry.show_id)
+            .unwrap();
+        assert_eq!(target.collection_id, copied_entry.collection_id);
+        assert_eq!(show_entry.name, copied_entry.name);
+        assert_eq!(show_entry.note, copied_entry.note);
+        assert_eq!(show_entry.priority, copied_entry.priority);
+
+        let entries = {
+            let mut conn = pool.get().unwrap();
+            ShowEntry::find_all(
+                &mut conn,
+                collection.user_id,
+                collection.collection_id,
+                Default::default(),
+            )
+            .unwrap()
+        };
+        assert_eq!(2, entries.len());
+    }
+}
+
+mod move_entries {
+    use crate::common::{data, process, setup};
+    use actix_web::{http::header::AUTHORIZATION, test};
+    use framerate::{
+        collection_change::{CollectionChange, ENTRY_ADDED, ENTRY_REMOVED},
+        collection_member::COLLECTION_VIEWER,
+        show_collection::{move_entries, TransferShowCollectionRequest},
+        show_entry::ShowEntry,
+    };
+
+    #[actix_web::test]
+    async fn should_require_authentication() {
+        let (app, pool) = setup::create_app(move_entries).await;
+
+        let (collection, target) = {
+            let mut conn = pool.get().unwrap();
+            let user = data::create_user(&mut conn);
+            let collection = data::create_show_collection(&mut conn, &user);
+            let target = data::create_show_collection(&mut conn, &user);
+            (collection, target)
+        };
+
+        let request = test::TestRequest::post()
+            .uri(&format!(
+                "/shows/collections/{}/move",
+                collection.collection_id
+            ))
+            .set_json(TransferShowCollectionRequest {
+                target_collection_id: target.collection_id,
+                show_ids: vec![],
+            })
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(401, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_not_move_from_show_collection_as_viewer() {
+        let (app, pool) = setup::create_app(move_entries).await;
+
+        let (token, show_entry, target) = {
+            let mut conn = pool.get().unwrap();
+            let owner = data::create_user(&mut conn);
+            let collection = data::create_show_collection(&mut conn, &owner);
+            let show_entry = data::create_show_entry(&mut conn, &owner, &collection);
+            let (token, user) = data::create_authed_user(&mut conn);
+            data::create_collection_member(&mut conn, &collection, &user, COLLECTION_VIEWER);
+            let target = data::create_show_collection(&mut conn, &user);
+            (token, show_entry, target)
+        };
+
+        let request = test::TestRequest::post()
+            .uri(&format!(
+                "/shows/collections/{}/move",
+                show_entry.collection_id
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .set_json(TransferShowCollectionRequest {
+                target_collection_id: target.collection_id,
+                show_ids: vec![show_entry.show_id],
+            })
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(404, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_move_show_entries() {
+        let (app, pool) = setup::create_app(move_entries).await;
+
+        let (token, user, show_entry, target) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let watchlist = data::create_default_show_watchlist(&mut conn, &user);
+            let show_entry = data::create_show_entry(&mut conn, &user, &watchlist);
+            let target = data::create_show_collection(&mut conn, &user);
+            (token, user, show_entry, target)
+        };
+
+        let request = test::TestRequest::post()
+            .uri(&format!(
+                "/shows/collections/{}/move",
+                show_entry.collection_id
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .set_json(TransferShowCollectionRequest {
+                target_collection_id: target.collection_id,
+                show_ids: vec![show_entry.show_id],
+            })
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<Vec<ShowEntry>>(response).await;
+        assert_eq!(1, result.data.len());
+        assert_eq!(target.collection_id, result.data[0].collection_id);
+        assert_eq!(show_entry.show_id, result.data[0].show_id);
+
+        let (source_entries, source_changes, target_changes) = {
+            let mut conn = pool.get().unwrap();
+            (
+                ShowEntry::find_all(
+                    &mut conn,
+                    user.user_id,
+                    show_entry.collection_id,
+                    Default::default(),
+                )
+                .unwrap(),
+                CollectionChange::find_all(&mut conn, show_entry.collection_id).unwrap(),
+                CollectionChange::find_all(&mut conn, target.collection_id).unwrap(),
+            )
+        };
+
+        assert!(source_entries.is_empty());
+        assert_eq!(1, source_changes.len());
+        assert_eq!(ENTRY_REMOVED, source_changes[0].action);
+        assert_eq!(1, target_changes.len());
+        assert_eq!(ENTRY_ADDED, target_changes[0].action);
+    }
+}
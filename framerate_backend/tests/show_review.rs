@@ -263,6 +263,7 @@ mod find_by_show_id {
 mod create {
     use crate::common::{data, process, setup};
     use actix_web::{http::header::AUTHORIZATION, test};
+    use framerate::show_entry::ShowEntry;
     use framerate::{
         review_company::ReviewCompanySummary,
         show_review::{create, ShowReview, ShowReviewResponse},
@@ -391,6 +392,46 @@ mod create {
         assert_eq!(company_details.first_name, result_company.first_name);
         assert_eq!(company_details.last_name, result_company.last_name);
     }
+
+    #[actix_web::test]
+    async fn should_remove_reviewed_show_from_watchlist() {
+        let (app, pool) = setup::create_app(create).await;
+
+        let (token, show_entry) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            data::set_watchlist_on_review(&mut conn, &user, "remove");
+            let watchlist = data::create_default_show_watchlist(&mut conn, &user);
+            let show_entry = data::create_show_entry(&mut conn, &user, &watchlist);
+            (token, show_entry)
+        };
+
+        let request = test::TestRequest::post()
+            .uri(&format!("/shows/{}/reviews", show_entry.show_id))
+            .set_json(data::generate_save_show_review())
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<ShowReviewResponse>(response).await;
+        let removal = result.data.watchlist_removal.unwrap();
+        assert_eq!(show_entry, removal.entry);
+        assert!(removal.moved_to.is_none());
+
+        let entries = {
+            let mut conn = pool.get().unwrap();
+            ShowEntry::find_all(
+                &mut conn,
+                show_entry.user_id,
+                show_entry.collection_id,
+                Default::default(),
+            )
+            .unwrap()
+        };
+        assert!(entries.is_empty());
+    }
 }
 
 mod update {
@@ -655,3 +696,116 @@ mod update {
         assert_eq!(&0, &company.len());
     }
 }
+
+mod delete {
+    use crate::common::{data, process, setup};
+    use actix_web::{http::header::AUTHORIZATION, test};
+    use framerate::{
+        review_company::{ReviewCompany, ReviewCompanySummary},
+        show_review::{delete, ShowReview},
+        utils::response_body::DeleteResponse,
+    };
+
+    #[actix_web::test]
+    async fn should_require_authentication() {
+        let (app, pool) = setup::create_app(delete).await;
+
+        let show_review = {
+            let mut conn = pool.get().unwrap();
+            let user = data::create_user(&mut conn);
+            let review = data::create_review(&mut conn, &user);
+            data::create_show_review(&mut conn, &user, &review)
+        };
+
+        let request = test::TestRequest::delete()
+            .uri(&format!(
+                "/shows/{}/reviews/{}",
+                show_review.show_id, show_review.review_id
+            ))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(401, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_not_delete_other_users_review() {
+        let (app, pool) = setup::create_app(delete).await;
+
+        let (token, user, show_review) = {
+            let mut conn = pool.get().unwrap();
+            let user = data::create_user(&mut conn);
+            let review = data::create_review(&mut conn, &user);
+            let show_review = data::create_show_review(&mut conn, &user, &review);
+            let (token, _) = data::create_authed_user(&mut conn);
+            (token, user, show_review)
+        };
+
+        let request = test::TestRequest::delete()
+            .uri(&format!(
+                "/shows/{}/reviews/{}",
+                show_review.show_id, show_review.review_id
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(404, response.status());
+
+        let existing_review = {
+            let mut conn = pool.get().unwrap();
+            ShowReview::find_by_review_id(&mut conn, user.user_id, show_review.review_id)
+        };
+
+        assert!(existing_review.is_ok());
+    }
+
+    #[actix_web::test]
+    async fn should_delete_review() {
+        let (app, pool) = setup::create_app(delete).await;
+
+        let (token, user, show_review) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let review = data::create_review(&mut conn, &user);
+            let show_review = data::create_show_review(&mut conn, &user, &review);
+            let company = data::create_company(&mut conn, &user);
+
+            ReviewCompany::replace(
+                &mut conn,
+                show_review.review_id,
+                Some(&vec![ReviewCompanySummary {
+                    company_id: company.company_id,
+                }]),
+            )
+            .unwrap();
+
+            (token, user, show_review)
+        };
+
+        let request = test::TestRequest::delete()
+            .uri(&format!(
+                "/shows/{}/reviews/{}",
+                show_review.show_id, show_review.review_id
+            ))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<DeleteResponse>(response).await;
+        assert_eq!(1, result.data.count);
+
+        let (existing_review, company) = {
+            let mut conn = pool.get().unwrap();
+            let review =
+                ShowReview::find_by_review_id(&mut conn, user.user_id, show_review.review_id);
+            let company = ReviewCompany::find_by_review(&mut conn, show_review.review_id).unwrap();
+            (review, company)
+        };
+
+        assert!(existing_review.is_err());
+        assert_eq!(0, company.len());
+    }
+}
@@ -0,0 +1,285 @@
+pub mod common;
+
+mod find_all {
+    use crate::common::{data, process, setup};
+    use actix_web::{http::header::AUTHORIZATION, test};
+    use framerate::tag::{find_all, Tag};
+
+    #[actix_web::test]
+    async fn should_require_authentication() {
+        let (app, _) = setup::create_app(find_all).await;
+
+        let request = test::TestRequest::get().uri("/tags").to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(401, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_not_return_other_users_tags() {
+        let (app, pool) = setup::create_app(find_all).await;
+
+        let token = {
+            let mut conn = pool.get().unwrap();
+            let user = data::create_user(&mut conn);
+            data::create_tag(&mut conn, &user);
+            let (token, _) = data::create_authed_user(&mut conn);
+            token
+        };
+
+        let request = test::TestRequest::get()
+            .uri("/tags")
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<Vec<Tag>>(response).await;
+        assert_eq!(0, result.data.len());
+    }
+
+    #[actix_web::test]
+    async fn should_return_user_tags() {
+        let (app, pool) = setup::create_app(find_all).await;
+
+        let (token, user, tag) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let tag = data::create_tag(&mut conn, &user);
+            (token, user, tag)
+        };
+
+        let request = test::TestRequest::get()
+            .uri("/tags")
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<Vec<Tag>>(response).await;
+        assert_eq!(1, result.data.len());
+
+        let tag_response = result.data.first().unwrap();
+
+        assert_eq!(user.user_id, tag_response.user_id);
+        assert_eq!(tag.name, tag_response.name);
+    }
+}
+
+mod create {
+    use crate::common::{data, process, setup};
+    use actix_web::{http::header::AUTHORIZATION, test};
+    use framerate::tag::{create, SaveTag, Tag};
+
+    #[actix_web::test]
+    async fn should_require_authentication() {
+        let (app, _) = setup::create_app(create).await;
+
+        let tag = data::generate_save_tag();
+
+        let request = test::TestRequest::post()
+            .uri("/tags")
+            .set_json(tag)
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(401, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_create_tag() {
+        let (app, pool) = setup::create_app(create).await;
+
+        let (token, user) = {
+            let mut conn = pool.get().unwrap();
+            data::create_authed_user(&mut conn)
+        };
+
+        let tag = data::generate_save_tag();
+
+        let request = test::TestRequest::post()
+            .uri("/tags")
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .set_json(&tag)
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<Tag>(response).await;
+
+        assert_eq!(user.user_id, result.data.user_id);
+        assert_eq!(tag.name, result.data.name);
+    }
+
+    #[actix_web::test]
+    async fn should_not_create_duplicate_tag() {
+        let (app, pool) = setup::create_app(create).await;
+
+        let (token, tag) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let tag = data::create_tag(&mut conn, &user);
+            (token, tag)
+        };
+
+        let request = test::TestRequest::post()
+            .uri("/tags")
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .set_json(SaveTag { name: tag.name })
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(409, response.status());
+    }
+}
+
+mod update {
+    use crate::common::{data, process, setup};
+    use actix_web::{http::header::AUTHORIZATION, test};
+    use framerate::tag::{update, Tag};
+    use uuid::Uuid;
+
+    #[actix_web::test]
+    async fn should_require_authentication() {
+        let (app, pool) = setup::create_app(update).await;
+
+        let tag = {
+            let mut conn = pool.get().unwrap();
+            let (_, user) = data::create_authed_user(&mut conn);
+            data::create_tag(&mut conn, &user)
+        };
+
+        let request = test::TestRequest::put()
+            .uri(&format!("/tags/{}", tag.tag_id))
+            .set_json(tag)
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(401, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_not_update_other_users_tag() {
+        let (app, pool) = setup::create_app(update).await;
+
+        let (token, tag) = {
+            let mut conn = pool.get().unwrap();
+            let (token, _) = data::create_authed_user(&mut conn);
+            let other_user = data::create_user(&mut conn);
+            let tag = data::create_tag(&mut conn, &other_user);
+
+            (token, tag)
+        };
+
+        let request = test::TestRequest::put()
+            .uri(&format!("/tags/{}", tag.tag_id))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .set_json(tag)
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(404, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_update_tag() {
+        let (app, pool) = setup::create_app(update).await;
+
+        let (token, user, mut tag) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let tag = data::create_tag(&mut conn, &user);
+
+            (token, user, tag)
+        };
+
+        tag.name = Uuid::new_v4().to_string();
+
+        let request = test::TestRequest::put()
+            .uri(&format!("/tags/{}", tag.tag_id))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .set_json(&tag)
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<Tag>(response).await;
+
+        assert_eq!(user.user_id, result.data.user_id);
+        assert_eq!(tag.name, result.data.name);
+    }
+}
+
+mod delete {
+    use crate::common::{data, process, setup};
+    use actix_web::{http::header::AUTHORIZATION, test};
+    use framerate::{tag::delete, utils::response_body::DeleteResponse};
+
+    #[actix_web::test]
+    async fn should_require_authentication() {
+        let (app, pool) = setup::create_app(delete).await;
+
+        let tag = {
+            let mut conn = pool.get().unwrap();
+            let (_, user) = data::create_authed_user(&mut conn);
+            data::create_tag(&mut conn, &user)
+        };
+
+        let request = test::TestRequest::delete()
+            .uri(&format!("/tags/{}", tag.tag_id))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(401, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_not_delete_other_users_tag() {
+        let (app, pool) = setup::create_app(delete).await;
+
+        let (token, tag) = {
+            let mut conn = pool.get().unwrap();
+            let (token, _) = data::create_authed_user(&mut conn);
+            let other_user = data::create_user(&mut conn);
+            let tag = data::create_tag(&mut conn, &other_user);
+
+            (token, tag)
+        };
+
+        let request = test::TestRequest::delete()
+            .uri(&format!("/tags/{}", tag.tag_id))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert_eq!(404, response.status());
+    }
+
+    #[actix_web::test]
+    async fn should_delete_tag() {
+        let (app, pool) = setup::create_app(delete).await;
+
+        let (token, tag) = {
+            let mut conn = pool.get().unwrap();
+            let (token, user) = data::create_authed_user(&mut conn);
+            let tag = data::create_tag(&mut conn, &user);
+
+            (token, tag)
+        };
+
+        let request = test::TestRequest::delete()
+            .uri(&format!("/tags/{}", tag.tag_id))
+            .insert_header((AUTHORIZATION, format!("Bearer {token}")))
+            .to_request();
+
+        let response = test::call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let result = process::parse_body::<DeleteResponse>(response).await;
+        assert_eq!(1, result.data.count);
+    }
+}
@@ -0,0 +1,8 @@
+use super::{movie::Movie, show::ShowSearch};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct FindResults {
+    pub movie_results: Vec<Movie>,
+    pub tv_results: Vec<ShowSearch>,
+}
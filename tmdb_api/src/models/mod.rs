@@ -1,4 +1,5 @@
 pub mod episode;
+pub mod find;
 pub mod movie;
 pub mod season;
 pub mod show;
@@ -54,6 +54,14 @@ pub struct ReleaseDates {
     pub results: Vec<ReleaseDateResult>,
 }
 
+#[derive(Deserialize, Debug)]
+pub struct BelongsToCollection {
+    pub id: i32,
+    pub name: String,
+    pub poster_path: Option<String>,
+    pub backdrop_path: Option<String>,
+}
+
 #[derive(Deserialize)]
 pub struct Movie {
     pub id: i32,
@@ -70,9 +78,20 @@ pub struct Movie {
     pub runtime: Option<i32>,
     pub release_dates: Option<ReleaseDates>,
     pub credits: Option<Credits>,
+    pub belongs_to_collection: Option<BelongsToCollection>,
 }
 
 #[derive(Deserialize)]
 pub struct MovieSearch {
     pub results: Vec<Movie>,
 }
+
+#[derive(Deserialize)]
+pub struct Collection {
+    pub id: i32,
+    pub name: String,
+    pub overview: Option<String>,
+    pub poster_path: Option<String>,
+    pub backdrop_path: Option<String>,
+    pub parts: Vec<Movie>,
+}